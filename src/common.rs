@@ -1,36 +1,127 @@
+mod format;
+mod histogram;
+mod ids;
+mod mark;
+mod model;
+mod pacing;
+mod renderer;
+mod scale;
+mod theme;
+mod zone;
+
+pub use format::{ValueFormat, value_text};
+pub use histogram::{
+    HISTOGRAM_VIEW_BOX, HistogramBar, histogram_bars, histogram_style, histogram_track_style,
+};
+pub use ids::SliderIds;
+pub use mark::{Mark, mark_style, mark_tick_style, marks_container_style};
+pub use model::{ChangeSource, KeyAction, RangeBehavior, SliderChange, SliderModel};
+pub use pacing::{ChangePacer, RateLimit};
+pub use renderer::{
+    RANGE_CLASS, RANGE_DRAG_CLASS, RANGE_DRAG_CSS, Renderer, THUMB_CLASS, TRACK_CLASS,
+    TRACK_LAYOUT, custom_range_style, custom_thumb_style, custom_track_style, focus_thumb, is_rtl,
+    pointer_on_range, pointer_on_track, position_percent, range_drag_style, range_layout,
+};
+pub use scale::{Scale, ScaleMapping};
+pub use theme::{THEME_CSS, THEMED_CLASS, Theme, themed_container};
+pub use zone::{Zone, zone_label_style, zones_gradient};
+
+use std::borrow::Cow;
+
 #[derive(Clone, PartialEq, Default)]
 pub enum Cursor {
     #[default]
     Round,
     Square,
-    Custom(&'static str),
+    /// Declarations replacing the built-in thumb shape.
+    Custom(Cow<'static, str>),
 }
 
 impl Cursor {
+    pub fn custom(style: impl Into<Cow<'static, str>>) -> Self {
+        Cursor::Custom(style.into())
+    }
+
+    /// Declarations for the native thumb, see [`thumb_css`].
     pub fn to_style(&self, disabled: bool, color: &Color) -> String {
         let base_color = if disabled {
-            "#aaa"
+            "var(--slider-disabled, #aaa)"
+        } else if *color == Color::Primary {
+            "var(--slider-thumb, var(--slider-fill, #0d6efd))"
         } else {
             color.to_color_code()
         };
         let base_style = match self {
             Cursor::Round => format!(
-                "width: 16px; height: 16px; background: {}; border-radius: 50%;",
-                base_color
+                "width: {size}; height: {size}; background: {}; border-radius: var(--slider-thumb-radius, 50%);",
+                base_color,
+                size = THUMB_SIZE
             ),
             Cursor::Square => format!(
-                "width: 16px; height: 16px; background: {}; border-radius: 0;",
-                base_color
+                "width: {size}; height: {size}; background: {}; border-radius: 0;",
+                base_color,
+                size = THUMB_SIZE
             ),
-            Cursor::Custom(s) => s.to_string(),
+            Cursor::Custom(style) => style.to_string(),
         };
+        let cursor = if disabled { "not-allowed" } else { "pointer" };
         format!(
-            "{} cursor: pointer; transition: background 0.3s;",
-            base_style
+            "{} cursor: {}; transition: background 0.3s, transform 0.15s;",
+            base_style, cursor
         )
     }
 }
 
+/// Size of the built-in thumbs, overridable through a [`Theme`].
+const THUMB_SIZE: &str = "var(--slider-thumb-size, 16px)";
+
+/// Size of a native thumb as a CSS length: the `thumb_size` of the resolved `theme`, or the
+/// `--slider-thumb-size` variable with the built-in size as fallback.
+pub fn thumb_size(theme: Option<&Theme>) -> &str {
+    theme.map_or(THUMB_SIZE, |theme| theme.thumb_size.as_str())
+}
+
+/// Builds the stylesheet for the thumbs of the slider whose container has the id `scope`.
+///
+/// Native thumbs are pseudo-elements that inline styles cannot reach, so the [`Cursor`] style,
+/// the `custom_thumb_css` declarations and the hover, active and focus states go into a
+/// `<style>` element next to the inputs, once for WebKit and once for Firefox. The same rules
+/// style the thumbs of [`Renderer::Custom`].
+pub fn thumb_css(
+    scope: &str,
+    cursor: &Cursor,
+    color: &Color,
+    disabled: bool,
+    custom_thumb_css: Option<&str>,
+) -> String {
+    let thumb = format!(
+        "{} {}",
+        cursor.to_style(disabled, color),
+        custom_thumb_css.unwrap_or("")
+    );
+    let input = format!("#{} input[type=range]", scope);
+    let custom = format!("#{} .{}", scope, THUMB_CLASS);
+    let mut css = format!(
+        "{input}::-webkit-slider-thumb {{ -webkit-appearance: none; appearance: none; border: none; {thumb} }} \
+         {input}::-moz-range-thumb {{ border: none; {thumb} }} \
+         {custom} {{ border: none; outline: none; {thumb} }} \
+         {input}:focus-visible::-webkit-slider-thumb {{ box-shadow: 0 0 0 3px {ring}; }} \
+         {input}:focus-visible::-moz-range-thumb {{ box-shadow: 0 0 0 3px {ring}; }} \
+         {custom}:focus-visible {{ box-shadow: 0 0 0 3px {ring}; }}",
+        ring = "var(--slider-focus-ring, rgba(13, 110, 253, 0.5))"
+    );
+    if !disabled {
+        css.push_str(&format!(
+            " {input}::-webkit-slider-thumb:hover {{ filter: brightness(1.1); }} \
+             {input}::-moz-range-thumb:hover {{ filter: brightness(1.1); }} \
+             {custom}:hover {{ filter: brightness(1.1); }} \
+             {input}:active::-webkit-slider-thumb {{ transform: scale(1.2); }} \
+             {input}:active::-moz-range-thumb {{ transform: scale(1.2); }}"
+        ));
+    }
+    css
+}
+
 #[derive(Clone, PartialEq, Default)]
 pub enum Size {
     #[default]
@@ -38,17 +129,22 @@ pub enum Size {
     Md,
     Sm,
     Lg,
-    Custom(&'static str),
+    /// Declarations setting the height and width, such as `"height: 8px; width: 50vw;"`.
+    Custom(Cow<'static, str>),
 }
 
 impl Size {
-    pub fn to_style(&self) -> &'static str {
+    pub fn custom(style: impl Into<Cow<'static, str>>) -> Self {
+        Size::Custom(style.into())
+    }
+
+    pub fn to_style(&self) -> &str {
         match self {
             Size::None => "",
             Size::Sm => "height: 10px; width: 120px;",
             Size::Md => "height: 20px; width: 220px;",
             Size::Lg => "height: 35px; width: 320px;",
-            Size::Custom(s) => s,
+            Size::Custom(style) => style,
         }
     }
 }
@@ -66,13 +162,18 @@ pub enum Color {
     Dark,
     Muted,
     White,
-    Custom(&'static str),
+    /// Any CSS colour, such as `"#ff5722"` or `"var(--brand)"`.
+    Custom(Cow<'static, str>),
 }
 
 impl Color {
-    pub fn to_color_code(&self) -> &'static str {
+    pub fn custom(color: impl Into<Cow<'static, str>>) -> Self {
+        Color::Custom(color.into())
+    }
+
+    pub fn to_color_code(&self) -> &str {
         match self {
-            Color::Primary => "#0d6efd",
+            Color::Primary => "var(--slider-fill, #0d6efd)",
             Color::Secondary => "#6c757d",
             Color::Success => "#198754",
             Color::Warning => "#ffc107",
@@ -82,7 +183,7 @@ impl Color {
             Color::Dark => "#212529",
             Color::Muted => "#6c757d",
             Color::White => "#ffffff",
-            Color::Custom(s) => s,
+            Color::Custom(color) => color,
         }
     }
 }
@@ -114,18 +215,163 @@ impl Orientation {
             None
         }
     }
+
+    pub fn to_aria(&self) -> &'static str {
+        if self.is_vertical() {
+            "vertical"
+        } else {
+            "horizontal"
+        }
+    }
+
+    /// Direction of the fill gradient along the track.
+    pub fn fill_direction(&self, rtl_fill: bool) -> &'static str {
+        match (self.is_vertical(), rtl_fill) {
+            (true, true) => "to top",
+            (true, false) => "to bottom",
+            (false, true) => "to left",
+            (false, false) => "to right",
+        }
+    }
 }
 
+/// Colour of the unfilled track, overridable through a [`Theme`].
+const TRACK_COLOR: &str = "var(--slider-track, #ccc)";
+
+/// Builds the track `background` declaration, filled up to `percent` of the track.
+pub fn track_background(
+    color: &Color,
+    outside: Option<&Color>,
+    orientation: &Orientation,
+    rtl_fill: bool,
+    use_gradient: bool,
+    percent: f64,
+) -> String {
+    let fill_color = color.to_color_code();
+    let track_color = outside.map_or(TRACK_COLOR, Color::to_color_code);
+    if use_gradient {
+        format!(
+            "background: linear-gradient({}, {} 0%, {} {:.2}%, {} {:.2}%, {} 100%);",
+            orientation.fill_direction(rtl_fill),
+            fill_color,
+            fill_color,
+            percent,
+            track_color,
+            percent,
+            track_color
+        )
+    } else {
+        format!("background: {};", fill_color)
+    }
+}
+
+/// Builds the track `background` declaration, filled between `start` and `end` percent.
+///
+/// The track outside the fill takes the `outside` colour, or the theme's track colour.
+pub fn range_background(
+    color: &Color,
+    outside: Option<&Color>,
+    orientation: &Orientation,
+    rtl_fill: bool,
+    use_gradient: bool,
+    start: f64,
+    end: f64,
+) -> String {
+    let fill_color = color.to_color_code();
+    let track_color = outside.map_or(TRACK_COLOR, Color::to_color_code);
+    if use_gradient {
+        format!(
+            "background: linear-gradient({}, {} 0%, {} {:.2}%, {} {:.2}%, {} {:.2}%, {} {:.2}%, {} 100%);",
+            orientation.fill_direction(rtl_fill),
+            track_color,
+            track_color,
+            start,
+            fill_color,
+            start,
+            fill_color,
+            end,
+            track_color,
+            end,
+            track_color
+        )
+    } else {
+        format!("background: {};", fill_color)
+    }
+}
+
+/// Builds the track `background` declaration of a slider with `zones`: the fill spans
+/// [`SliderModel::fill_percent`] and the zones show through around it.
+pub fn zoned_background(
+    color: &Color,
+    outside: Option<&Color>,
+    zones: &[Zone],
+    model: &SliderModel,
+    orientation: &Orientation,
+    rtl_fill: bool,
+) -> String {
+    let direction = orientation.fill_direction(rtl_fill);
+    let (start, end) = model.fill_percent();
+    let fill = color.to_color_code();
+    format!(
+        "background: linear-gradient({}, transparent {:.2}%, {} {:.2}%, {} {:.2}%, transparent {:.2}%), {};",
+        direction,
+        start,
+        fill,
+        start,
+        fill,
+        end,
+        end,
+        zones_gradient(
+            zones,
+            model,
+            direction,
+            outside.map_or(TRACK_COLOR, Color::to_color_code)
+        ),
+    )
+}
+
+/// Builds the `background` declaration of a custom track showing `zones`.
+pub fn zones_track_background(
+    outside: Option<&Color>,
+    zones: &[Zone],
+    model: &SliderModel,
+    orientation: &Orientation,
+) -> String {
+    format!(
+        "background: {};",
+        zones_gradient(
+            zones,
+            model,
+            orientation.fill_direction(false),
+            outside.map_or(TRACK_COLOR, Color::to_color_code),
+        )
+    )
+}
+
+/// Class of the wrapper that stacks the inputs of a multi-thumb slider on one track.
+pub const STACKED_THUMBS_CLASS: &str = "slider-thumbs";
+
+/// Overlaps the stacked inputs and lets pointer events through them except on their thumbs, so
+/// every thumb of a multi-thumb slider stays draggable.
+pub const STACKED_THUMBS_CSS: &str = ".slider-thumbs input[type=range] { grid-area: 1 / 1; pointer-events: none; } \
+.slider-thumbs input[type=range]::-webkit-slider-thumb { pointer-events: auto; } \
+.slider-thumbs input[type=range]::-moz-range-thumb { pointer-events: auto; }";
+
 #[derive(Clone, PartialEq, Default)]
 pub enum Width {
     #[default]
     Auto,
     Px(u32),
     Percent(u8),
-    Custom(&'static str),
+    /// Any CSS width, such as `"calc(100% - 2rem)"`.
+    Custom(Cow<'static, str>),
 }
 
 impl Width {
+    pub fn custom(width: impl Into<Cow<'static, str>>) -> Self {
+        Width::Custom(width.into())
+    }
+
     pub fn to_style(&self) -> String {
         match self {
             Width::Auto => "width: auto;".to_string(),
@@ -141,10 +387,15 @@ pub enum Height {
     #[default]
     Auto,
     Px(u32),
-    Custom(&'static str),
+    /// Any CSS height, such as `"2rem"`.
+    Custom(Cow<'static, str>),
 }
 
 impl Height {
+    pub fn custom(height: impl Into<Cow<'static, str>>) -> Self {
+        Height::Custom(height.into())
+    }
+
     pub fn to_style(&self) -> String {
         match self {
             Height::Auto => "height: auto;".to_string(),
@@ -153,3 +404,99 @@ impl Height {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn range_background_fills_between_the_thumbs() {
+        let css = range_background(
+            &Color::Danger,
+            None,
+            &Orientation::Horizontal,
+            false,
+            true,
+            20.0,
+            70.0,
+        );
+        assert!(css.starts_with("background: linear-gradient(to right,"));
+        assert!(css.contains("#dc3545 20.00%, #dc3545 70.00%"));
+        assert!(css.contains("var(--slider-track, #ccc) 70.00%"));
+
+        let css = range_background(
+            &Color::Danger,
+            Some(&Color::Light),
+            &Orientation::Vertical,
+            true,
+            true,
+            20.0,
+            70.0,
+        );
+        assert!(css.starts_with("background: linear-gradient(to top, #f8f9fa 0%"));
+        assert!(css.contains("#dc3545 70.00%, #f8f9fa 70.00%"));
+    }
+
+    #[test]
+    fn thumb_size_follows_the_theme() {
+        assert_eq!(thumb_size(None), "var(--slider-thumb-size, 16px)");
+        assert_eq!(thumb_size(Some(&Theme::high_contrast())), "20px");
+    }
+
+    #[test]
+    fn zoned_background_draws_the_fill_over_the_zones() {
+        let model = SliderModel::new(0.0, 100.0, 1.0).with_value(40.0);
+        let zones = vec![Zone::new(60.0, 100.0, Color::Danger)];
+        let css = zoned_background(
+            &Color::Success,
+            None,
+            &zones,
+            &model,
+            &Orientation::Horizontal,
+            false,
+        );
+        assert!(css.starts_with(
+            "background: linear-gradient(to right, transparent 0.00%, #198754 0.00%, #198754 40.00%, transparent 40.00%), linear-gradient(to right,"
+        ));
+        assert!(css.contains("#dc3545 60.00%"));
+        let css =
+            zones_track_background(Some(&Color::Light), &zones, &model, &Orientation::Vertical);
+        assert!(css.starts_with("background: linear-gradient(to bottom, #f8f9fa 0%"));
+    }
+
+    #[test]
+    fn thumb_css_is_scoped_to_the_slider() {
+        let css = thumb_css(
+            "slider-a",
+            &Cursor::Square,
+            &Color::Danger,
+            false,
+            Some("border: 1px solid;"),
+        );
+        assert!(css.contains("#slider-a input[type=range]::-webkit-slider-thumb {"));
+        assert!(css.contains("#slider-a input[type=range]::-moz-range-thumb {"));
+        assert!(css.contains("background: #dc3545; border-radius: 0;"));
+        assert!(css.contains("border: 1px solid;"));
+        assert!(css.contains(":hover"));
+    }
+
+    #[test]
+    fn custom_values_can_be_built_at_runtime() {
+        let hue = 200;
+        let color = Color::custom(format!("hsl({}, 80%, 50%)", hue));
+        assert_eq!(color.to_color_code(), "hsl(200, 80%, 50%)");
+        assert_eq!(
+            Width::custom(format!("{}rem", 12)).to_style(),
+            "width: 12rem;"
+        );
+        assert_eq!(Size::custom("height: 4px;").to_style(), "height: 4px;");
+    }
+
+    #[test]
+    fn disabled_thumbs_have_no_hover_state() {
+        let css = thumb_css("slider-b", &Cursor::Round, &Color::Primary, true, None);
+        assert!(css.contains("var(--slider-disabled, #aaa)"));
+        assert!(css.contains("cursor: not-allowed;"));
+        assert!(!css.contains(":hover"));
+    }
+}
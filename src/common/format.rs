@@ -0,0 +1,265 @@
+use super::SliderModel;
+use std::borrow::Cow;
+use std::fmt;
+use std::sync::Arc;
+
+/// Text for `value`: the entry of `labels` at its position in [`SliderModel::step_values`]
+/// when there is one, the value in `format` otherwise.
+pub fn value_text(
+    model: &SliderModel,
+    labels: &[String],
+    format: &ValueFormat,
+    value: f64,
+) -> String {
+    if labels.is_empty() {
+        return format.format(value, model.step());
+    }
+    model
+        .step_index(value)
+        .and_then(|index| labels.get(index))
+        .cloned()
+        .unwrap_or_else(|| format.format(value, model.step()))
+}
+
+/// How slider values are turned into text for the output, the tooltip, the step labels and
+/// `aria-valuetext`.
+///
+/// # Examples
+/// ```rust
+/// use slider_rs::common::ValueFormat;
+///
+/// assert_eq!(ValueFormat::Auto.format(0.25, 0.05), "0.25");
+/// assert_eq!(ValueFormat::currency("$", 2).format(1_234.5, 1.0), "$1,234.50");
+/// assert_eq!(ValueFormat::Compact.format(1_260.0, 1.0), "1.3k");
+/// assert_eq!(ValueFormat::Duration.format(95.0, 1.0), "1:35");
+/// assert_eq!(ValueFormat::custom(|v| format!("{v} dB")).format(-3.0, 1.0), "-3 dB");
+/// ```
+#[derive(Clone, Default)]
+pub enum ValueFormat {
+    /// As many decimals as the step has, e.g. `0.05` for a `0.05` step.
+    #[default]
+    Auto,
+    /// A fixed number of decimals.
+    Precision(usize),
+    /// A ratio shown as a percentage with the given decimals, e.g. `0.25` as `25%`.
+    Percent(usize),
+    /// An amount with a currency symbol and thousands separators, e.g. `$1,234.50`.
+    Currency {
+        symbol: Cow<'static, str>,
+        decimals: usize,
+    },
+    /// Large numbers shortened with a suffix, e.g. `1.2k`, `3.4M`.
+    Compact,
+    /// Seconds shown as `m:ss`, or `h:mm:ss` from one hour on.
+    Duration,
+    /// A byte count in binary units, e.g. `1.5 KB`.
+    Bytes,
+    /// A user supplied formatter.
+    Custom(Arc<dyn Fn(f64) -> String + Send + Sync>),
+}
+
+impl ValueFormat {
+    /// Wraps a closure into a [`ValueFormat::Custom`].
+    pub fn custom(format: impl Fn(f64) -> String + Send + Sync + 'static) -> Self {
+        ValueFormat::Custom(Arc::new(format))
+    }
+
+    /// A [`ValueFormat::Currency`] with the given symbol and decimals.
+    pub fn currency(symbol: impl Into<Cow<'static, str>>, decimals: usize) -> Self {
+        ValueFormat::Currency {
+            symbol: symbol.into(),
+            decimals,
+        }
+    }
+
+    /// Formats `value`; `step` is the slider step, used by [`ValueFormat::Auto`].
+    pub fn format(&self, value: f64, step: f64) -> String {
+        match self {
+            ValueFormat::Auto => format!("{:.*}", decimals(step), value),
+            ValueFormat::Precision(decimals) => format!("{:.*}", decimals, value),
+            ValueFormat::Percent(decimals) => format!("{:.*}%", decimals, value * 100.0),
+            ValueFormat::Currency { symbol, decimals } => {
+                let sign = if value < 0.0 { "-" } else { "" };
+                let amount = format!("{:.*}", decimals, value.abs());
+                let (whole, fraction) = match amount.split_once('.') {
+                    Some((whole, fraction)) => (whole, format!(".{fraction}")),
+                    None => (amount.as_str(), String::new()),
+                };
+                format!("{sign}{symbol}{}{fraction}", group_thousands(whole))
+            }
+            ValueFormat::Compact => scaled(value, 1000.0, &["", "k", "M", "B", "T"], ""),
+            ValueFormat::Duration => {
+                let sign = if value < 0.0 { "-" } else { "" };
+                let total = value.abs().round() as u64;
+                let (hours, minutes, seconds) = (total / 3600, total / 60 % 60, total % 60);
+                if hours > 0 {
+                    format!("{sign}{hours}:{minutes:02}:{seconds:02}")
+                } else {
+                    format!("{sign}{minutes}:{seconds:02}")
+                }
+            }
+            ValueFormat::Bytes => scaled(value, 1024.0, &["B", "KB", "MB", "GB", "TB"], " "),
+            ValueFormat::Custom(format) => format(value),
+        }
+    }
+}
+
+impl PartialEq for ValueFormat {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (ValueFormat::Auto, ValueFormat::Auto)
+            | (ValueFormat::Compact, ValueFormat::Compact)
+            | (ValueFormat::Duration, ValueFormat::Duration)
+            | (ValueFormat::Bytes, ValueFormat::Bytes) => true,
+            (ValueFormat::Precision(a), ValueFormat::Precision(b))
+            | (ValueFormat::Percent(a), ValueFormat::Percent(b)) => a == b,
+            (
+                ValueFormat::Currency { symbol, decimals },
+                ValueFormat::Currency {
+                    symbol: other_symbol,
+                    decimals: other_decimals,
+                },
+            ) => symbol == other_symbol && decimals == other_decimals,
+            (ValueFormat::Custom(a), ValueFormat::Custom(b)) => Arc::ptr_eq(a, b),
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Debug for ValueFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValueFormat::Auto => f.write_str("Auto"),
+            ValueFormat::Precision(decimals) => f.debug_tuple("Precision").field(decimals).finish(),
+            ValueFormat::Percent(decimals) => f.debug_tuple("Percent").field(decimals).finish(),
+            ValueFormat::Currency { symbol, decimals } => f
+                .debug_struct("Currency")
+                .field("symbol", symbol)
+                .field("decimals", decimals)
+                .finish(),
+            ValueFormat::Compact => f.write_str("Compact"),
+            ValueFormat::Duration => f.write_str("Duration"),
+            ValueFormat::Bytes => f.write_str("Bytes"),
+            ValueFormat::Custom(_) => f.write_str("Custom(..)"),
+        }
+    }
+}
+
+/// Decimals needed to show `value` exactly, such as those of a step; at most 12.
+pub(super) fn decimals(value: f64) -> usize {
+    value
+        .to_string()
+        .split_once('.')
+        .map_or(0, |(_, fraction)| fraction.len().min(12))
+}
+
+/// Divides `value` by `base` until it fits and appends the matching unit, with one decimal
+/// that is dropped when it is zero.
+fn scaled(value: f64, base: f64, units: &[&str], separator: &str) -> String {
+    let mut scaled = value.abs();
+    let mut unit = 0;
+    while scaled >= base && unit < units.len() - 1 {
+        scaled /= base;
+        unit += 1;
+    }
+    // Rounding can carry over into the next unit, e.g. 999.96 to "1000.0".
+    if format!("{scaled:.1}").parse::<f64>() == Ok(base) && unit < units.len() - 1 {
+        scaled /= base;
+        unit += 1;
+    }
+    let number = format!("{scaled:.1}");
+    let number = number.strip_suffix(".0").unwrap_or(&number);
+    let sign = if value < 0.0 { "-" } else { "" };
+    format!("{sign}{number}{separator}{}", units[unit])
+}
+
+fn group_thousands(digits: &str) -> String {
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / 3);
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i) % 3 == 0 {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+    grouped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::Scale;
+
+    #[test]
+    fn labels_follow_the_step_values() {
+        let labels: Vec<String> = ["low", "mid", "high"].map(String::from).to_vec();
+        let model = SliderModel::new(0.0, 1.0, 0.0);
+        assert_eq!(value_text(&model, &labels, &ValueFormat::Auto, 1.0), "mid");
+        assert_eq!(
+            value_text(&model, &labels, &ValueFormat::Precision(1), 0.5),
+            "0.5"
+        );
+        let model = SliderModel::new(0.0, 100.0, 1.0).with_scale(Scale::Pow(2.0));
+        assert_eq!(value_text(&model, &labels, &ValueFormat::Auto, 4.0), "high");
+        assert_eq!(value_text(&model, &labels, &ValueFormat::Auto, 2.0), "2");
+    }
+
+    #[test]
+    fn auto_follows_step_precision() {
+        assert_eq!(ValueFormat::Auto.format(5.0, 1.0), "5");
+        assert_eq!(ValueFormat::Auto.format(0.3, 0.05), "0.30");
+        assert_eq!(ValueFormat::Auto.format(2.5, 0.5), "2.5");
+    }
+
+    #[test]
+    fn precision_and_percent() {
+        assert_eq!(ValueFormat::Precision(2).format(1.0 / 3.0, 1.0), "0.33");
+        assert_eq!(ValueFormat::Percent(0).format(0.456, 0.01), "46%");
+        assert_eq!(ValueFormat::Percent(1).format(1.0, 0.01), "100.0%");
+    }
+
+    #[test]
+    fn currency_groups_thousands() {
+        let usd = ValueFormat::currency("$", 2);
+        assert_eq!(usd.format(1234.5, 1.0), "$1,234.50");
+        assert_eq!(usd.format(-1_000_000.0, 1.0), "-$1,000,000.00");
+        let eur = ValueFormat::Currency {
+            symbol: String::from("€").into(),
+            decimals: 0,
+        };
+        assert_eq!(eur.format(999.0, 1.0), "€999");
+    }
+
+    #[test]
+    fn compact_shortens_large_numbers() {
+        assert_eq!(ValueFormat::Compact.format(950.0, 1.0), "950");
+        assert_eq!(ValueFormat::Compact.format(1_200.0, 1.0), "1.2k");
+        assert_eq!(ValueFormat::Compact.format(3_400_000.0, 1.0), "3.4M");
+        assert_eq!(ValueFormat::Compact.format(999_960.0, 1.0), "1M");
+        assert_eq!(ValueFormat::Compact.format(-2_000.0, 1.0), "-2k");
+    }
+
+    #[test]
+    fn duration_uses_minutes_and_hours() {
+        assert_eq!(ValueFormat::Duration.format(5.0, 1.0), "0:05");
+        assert_eq!(ValueFormat::Duration.format(605.0, 1.0), "10:05");
+        assert_eq!(ValueFormat::Duration.format(3_725.0, 1.0), "1:02:05");
+    }
+
+    #[test]
+    fn bytes_use_binary_units() {
+        assert_eq!(ValueFormat::Bytes.format(512.0, 1.0), "512 B");
+        assert_eq!(ValueFormat::Bytes.format(1_536.0, 1.0), "1.5 KB");
+        assert_eq!(
+            ValueFormat::Bytes.format(5.0 * 1024.0 * 1024.0, 1.0),
+            "5 MB"
+        );
+    }
+
+    #[test]
+    fn custom_formats_are_compared_by_identity() {
+        let format = ValueFormat::custom(|v| format!("{v}°"));
+        assert_eq!(format.format(21.0, 1.0), "21°");
+        assert_eq!(format, format.clone());
+        assert_ne!(format, ValueFormat::custom(|v| format!("{v}°")));
+    }
+}
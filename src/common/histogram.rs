@@ -0,0 +1,160 @@
+use super::{Orientation, SliderModel};
+
+/// The `viewBox` of the histogram SVG; bar geometry is given in percent of the track.
+pub const HISTOGRAM_VIEW_BOX: &str = "0 0 100 100";
+
+/// One bar of a histogram backdrop, in percent of the SVG drawn behind the track.
+#[derive(Clone, Debug, PartialEq)]
+pub struct HistogramBar {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+    /// Whether the bar lies inside the fill; bars outside it are dimmed.
+    pub selected: bool,
+}
+
+impl HistogramBar {
+    /// The `opacity` of the bar.
+    pub fn opacity(&self) -> &'static str {
+        if self.selected { "1" } else { "0.3" }
+    }
+}
+
+/// Bars for `counts`, spread evenly over the value range of `model`.
+///
+/// Bucket edges are placed with [`SliderModel::percent`], so the bars line up with the thumb on
+/// any scale, and run from the far end with `rtl_fill`, like the fill. Bars grow up from a
+/// horizontal track and to the right of a vertical one; a bar is selected when its centre lies
+/// inside [`SliderModel::fill_percent`].
+///
+/// # Examples
+/// ```rust
+/// use slider_rs::common::{Orientation, SliderModel, histogram_bars};
+///
+/// let model = SliderModel::new(0.0, 100.0, 1.0).with_values(vec![25.0, 75.0]);
+/// let bars = histogram_bars(&[2.0, 4.0, 1.0, 0.0], &model, &Orientation::Horizontal, false);
+/// assert_eq!((bars[1].x, bars[1].width, bars[1].height), (25.0, 25.0, 100.0));
+/// assert!(!bars[0].selected && bars[1].selected && bars[2].selected && !bars[3].selected);
+/// ```
+pub fn histogram_bars(
+    counts: &[f64],
+    model: &SliderModel,
+    orientation: &Orientation,
+    rtl_fill: bool,
+) -> Vec<HistogramBar> {
+    let highest = counts.iter().copied().fold(0.0, f64::max);
+    if counts.is_empty() || highest <= 0.0 {
+        return Vec::new();
+    }
+    let (fill_start, fill_end) = model.fill_percent();
+    let bucket = (model.max() - model.min()) / counts.len() as f64;
+    counts
+        .iter()
+        .enumerate()
+        .map(|(index, &count)| {
+            let from = model.percent(model.min() + bucket * index as f64);
+            let to = model.percent(model.min() + bucket * (index + 1) as f64);
+            let (start, length) = (from.min(to), (to - from).abs());
+            let size = count.max(0.0) / highest * 100.0;
+            let centre = start + length / 2.0;
+            let selected = (fill_start..=fill_end).contains(&centre);
+            let start = if rtl_fill {
+                100.0 - start - length
+            } else {
+                start
+            };
+            if orientation.is_vertical() {
+                HistogramBar {
+                    x: 0.0,
+                    y: start,
+                    width: size,
+                    height: length,
+                    selected,
+                }
+            } else {
+                HistogramBar {
+                    x: start,
+                    y: 100.0 - size,
+                    width: length,
+                    height: size,
+                    selected,
+                }
+            }
+        })
+        .collect()
+}
+
+/// Inline style of the SVG holding the bars, sized along the track.
+///
+/// Native thumbs travel between half a thumb from either end, so the SVG is inset by the same
+/// amount, as [`mark_style`](super::mark_style) does for marks. Pass `0px` as `thumb_size` for
+/// thumbs centred on their value.
+pub fn histogram_style(orientation: &Orientation, thumb_size: &str) -> String {
+    if orientation.is_vertical() {
+        format!(
+            "display: block; width: 3em; height: calc(100% - {size}); margin: calc({size} / 2) 8px calc({size} / 2) 0; flex-shrink: 0;",
+            size = thumb_size
+        )
+    } else {
+        format!(
+            "display: block; width: calc(100% - {size}); height: 3em; margin: 0 calc({size} / 2) 4px;",
+            size = thumb_size
+        )
+    }
+}
+
+/// Inline style of the element holding the histogram and the track, so the bars span the
+/// track alone and not the icons beside it.
+pub fn histogram_track_style(orientation: &Orientation) -> &'static str {
+    if orientation.is_vertical() {
+        "position: relative; display: flex; flex-direction: row; height: 100%;"
+    } else {
+        "position: relative; display: flex; flex-direction: column; flex: 1; min-width: 0;"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vertical_bars_run_down_the_track() {
+        let model = SliderModel::new(0.0, 10.0, 1.0).with_values(vec![4.0]);
+        let bars = histogram_bars(&[1.0, 3.0], &model, &Orientation::Vertical, false);
+        assert_eq!(bars.len(), 2);
+        assert_eq!((bars[0].y, bars[0].height), (0.0, 50.0));
+        assert!((bars[0].width - 100.0 / 3.0).abs() < 1e-9);
+        assert_eq!((bars[1].x, bars[1].y, bars[1].width), (0.0, 50.0, 100.0));
+        assert!(bars[0].selected);
+        assert!(!bars[1].selected);
+        assert_eq!(bars[1].opacity(), "0.3");
+        assert!(histogram_bars(&[0.0, 0.0], &model, &Orientation::Vertical, false).is_empty());
+    }
+
+    #[test]
+    fn rtl_bars_run_from_the_far_end() {
+        let model = SliderModel::new(0.0, 100.0, 1.0).with_value(30.0);
+        let bars = histogram_bars(
+            &[1.0, 2.0, 4.0, 4.0],
+            &model,
+            &Orientation::Horizontal,
+            true,
+        );
+        let xs: Vec<f64> = bars.iter().map(|bar| bar.x).collect();
+        assert_eq!(xs, [75.0, 50.0, 25.0, 0.0]);
+        assert!(bars[0].selected && !bars[1].selected);
+    }
+
+    #[test]
+    fn native_thumbs_inset_the_histogram() {
+        let style = histogram_style(&Orientation::Horizontal, "16px");
+        assert!(
+            style.contains("width: calc(100% - 16px); height: 3em; margin: 0 calc(16px / 2) 4px;")
+        );
+        let style = histogram_style(&Orientation::Vertical, "0px");
+        assert!(
+            style.contains("height: calc(100% - 0px); margin: calc(0px / 2) 8px calc(0px / 2) 0;")
+        );
+    }
+}
@@ -0,0 +1,94 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Ids of the elements of one slider, all derived from the container id so the label, the
+/// output and the description can point at the inputs.
+///
+/// # Examples
+/// ```rust
+/// use slider_rs::common::SliderIds;
+///
+/// let ids = SliderIds::new("volume");
+/// assert_eq!(ids.input(1), "volume-input-1");
+/// assert_eq!(ids.inputs(2), "volume-input-0 volume-input-1");
+/// assert_eq!(ids.list(), "volume-list");
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct SliderIds {
+    root: String,
+}
+
+static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+
+impl SliderIds {
+    pub fn new(root: impl Into<String>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// Ids rooted at `slider-{index}`.
+    pub fn numbered(index: usize) -> Self {
+        Self::new(format!("slider-{}", index))
+    }
+
+    /// Ids rooted at the next number of a process-wide counter.
+    ///
+    /// The counter keeps counting across server renders, so sliders rendered on the server
+    /// should get an explicit id to match the hydrated markup.
+    pub fn generated() -> Self {
+        Self::numbered(NEXT_ID.fetch_add(1, Ordering::Relaxed))
+    }
+
+    /// The container id.
+    pub fn root(&self) -> &str {
+        &self.root
+    }
+
+    /// The input, or `role="slider"` element, of a thumb.
+    pub fn input(&self, thumb: usize) -> String {
+        format!("{}-input-{}", self.root, thumb)
+    }
+
+    /// The ids of the first `thumbs` inputs, separated by spaces.
+    pub fn inputs(&self, thumbs: usize) -> String {
+        (0..thumbs)
+            .map(|thumb| self.input(thumb))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    pub fn label(&self) -> String {
+        format!("{}-label", self.root)
+    }
+
+    /// The `<datalist>` with the tick values.
+    pub fn list(&self) -> String {
+        format!("{}-list", self.root)
+    }
+
+    pub fn output(&self) -> String {
+        format!("{}-output", self.root)
+    }
+
+    pub fn description(&self) -> String {
+        format!("{}-description", self.root)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ids_derive_from_the_root() {
+        let ids = SliderIds::numbered(3);
+        assert_eq!(ids.root(), "slider-3");
+        assert_eq!(ids.label(), "slider-3-label");
+        assert_eq!(ids.output(), "slider-3-output");
+        assert_eq!(ids.description(), "slider-3-description");
+        assert_eq!(ids.inputs(1), "slider-3-input-0");
+    }
+
+    #[test]
+    fn generated_ids_are_unique() {
+        assert_ne!(SliderIds::generated(), SliderIds::generated());
+    }
+}
@@ -0,0 +1,117 @@
+use super::Orientation;
+
+/// A labelled point on the slider track, such as "recommended" or "max safe".
+///
+/// # Examples
+/// ```rust
+/// use slider_rs::common::Mark;
+///
+/// let marks = vec![
+///     Mark::new(0.0),
+///     Mark::new(70.0).with_label("Recommended"),
+///     Mark::new(90.0).with_label("Max safe").with_class("mark-danger"),
+/// ];
+/// assert_eq!(marks[2].label.as_deref(), Some("Max safe"));
+/// ```
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct Mark {
+    /// Value the mark points at.
+    pub value: f64,
+    /// Text shown next to the mark.
+    pub label: Option<String>,
+    /// Extra CSS class for the mark element.
+    pub class: Option<String>,
+}
+
+impl Mark {
+    pub fn new(value: f64) -> Self {
+        Self {
+            value,
+            ..Self::default()
+        }
+    }
+
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    pub fn with_class(mut self, class: impl Into<String>) -> Self {
+        self.class = Some(class.into());
+        self
+    }
+
+    /// The `class` attribute of the mark element.
+    pub fn class_name(&self) -> String {
+        match &self.class {
+            Some(class) => format!("slider-mark {}", class),
+            None => "slider-mark".to_string(),
+        }
+    }
+}
+
+impl From<f64> for Mark {
+    fn from(value: f64) -> Self {
+        Mark::new(value)
+    }
+}
+
+/// Inline style placing a mark at `percent` of the track.
+///
+/// Native thumbs travel between half a thumb from either end, so marks are shifted by the same
+/// amount to line up with the thumb centre. `thumb_size` is a CSS length, see
+/// [`thumb_size`](super::thumb_size); pass `0px` for thumbs centred on their value, as the
+/// custom renderer draws them.
+pub fn mark_style(percent: f64, orientation: &Orientation, thumb_size: &str) -> String {
+    let offset = 0.5 - percent / 100.0;
+    let (side, translate) = if orientation.is_vertical() {
+        ("top", "translateY(-50%)")
+    } else {
+        ("left", "translateX(-50%)")
+    };
+    format!(
+        "position: absolute; {}: calc({:.2}% + {} * {:.4}); transform: {}; font-size: 10px; white-space: nowrap; text-align: center;",
+        side, percent, thumb_size, offset, translate
+    )
+}
+
+/// Inline style of the small line drawn for every mark, pointing at the track.
+pub fn mark_tick_style(orientation: &Orientation) -> &'static str {
+    if orientation.is_vertical() {
+        "display: inline-block; width: 6px; height: 1px; margin-right: 4px; vertical-align: middle; background: var(--slider-tick, currentColor);"
+    } else {
+        "display: block; width: 1px; height: 6px; margin: 0 auto 2px; background: var(--slider-tick, currentColor);"
+    }
+}
+
+/// Inline style of the element that holds the marks, sized along the track.
+pub fn marks_container_style(orientation: &Orientation) -> &'static str {
+    if orientation.is_vertical() {
+        "position: relative; height: 100%; min-width: 3em; margin-left: 8px;"
+    } else {
+        "position: relative; width: 100%; height: 1.5em; margin-top: 8px;"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builders_set_label_and_class() {
+        let mark = Mark::new(5.0).with_label("Five").with_class("accent");
+        assert_eq!(mark.label.as_deref(), Some("Five"));
+        assert_eq!(mark.class_name(), "slider-mark accent");
+        assert_eq!(Mark::from(1.0).class_name(), "slider-mark");
+    }
+
+    #[test]
+    fn mark_style_follows_the_thumb_centre() {
+        let start = mark_style(0.0, &Orientation::Horizontal, "16px");
+        assert!(start.contains("left: calc(0.00% + 16px * 0.5000)"));
+        let end = mark_style(100.0, &Orientation::Horizontal, "20px");
+        assert!(end.contains("left: calc(100.00% + 20px * -0.5000)"));
+        let middle = mark_style(50.0, &Orientation::Vertical, "16px");
+        assert!(middle.contains("top: calc(50.00% + 16px * 0.0000)"));
+    }
+}
@@ -0,0 +1,1399 @@
+use super::Scale;
+use super::format::decimals;
+
+/// A keyboard action understood by [`SliderModel::apply_key`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KeyAction {
+    /// Move the thumb up by one keyboard step.
+    Increment,
+    /// Move the thumb down by one keyboard step.
+    Decrement,
+    /// Move the thumb up by one page step.
+    PageIncrement,
+    /// Move the thumb down by one page step.
+    PageDecrement,
+    /// Move the thumb to `min`.
+    Home,
+    /// Move the thumb to `max`.
+    End,
+}
+
+impl KeyAction {
+    /// Maps a DOM `KeyboardEvent.key` value to an action, if the key is handled.
+    pub fn from_key(key: &str) -> Option<Self> {
+        Self::from_key_event(key, false, false)
+    }
+
+    /// Maps a key press to an action following the WAI-ARIA slider pattern.
+    ///
+    /// Shift turns arrow keys into page steps. In a right-to-left layout `ArrowLeft`
+    /// increments and `ArrowRight` decrements; `ArrowUp` always increments.
+    pub fn from_key_event(key: &str, shift: bool, rtl: bool) -> Option<Self> {
+        let (up, down) = if shift {
+            (KeyAction::PageIncrement, KeyAction::PageDecrement)
+        } else {
+            (KeyAction::Increment, KeyAction::Decrement)
+        };
+        match key {
+            "ArrowUp" => Some(up),
+            "ArrowDown" => Some(down),
+            "ArrowRight" => Some(if rtl { down } else { up }),
+            "ArrowLeft" => Some(if rtl { up } else { down }),
+            "PageUp" => Some(KeyAction::PageIncrement),
+            "PageDown" => Some(KeyAction::PageDecrement),
+            "Home" => Some(KeyAction::Home),
+            "End" => Some(KeyAction::End),
+            _ => None,
+        }
+    }
+}
+
+/// How thumbs of a multi-thumb slider interact when one is moved into another.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum RangeBehavior {
+    /// The moved thumb stops at its neighbour (minus `min_distance`).
+    #[default]
+    Block,
+    /// Thumbs may cross; values are re-sorted and the moved thumb takes its new index.
+    Swap,
+    /// The moved thumb pushes its neighbours along, keeping `min_distance` between them.
+    Push,
+}
+
+/// What moved a thumb.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum ChangeSource {
+    /// Dragging or clicking with a mouse, pen or finger.
+    Pointer,
+    /// Arrow, page, Home or End keys.
+    Keyboard,
+    /// The mouse wheel over a thumb.
+    Wheel,
+    /// Code calling [`SliderModel::set_value`] directly.
+    #[default]
+    Programmatic,
+}
+
+/// A value change produced by [`SliderModel`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SliderChange {
+    /// Index of the thumb that moved, after any [`RangeBehavior::Swap`] reordering.
+    pub thumb: usize,
+    /// The new value of the thumb.
+    pub value: f64,
+    /// The value of the thumb before the change.
+    pub previous: f64,
+    /// What moved the thumb.
+    pub source: ChangeSource,
+    /// Whether the interaction is over: the thumb was released, the key was released or the
+    /// slider lost focus.
+    pub committed: bool,
+}
+
+impl SliderChange {
+    pub fn with_source(mut self, source: ChangeSource) -> Self {
+        self.source = source;
+        self
+    }
+
+    /// The same change, marking the end of the interaction.
+    pub fn committed(mut self) -> Self {
+        self.committed = true;
+        self
+    }
+}
+
+/// Framework-agnostic slider state.
+///
+/// Owns the bounds, the step grid and the current thumb values. Every value that enters the
+/// model is clamped to `[min, max]` and snapped to the step grid, so adapters can feed raw
+/// input (DOM strings, pointer positions, key presses) straight into it.
+///
+/// Thumb values are always kept in ascending order and at least `min_distance` (and at most
+/// `max_distance`) apart; [`RangeBehavior`] decides how a thumb moved into a neighbour reacts.
+///
+/// # Examples
+/// ```rust
+/// use slider_rs::common::{KeyAction, SliderModel};
+///
+/// let mut model = SliderModel::new(0.0, 100.0, 5.0).with_value(42.0);
+/// assert_eq!(model.value(), 40.0);
+///
+/// let change = model.apply_key(0, KeyAction::Increment).unwrap();
+/// assert_eq!((change.previous, change.value), (40.0, 45.0));
+/// assert_eq!(model.percent(model.value()), 45.0);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct SliderModel {
+    min: f64,
+    max: f64,
+    step: f64,
+    keyboard_step: f64,
+    page_step: Option<f64>,
+    min_distance: f64,
+    max_distance: Option<f64>,
+    behavior: RangeBehavior,
+    scale: Scale,
+    snap_points: Vec<f64>,
+    fill_origin: Option<f64>,
+    origin_detent: Option<f64>,
+    values: Vec<f64>,
+}
+
+impl SliderModel {
+    /// Creates a single-thumb model positioned at `min`.
+    ///
+    /// Swapped bounds are reordered; a `step` of `0.0` (or less) disables snapping.
+    pub fn new(min: f64, max: f64, step: f64) -> Self {
+        let (min, max) = if max < min { (max, min) } else { (min, max) };
+        Self {
+            min,
+            max,
+            step: step.max(0.0),
+            keyboard_step: step.max(0.0),
+            page_step: None,
+            min_distance: 0.0,
+            max_distance: None,
+            behavior: RangeBehavior::default(),
+            scale: Scale::default(),
+            snap_points: Vec::new(),
+            fill_origin: None,
+            origin_detent: None,
+            values: vec![min],
+        }
+    }
+
+    /// Sets a single thumb value.
+    pub fn with_value(self, value: f64) -> Self {
+        self.with_values(vec![value])
+    }
+
+    /// Sets two thumb values for a range slider.
+    pub fn with_range(self, (start, end): (f64, f64)) -> Self {
+        self.with_values(vec![start, end])
+    }
+
+    /// Sets all thumb values. An empty list keeps a single thumb at `min`.
+    pub fn with_values(mut self, values: Vec<f64>) -> Self {
+        self.values = if values.is_empty() {
+            vec![self.min]
+        } else {
+            values.into_iter().map(|v| self.normalize(v)).collect()
+        };
+        self.settle();
+        self
+    }
+
+    /// Sets the smallest allowed gap between neighbouring thumbs.
+    pub fn with_min_distance(mut self, min_distance: f64) -> Self {
+        self.min_distance = min_distance.abs();
+        self.settle();
+        self
+    }
+
+    /// Sets the largest allowed gap between neighbouring thumbs.
+    pub fn with_max_distance(mut self, max_distance: Option<f64>) -> Self {
+        self.max_distance = max_distance.map(f64::abs);
+        self.settle();
+        self
+    }
+
+    /// Sets how a thumb reacts when moved into a neighbour.
+    pub fn with_range_behavior(mut self, behavior: RangeBehavior) -> Self {
+        self.behavior = behavior;
+        self
+    }
+
+    /// Sets how values are distributed along the track.
+    pub fn with_scale(mut self, scale: Scale) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Restricts values to the given points instead of the step grid, e.g. to snap to marks.
+    ///
+    /// Points outside `[min, max]` are dropped; an empty list restores the step grid.
+    pub fn with_snap_points(mut self, points: Vec<f64>) -> Self {
+        let mut points: Vec<f64> = points
+            .into_iter()
+            .filter(|p| (self.min..=self.max).contains(p))
+            .collect();
+        points.sort_by(f64::total_cmp);
+        points.dedup();
+        self.snap_points = points;
+        self.values = self.values.iter().map(|&v| self.normalize(v)).collect();
+        self.settle();
+        self
+    }
+
+    /// Sets the increment used by [`KeyAction`]s. Defaults to `step`.
+    pub fn with_keyboard_step(mut self, keyboard_step: f64) -> Self {
+        self.keyboard_step = keyboard_step.abs();
+        self
+    }
+
+    /// Sets the increment used by PageUp, PageDown and Shift+arrow keys.
+    ///
+    /// Defaults to a tenth of the range, rounded to the step grid.
+    pub fn with_page_step(mut self, page_step: Option<f64>) -> Self {
+        self.page_step = page_step.map(f64::abs);
+        self
+    }
+
+    /// Anchors the fill of a single-thumb slider at `origin` instead of `min`, so it runs from
+    /// the origin to the thumb in either direction.
+    ///
+    /// The origin is snapped like any value, to the step grid or the snap points, so the
+    /// detent never pulls a thumb onto a value the keyboard cannot reach.
+    pub fn with_fill_origin(mut self, origin: Option<f64>) -> Self {
+        self.fill_origin = origin;
+        self
+    }
+
+    /// Sets how close to the fill origin a dragged thumb snaps onto it, see
+    /// [`SliderModel::detent`].
+    pub fn with_origin_detent(mut self, tolerance: Option<f64>) -> Self {
+        self.origin_detent = tolerance.map(f64::abs);
+        self
+    }
+
+    pub fn min(&self) -> f64 {
+        self.min
+    }
+
+    pub fn max(&self) -> f64 {
+        self.max
+    }
+
+    pub fn step(&self) -> f64 {
+        self.step
+    }
+
+    pub fn keyboard_step(&self) -> f64 {
+        self.keyboard_step
+    }
+
+    /// The increment of a page step, see [`SliderModel::with_page_step`].
+    pub fn page_step(&self) -> f64 {
+        if let Some(page_step) = self.page_step {
+            return page_step;
+        }
+        let tenth = (self.max - self.min) / 10.0;
+        if self.step > 0.0 {
+            (tenth / self.step).round().max(1.0) * self.step
+        } else {
+            tenth
+        }
+    }
+
+    pub fn min_distance(&self) -> f64 {
+        self.min_distance
+    }
+
+    pub fn max_distance(&self) -> Option<f64> {
+        self.max_distance
+    }
+
+    pub fn range_behavior(&self) -> RangeBehavior {
+        self.behavior
+    }
+
+    pub fn scale(&self) -> &Scale {
+        &self.scale
+    }
+
+    pub fn snap_points(&self) -> &[f64] {
+        &self.snap_points
+    }
+
+    /// The fill origin, snapped with [`SliderModel::normalize`].
+    pub fn fill_origin(&self) -> Option<f64> {
+        self.fill_origin.map(|origin| self.normalize(origin))
+    }
+
+    pub fn origin_detent(&self) -> Option<f64> {
+        self.origin_detent
+    }
+
+    /// All thumb values in thumb order.
+    pub fn values(&self) -> &[f64] {
+        &self.values
+    }
+
+    /// Value of the first thumb.
+    pub fn value(&self) -> f64 {
+        self.values[0]
+    }
+
+    /// Values of the first and last thumb.
+    pub fn range(&self) -> (f64, f64) {
+        (self.values[0], self.values[self.values.len() - 1])
+    }
+
+    /// Value of a given thumb, if it exists.
+    pub fn thumb_value(&self, thumb: usize) -> Option<f64> {
+        self.values.get(thumb).copied()
+    }
+
+    /// Index of the thumb a pointer at `value` should grab.
+    ///
+    /// Among thumbs sharing the closest value, the last one is picked above them and the
+    /// first one below them, so stacked thumbs can always be pulled apart.
+    pub fn closest_thumb(&self, value: f64) -> usize {
+        let mut closest = 0;
+        for (thumb, &v) in self.values.iter().enumerate() {
+            let distance = (v - value).abs();
+            let best = (self.values[closest] - value).abs();
+            if distance < best || (distance == best && v <= value && thumb > closest) {
+                closest = thumb;
+            }
+        }
+        closest
+    }
+
+    /// The `step` attribute for a native range input (`"any"` when snapping is disabled).
+    pub fn step_attr(&self) -> String {
+        if self.step == 0.0 {
+            "any".to_string()
+        } else {
+            self.step.to_string()
+        }
+    }
+
+    /// Clamps `value` to the bounds and snaps it to the step grid (or the snap points).
+    pub fn normalize(&self, value: f64) -> f64 {
+        if value.is_nan() {
+            return self.min;
+        }
+        let value = value.clamp(self.min, self.max);
+        if !self.snap_points.is_empty() {
+            return self
+                .snap_points
+                .iter()
+                .copied()
+                .min_by(|a, b| (a - value).abs().total_cmp(&(b - value).abs()))
+                .unwrap_or(value);
+        }
+        if self.step == 0.0 {
+            return value;
+        }
+        let steps = ((value - self.min) / self.step).round();
+        let snapped = self.round_to_grid(self.min + steps * self.step);
+        if snapped > self.max {
+            self.round_to_grid(snapped - self.step)
+        } else {
+            snapped
+        }
+    }
+
+    /// Position of `value` along the track, from `0.0` to `100.0`.
+    pub fn percent(&self, value: f64) -> f64 {
+        let value = value.clamp(self.min, self.max);
+        self.scale.to_position(value, self.min, self.max) * 100.0
+    }
+
+    /// Start and end of the fill in percent of the track: from the start of the track (or the
+    /// fill origin) to a single thumb, or between the outer thumbs.
+    pub fn fill_percent(&self) -> (f64, f64) {
+        if self.values.len() > 1 {
+            let (start, end) = self.range();
+            (self.percent(start), self.percent(end))
+        } else if let Some(origin) = self.fill_origin() {
+            let (origin, value) = (self.percent(origin), self.percent(self.value()));
+            (origin.min(value), origin.max(value))
+        } else {
+            (0.0, self.percent(self.value()))
+        }
+    }
+
+    /// Snaps `value` onto the fill origin when it lies within the origin detent, and returns it
+    /// unchanged otherwise.
+    ///
+    /// Adapters apply the detent to pointer input only, so the keyboard can still step away
+    /// from the origin.
+    pub fn detent(&self, value: f64) -> f64 {
+        match (self.fill_origin(), self.origin_detent) {
+            (Some(origin), Some(tolerance)) if (value - origin).abs() <= tolerance => origin,
+            _ => value,
+        }
+    }
+
+    /// Normalized value at a track position given in percent.
+    pub fn value_from_percent(&self, percent: f64) -> f64 {
+        let percent = percent.clamp(0.0, 100.0);
+        self.normalize(self.scale.to_value(percent / 100.0, self.min, self.max))
+    }
+
+    /// The `min` and `max` attributes of a native range input.
+    ///
+    /// A native input is always linear, so with a non-linear [`Scale`] it works on the track
+    /// position (`0` to `100`) instead of on the value.
+    pub fn input_bounds(&self) -> (f64, f64) {
+        if self.scale.is_linear() {
+            (self.min, self.max)
+        } else {
+            (0.0, 100.0)
+        }
+    }
+
+    /// The `step` attribute of a native range input, see [`SliderModel::input_bounds`].
+    pub fn input_step(&self) -> String {
+        if self.scale.is_linear() {
+            self.step_attr()
+        } else {
+            "any".to_string()
+        }
+    }
+
+    /// Converts a value into the value space of a native range input.
+    pub fn to_input(&self, value: f64) -> f64 {
+        if self.scale.is_linear() {
+            value
+        } else {
+            self.percent(value)
+        }
+    }
+
+    /// Converts a native range input value back into a slider value.
+    pub fn from_input(&self, input: f64) -> f64 {
+        if self.scale.is_linear() {
+            input
+        } else {
+            self.value_from_percent(input)
+        }
+    }
+
+    /// Moves a thumb to `value`, returning the change if the thumb actually moved.
+    ///
+    /// The value is constrained by the neighbouring thumbs according to the [`RangeBehavior`].
+    pub fn set_value(&mut self, thumb: usize, value: f64) -> Option<SliderChange> {
+        let previous = self.thumb_value(thumb)?;
+        let before = self.values.clone();
+        let value = self.normalize(value);
+        let thumb = match self.behavior {
+            RangeBehavior::Block => {
+                let (low, high) = self.neighbour_bounds(thumb);
+                self.values[thumb] = self.normalize_within(value, low, high);
+                thumb
+            }
+            RangeBehavior::Push => {
+                let last = self.values.len() - 1;
+                let low = self.min + thumb as f64 * self.min_distance;
+                let high = self.max - (last - thumb) as f64 * self.min_distance;
+                self.values[thumb] = self.normalize_within(value, low, high);
+                self.push_neighbours(thumb);
+                thumb
+            }
+            RangeBehavior::Swap => {
+                self.values.remove(thumb);
+                let index = self.values.partition_point(|&v| v <= value);
+                self.values.insert(index, value);
+                let (low, high) = self.neighbour_bounds(index);
+                self.values[index] = self.normalize_within(value, low, high);
+                index
+            }
+        };
+        if self.values == before {
+            return None;
+        }
+        Some(SliderChange {
+            thumb,
+            value: self.values[thumb],
+            previous,
+            source: ChangeSource::Programmatic,
+            committed: false,
+        })
+    }
+
+    /// Moves a thumb by `delta` from its current value.
+    pub fn step_by(&mut self, thumb: usize, delta: f64) -> Option<SliderChange> {
+        let current = self.thumb_value(thumb)?;
+        self.set_value(thumb, current + delta)
+    }
+
+    /// Applies a keyboard action to a thumb.
+    ///
+    /// `Home` and `End` move the thumb as far as its neighbours allow. With snap points the
+    /// arrow keys move to the next point in the pressed direction, and page steps move to the
+    /// farthest point within a page (at least the next one).
+    ///
+    /// With a non-linear [`Scale`] the thumb travels the same distance on the track that the
+    /// increment covers on a linear one, and always moves by at least one `step`.
+    pub fn apply_key(&mut self, thumb: usize, action: KeyAction) -> Option<SliderChange> {
+        self.move_by_key(thumb, action)
+            .map(|change| change.with_source(ChangeSource::Keyboard))
+    }
+
+    /// Moves a thumb one `keyboard_step` for a wheel event, up for a negative `delta_y`
+    /// (scrolling up) and down for a positive one.
+    pub fn apply_wheel(&mut self, thumb: usize, delta_y: f64) -> Option<SliderChange> {
+        let action = if delta_y < 0.0 {
+            KeyAction::Increment
+        } else if delta_y > 0.0 {
+            KeyAction::Decrement
+        } else {
+            return None;
+        };
+        self.move_by_key(thumb, action)
+            .map(|change| change.with_source(ChangeSource::Wheel))
+    }
+
+    /// Moves every thumb by `delta`, keeping the distances between them, and returns the
+    /// change of the first thumb if the range moved.
+    ///
+    /// The outer thumbs stop at `min` and `max`, and the shift snaps to whole steps so thumbs
+    /// on the step grid stay on it. With snap points it takes the closest shift that leaves
+    /// every thumb on a snap point.
+    pub fn shift_range(&mut self, delta: f64) -> Option<SliderChange> {
+        let (start, end) = self.range();
+        let mut delta = delta.clamp(self.min - start, self.max - end);
+        if !self.snap_points.is_empty() {
+            delta = self
+                .range_shifts()
+                .into_iter()
+                .min_by(|a, b| (a - delta).abs().total_cmp(&(b - delta).abs()))
+                .unwrap_or(0.0);
+        } else if self.step > 0.0 {
+            let low = ((self.min - start) / self.step).ceil();
+            let high = ((self.max - end) / self.step).floor();
+            delta = (delta / self.step).round().clamp(low, high) * self.step;
+        }
+        if delta == 0.0 || delta.is_nan() {
+            return None;
+        }
+        self.values = self
+            .values
+            .iter()
+            .map(|&value| {
+                if self.snap_points.is_empty() {
+                    self.round_to_grid(value + delta).clamp(self.min, self.max)
+                } else {
+                    self.normalize(value + delta)
+                }
+            })
+            .collect();
+        Some(SliderChange {
+            thumb: 0,
+            value: self.values[0],
+            previous: start,
+            source: ChangeSource::Programmatic,
+            committed: false,
+        })
+    }
+
+    /// Applies a keyboard action to the whole range, see [`SliderModel::shift_range`].
+    ///
+    /// Arrow and page keys move the range by a keyboard or page step; `Home` and `End` move it
+    /// against `min` or `max`.
+    pub fn apply_range_key(&mut self, action: KeyAction) -> Option<SliderChange> {
+        let (start, end) = self.range();
+        let delta = match action {
+            KeyAction::Increment => self.keyboard_step,
+            KeyAction::Decrement => -self.keyboard_step,
+            KeyAction::PageIncrement => self.page_step(),
+            KeyAction::PageDecrement => -self.page_step(),
+            KeyAction::Home => self.min - start,
+            KeyAction::End => self.max - end,
+        };
+        let page = matches!(action, KeyAction::PageIncrement | KeyAction::PageDecrement);
+        let delta =
+            if self.snap_points.is_empty() || matches!(action, KeyAction::Home | KeyAction::End) {
+                delta
+            } else {
+                // Like a single thumb, step to the next shift that keeps the thumbs on snap points.
+                let reach = if page { delta } else { 0.0 };
+                let mut shifts = self.range_shifts().into_iter();
+                if delta > 0.0 {
+                    shifts
+                        .clone()
+                        .rfind(|&d| d > 0.0 && d <= reach)
+                        .or_else(|| shifts.find(|&d| d > 0.0))?
+                } else {
+                    shifts
+                        .clone()
+                        .find(|&d| d < 0.0 && d >= reach)
+                        .or_else(|| shifts.rfind(|&d| d < 0.0))?
+                }
+            };
+        self.shift_range(delta)
+            .map(|change| change.with_source(ChangeSource::Keyboard))
+    }
+
+    fn move_by_key(&mut self, thumb: usize, action: KeyAction) -> Option<SliderChange> {
+        let delta = match action {
+            KeyAction::Increment => self.keyboard_step,
+            KeyAction::Decrement => -self.keyboard_step,
+            KeyAction::PageIncrement => self.page_step(),
+            KeyAction::PageDecrement => -self.page_step(),
+            KeyAction::Home => return self.set_value(thumb, self.min),
+            KeyAction::End => return self.set_value(thumb, self.max),
+        };
+        let page = matches!(action, KeyAction::PageIncrement | KeyAction::PageDecrement);
+        if !self.snap_points.is_empty() {
+            let current = self.thumb_value(thumb)?;
+            let reach = if page { delta } else { 0.0 };
+            let mut points = self.snap_points.iter().copied();
+            let target = if delta > 0.0 {
+                points
+                    .clone()
+                    .rfind(|&p| p > current && p <= current + reach)
+                    .or_else(|| points.find(|&p| p > current))
+            } else {
+                points
+                    .clone()
+                    .find(|&p| p < current && p >= current + reach)
+                    .or_else(|| points.rfind(|&p| p < current))
+            };
+            return self.set_value(thumb, target?);
+        }
+        if self.scale.is_linear() {
+            return self.step_by(thumb, delta);
+        }
+        let current = self.thumb_value(thumb)?;
+        let span = self.max - self.min;
+        if span == 0.0 {
+            return None;
+        }
+        let mut target = self.value_from_percent(self.percent(current) + delta / span * 100.0);
+        if target == current {
+            target = current + self.step.max(f64::EPSILON).copysign(delta);
+        }
+        self.set_value(thumb, target)
+    }
+
+    /// Every value on the step grid from `min` to `max`, used for ticks and step labels.
+    ///
+    /// Without a step only the two bounds are returned. With a non-linear [`Scale`] the values
+    /// are spread evenly along the track instead, at most ten intervals apart.
+    pub fn step_values(&self) -> Vec<f64> {
+        if self.step == 0.0 {
+            return vec![self.min, self.max];
+        }
+        let count = ((self.max - self.min) / self.step + 1e-9).floor() as usize;
+        if !self.scale.is_linear() {
+            let count = count.clamp(1, 10);
+            let mut values: Vec<f64> = (0..=count)
+                .map(|i| self.value_from_percent(i as f64 * 100.0 / count as f64))
+                .collect();
+            values.dedup();
+            return values;
+        }
+        (0..=count)
+            .map(|i| self.round_to_grid(self.min + i as f64 * self.step))
+            .collect()
+    }
+
+    /// Position of `value` in [`SliderModel::step_values`], or `None` when it lies between them.
+    pub fn step_index(&self, value: f64) -> Option<usize> {
+        self.step_values()
+            .iter()
+            .position(|&step| (step - value).abs() < 1e-9)
+    }
+
+    /// [`SliderModel::step_values`] in the value space of a native range input, for the
+    /// `<datalist>` that draws the ticks.
+    pub fn tick_values(&self) -> Vec<f64> {
+        self.step_values()
+            .into_iter()
+            .map(|value| self.to_input(value))
+            .collect()
+    }
+
+    /// Range a thumb may occupy given its neighbours, `min_distance` and `max_distance`.
+    fn neighbour_bounds(&self, thumb: usize) -> (f64, f64) {
+        let mut low = self.min;
+        let mut high = self.max;
+        if thumb > 0 {
+            let below = self.values[thumb - 1];
+            low = low.max(below + self.min_distance);
+            if let Some(max_distance) = self.max_distance {
+                high = high.min(below + max_distance);
+            }
+        }
+        if let Some(&above) = self.values.get(thumb + 1) {
+            high = high.min(above - self.min_distance);
+            if let Some(max_distance) = self.max_distance {
+                low = low.max(above - max_distance);
+            }
+        }
+        (low, high.max(low))
+    }
+
+    /// Clamps an already normalized value into `[low, high]`, staying on the step grid.
+    fn normalize_within(&self, value: f64, low: f64, high: f64) -> f64 {
+        if value < low {
+            self.snap_up(low)
+        } else if value > high {
+            self.snap_down(high)
+        } else {
+            value
+        }
+    }
+
+    fn snap_up(&self, value: f64) -> f64 {
+        if let Some(&last) = self.snap_points.last() {
+            return self
+                .snap_points
+                .iter()
+                .copied()
+                .find(|&p| p >= value - 1e-9)
+                .unwrap_or(last);
+        }
+        if self.step == 0.0 {
+            return value.clamp(self.min, self.max);
+        }
+        let steps = ((value - self.min) / self.step - 1e-9).ceil();
+        self.round_to_grid(self.min + steps * self.step)
+            .clamp(self.min, self.max)
+    }
+
+    fn snap_down(&self, value: f64) -> f64 {
+        if let Some(&first) = self.snap_points.first() {
+            return self
+                .snap_points
+                .iter()
+                .rev()
+                .copied()
+                .find(|&p| p <= value + 1e-9)
+                .unwrap_or(first);
+        }
+        if self.step == 0.0 {
+            return value.clamp(self.min, self.max);
+        }
+        let steps = ((value - self.min) / self.step + 1e-9).floor();
+        self.round_to_grid(self.min + steps * self.step)
+            .clamp(self.min, self.max)
+    }
+
+    /// Moves the thumbs around `thumb` so every gap respects the distance limits.
+    fn push_neighbours(&mut self, thumb: usize) {
+        for i in thumb + 1..self.values.len() {
+            let below = self.values[i - 1];
+            let mut value = self.values[i];
+            if value < below + self.min_distance {
+                value = self.snap_up(below + self.min_distance);
+            }
+            if let Some(max_distance) = self.max_distance {
+                if value > below + max_distance {
+                    value = self.snap_down(below + max_distance);
+                }
+            }
+            self.values[i] = value;
+        }
+        for i in (0..thumb).rev() {
+            let above = self.values[i + 1];
+            let mut value = self.values[i];
+            if value > above - self.min_distance {
+                value = self.snap_down(above - self.min_distance);
+            }
+            if let Some(max_distance) = self.max_distance {
+                if value < above - max_distance {
+                    value = self.snap_up(above - max_distance);
+                }
+            }
+            self.values[i] = value;
+        }
+    }
+
+    /// Sorts the values and spreads them so the distance limits hold.
+    fn settle(&mut self) {
+        self.values.sort_by(f64::total_cmp);
+        self.push_neighbours(0);
+        self.push_neighbours(self.values.len() - 1);
+    }
+
+    /// The shifts of the whole range, in ascending order, that move every thumb onto a snap
+    /// point.
+    fn range_shifts(&self) -> Vec<f64> {
+        let Some(&first) = self.values.first() else {
+            return Vec::new();
+        };
+        let on_point = |value: f64| self.snap_points.iter().any(|&p| (p - value).abs() < 1e-9);
+        self.snap_points
+            .iter()
+            .map(|&point| point - first)
+            .filter(|&delta| self.values.iter().all(|&value| on_point(value + delta)))
+            .collect()
+    }
+
+    /// Rounds away floating point noise such as `0.30000000000000004` for a `0.1` step.
+    fn round_to_grid(&self, value: f64) -> f64 {
+        let factor = 10f64.powi(decimals(self.step).max(decimals(self.min)) as i32);
+        (value * factor).round() / factor
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_defaults_to_min() {
+        let model = SliderModel::new(10.0, 20.0, 1.0);
+        assert_eq!(model.values(), &[10.0]);
+        assert_eq!(model.keyboard_step(), 1.0);
+    }
+
+    #[test]
+    fn fill_spans_the_outer_thumbs() {
+        let model = SliderModel::new(0.0, 10.0, 1.0).with_value(4.0);
+        assert_eq!(model.fill_percent(), (0.0, 40.0));
+        let model = model.with_values(vec![2.0, 5.0, 8.0]);
+        assert_eq!(model.fill_percent(), (20.0, 80.0));
+    }
+
+    #[test]
+    fn new_reorders_swapped_bounds() {
+        let model = SliderModel::new(20.0, 10.0, 1.0);
+        assert_eq!((model.min(), model.max()), (10.0, 20.0));
+    }
+
+    #[test]
+    fn normalize_clamps_to_bounds() {
+        let model = SliderModel::new(0.0, 10.0, 1.0);
+        assert_eq!(model.normalize(-5.0), 0.0);
+        assert_eq!(model.normalize(15.0), 10.0);
+        assert_eq!(model.normalize(f64::NAN), 0.0);
+    }
+
+    #[test]
+    fn normalize_snaps_to_step_grid() {
+        let model = SliderModel::new(0.0, 100.0, 5.0);
+        assert_eq!(model.normalize(42.0), 40.0);
+        assert_eq!(model.normalize(42.5), 45.0);
+        assert_eq!(model.normalize(43.0), 45.0);
+    }
+
+    #[test]
+    fn normalize_snaps_relative_to_min() {
+        let model = SliderModel::new(3.0, 13.0, 5.0);
+        assert_eq!(model.normalize(6.0), 8.0);
+        assert_eq!(model.normalize(5.0), 3.0);
+    }
+
+    #[test]
+    fn normalize_keeps_fractional_min() {
+        let model = SliderModel::new(0.05, 10.05, 1.0);
+        assert_eq!(model.normalize(3.3), 3.05);
+        assert_eq!(model.step_values()[1], 1.05);
+    }
+
+    #[test]
+    fn normalize_never_snaps_past_max() {
+        let model = SliderModel::new(0.0, 10.0, 3.0);
+        assert_eq!(model.normalize(10.0), 9.0);
+    }
+
+    #[test]
+    fn normalize_removes_float_noise() {
+        let model = SliderModel::new(0.0, 1.0, 0.1);
+        assert_eq!(model.normalize(0.3), 0.3);
+        assert_eq!(model.normalize(0.7000001), 0.7);
+    }
+
+    #[test]
+    fn zero_step_disables_snapping() {
+        let model = SliderModel::new(0.0, 1.0, 0.0);
+        assert_eq!(model.normalize(0.123), 0.123);
+        assert_eq!(model.step_attr(), "any");
+    }
+
+    #[test]
+    fn with_value_and_range_normalize() {
+        let model = SliderModel::new(0.0, 10.0, 1.0).with_value(3.4);
+        assert_eq!(model.value(), 3.0);
+
+        let model = SliderModel::new(0.0, 10.0, 1.0).with_range((-1.0, 12.0));
+        assert_eq!(model.range(), (0.0, 10.0));
+    }
+
+    #[test]
+    fn with_empty_values_keeps_one_thumb() {
+        let model = SliderModel::new(0.0, 10.0, 1.0).with_values(vec![]);
+        assert_eq!(model.values(), &[0.0]);
+    }
+
+    #[test]
+    fn percent_maps_value_to_track() {
+        let model = SliderModel::new(-50.0, 50.0, 1.0);
+        assert_eq!(model.percent(-50.0), 0.0);
+        assert_eq!(model.percent(0.0), 50.0);
+        assert_eq!(model.percent(50.0), 100.0);
+        assert_eq!(model.percent(80.0), 100.0);
+    }
+
+    #[test]
+    fn percent_handles_empty_span() {
+        let model = SliderModel::new(5.0, 5.0, 1.0);
+        assert_eq!(model.percent(5.0), 0.0);
+    }
+
+    #[test]
+    fn value_from_percent_is_normalized() {
+        let model = SliderModel::new(0.0, 200.0, 10.0);
+        assert_eq!(model.value_from_percent(50.0), 100.0);
+        assert_eq!(model.value_from_percent(33.0), 70.0);
+        assert_eq!(model.value_from_percent(150.0), 200.0);
+    }
+
+    #[test]
+    fn set_value_reports_change() {
+        let mut model = SliderModel::new(0.0, 10.0, 1.0).with_range((2.0, 8.0));
+        let change = model.set_value(1, 6.2).unwrap();
+        assert_eq!(
+            change,
+            SliderChange {
+                thumb: 1,
+                value: 6.0,
+                previous: 8.0,
+                source: ChangeSource::Programmatic,
+                committed: false,
+            }
+        );
+        assert_eq!(model.range(), (2.0, 6.0));
+    }
+
+    #[test]
+    fn set_value_ignores_noop_and_unknown_thumbs() {
+        let mut model = SliderModel::new(0.0, 10.0, 1.0).with_value(4.0);
+        assert_eq!(model.set_value(0, 4.2), None);
+        assert_eq!(model.set_value(3, 5.0), None);
+        assert_eq!(model.value(), 4.0);
+    }
+
+    #[test]
+    fn apply_key_uses_keyboard_step_and_clamps() {
+        let mut model = SliderModel::new(0.0, 10.0, 1.0)
+            .with_value(9.0)
+            .with_keyboard_step(2.0);
+        let change = model.apply_key(0, KeyAction::Increment).unwrap();
+        assert_eq!(change.value, 10.0);
+        assert_eq!(model.apply_key(0, KeyAction::Increment), None);
+        assert_eq!(model.apply_key(0, KeyAction::Decrement).unwrap().value, 8.0);
+    }
+
+    #[test]
+    fn key_action_from_key() {
+        assert_eq!(KeyAction::from_key("ArrowUp"), Some(KeyAction::Increment));
+        assert_eq!(
+            KeyAction::from_key("ArrowRight"),
+            Some(KeyAction::Increment)
+        );
+        assert_eq!(KeyAction::from_key("ArrowDown"), Some(KeyAction::Decrement));
+        assert_eq!(KeyAction::from_key("ArrowLeft"), Some(KeyAction::Decrement));
+        assert_eq!(KeyAction::from_key("Enter"), None);
+    }
+
+    #[test]
+    fn key_events_follow_the_aria_pattern() {
+        use KeyAction::*;
+        assert_eq!(KeyAction::from_key("Home"), Some(Home));
+        assert_eq!(KeyAction::from_key("End"), Some(End));
+        assert_eq!(KeyAction::from_key("PageUp"), Some(PageIncrement));
+        assert_eq!(KeyAction::from_key("PageDown"), Some(PageDecrement));
+        assert_eq!(
+            KeyAction::from_key_event("ArrowRight", true, false),
+            Some(PageIncrement)
+        );
+        assert_eq!(
+            KeyAction::from_key_event("ArrowRight", false, true),
+            Some(Decrement)
+        );
+        assert_eq!(
+            KeyAction::from_key_event("ArrowLeft", true, true),
+            Some(PageIncrement)
+        );
+        assert_eq!(
+            KeyAction::from_key_event("ArrowUp", false, true),
+            Some(Increment)
+        );
+    }
+
+    #[test]
+    fn home_end_and_page_keys() {
+        let mut model = SliderModel::new(0.0, 100.0, 1.0).with_value(42.0);
+        assert_eq!(model.page_step(), 10.0);
+        assert_eq!(
+            model.apply_key(0, KeyAction::PageIncrement).unwrap().value,
+            52.0
+        );
+        assert_eq!(model.apply_key(0, KeyAction::Home).unwrap().value, 0.0);
+        assert_eq!(model.apply_key(0, KeyAction::Home), None);
+        assert_eq!(model.apply_key(0, KeyAction::End).unwrap().value, 100.0);
+
+        let mut model = model.with_page_step(Some(25.0));
+        assert_eq!(
+            model.apply_key(0, KeyAction::PageDecrement).unwrap().value,
+            75.0
+        );
+    }
+
+    #[test]
+    fn changes_report_their_source() {
+        let mut model = SliderModel::new(0.0, 10.0, 1.0).with_value(5.0);
+        let change = model.apply_key(0, KeyAction::Increment).unwrap();
+        assert_eq!((change.source, change.value), (ChangeSource::Keyboard, 6.0));
+        let change = model.apply_wheel(0, 120.0).unwrap();
+        assert_eq!((change.source, change.value), (ChangeSource::Wheel, 5.0));
+        assert_eq!(model.apply_wheel(0, 0.0), None);
+        let change = model.set_value(0, 9.0).unwrap();
+        assert_eq!(change.source, ChangeSource::Programmatic);
+        let change = change.with_source(ChangeSource::Pointer).committed();
+        assert!(change.committed);
+        assert_eq!(change.source, ChangeSource::Pointer);
+    }
+
+    #[test]
+    fn home_and_end_stop_at_neighbours() {
+        let mut model = SliderModel::new(0.0, 100.0, 1.0)
+            .with_range((20.0, 60.0))
+            .with_min_distance(5.0);
+        assert_eq!(
+            model.clone().apply_key(1, KeyAction::Home).unwrap().value,
+            25.0
+        );
+        assert_eq!(model.apply_key(0, KeyAction::End).unwrap().value, 55.0);
+    }
+
+    #[test]
+    fn page_keys_skip_snap_points() {
+        let mut model = SliderModel::new(0.0, 100.0, 1.0)
+            .with_snap_points(vec![0.0, 5.0, 8.0, 30.0, 100.0])
+            .with_page_step(Some(10.0));
+        assert_eq!(
+            model.apply_key(0, KeyAction::PageIncrement).unwrap().value,
+            8.0
+        );
+        assert_eq!(
+            model.apply_key(0, KeyAction::PageIncrement).unwrap().value,
+            30.0
+        );
+        assert_eq!(
+            model.apply_key(0, KeyAction::Increment).unwrap().value,
+            100.0
+        );
+    }
+
+    #[test]
+    fn step_values_cover_the_track() {
+        let model = SliderModel::new(0.0, 1.0, 0.25);
+        assert_eq!(model.step_values(), vec![0.0, 0.25, 0.5, 0.75, 1.0]);
+
+        let model = SliderModel::new(0.0, 1.0, 0.1);
+        assert_eq!(model.step_values().len(), 11);
+        assert_eq!(model.step_values()[3], 0.3);
+
+        let model = SliderModel::new(0.0, 10.0, 0.0);
+        assert_eq!(model.step_values(), vec![0.0, 10.0]);
+    }
+
+    #[test]
+    fn values_are_sorted() {
+        let model = SliderModel::new(0.0, 100.0, 1.0).with_range((80.0, 20.0));
+        assert_eq!(model.range(), (20.0, 80.0));
+    }
+
+    #[test]
+    fn block_stops_at_neighbour() {
+        let mut model = SliderModel::new(0.0, 100.0, 1.0).with_range((20.0, 80.0));
+        let change = model.set_value(0, 95.0).unwrap();
+        assert_eq!(change.value, 80.0);
+        assert_eq!(model.range(), (80.0, 80.0));
+        assert_eq!(model.set_value(1, 10.0), None);
+    }
+
+    #[test]
+    fn block_keeps_min_distance() {
+        let mut model = SliderModel::new(0.0, 100.0, 5.0)
+            .with_range((20.0, 80.0))
+            .with_min_distance(12.0);
+        assert_eq!(model.set_value(0, 75.0).unwrap().value, 65.0);
+        assert_eq!(model.set_value(1, 0.0), None);
+        assert_eq!(model.range(), (65.0, 80.0));
+    }
+
+    #[test]
+    fn block_keeps_max_distance() {
+        let mut model = SliderModel::new(0.0, 100.0, 1.0)
+            .with_range((40.0, 60.0))
+            .with_max_distance(Some(30.0));
+        assert_eq!(model.set_value(1, 100.0).unwrap().value, 70.0);
+        assert_eq!(model.set_value(0, 0.0), None);
+        assert_eq!(model.set_value(0, 50.0).unwrap().value, 50.0);
+        assert_eq!(model.range(), (50.0, 70.0));
+    }
+
+    #[test]
+    fn push_moves_neighbours() {
+        let mut model = SliderModel::new(0.0, 100.0, 1.0)
+            .with_range((20.0, 50.0))
+            .with_min_distance(10.0)
+            .with_range_behavior(RangeBehavior::Push);
+        let change = model.set_value(0, 45.0).unwrap();
+        assert_eq!((change.thumb, change.value), (0, 45.0));
+        assert_eq!(model.range(), (45.0, 55.0));
+    }
+
+    #[test]
+    fn push_stops_when_neighbour_hits_bound() {
+        let mut model = SliderModel::new(0.0, 100.0, 1.0)
+            .with_range((20.0, 50.0))
+            .with_min_distance(10.0)
+            .with_range_behavior(RangeBehavior::Push);
+        model.set_value(0, 99.0);
+        assert_eq!(model.range(), (90.0, 100.0));
+        model.set_value(1, 0.0);
+        assert_eq!(model.range(), (0.0, 10.0));
+    }
+
+    #[test]
+    fn push_pulls_with_max_distance() {
+        let mut model = SliderModel::new(0.0, 100.0, 1.0)
+            .with_range((20.0, 30.0))
+            .with_max_distance(Some(20.0))
+            .with_range_behavior(RangeBehavior::Push);
+        model.set_value(1, 70.0);
+        assert_eq!(model.range(), (50.0, 70.0));
+    }
+
+    #[test]
+    fn swap_reorders_thumbs() {
+        let mut model = SliderModel::new(0.0, 100.0, 1.0)
+            .with_range((20.0, 80.0))
+            .with_range_behavior(RangeBehavior::Swap);
+        let change = model.set_value(0, 90.0).unwrap();
+        assert_eq!(
+            change,
+            SliderChange {
+                thumb: 1,
+                value: 90.0,
+                previous: 20.0,
+                source: ChangeSource::Programmatic,
+                committed: false,
+            }
+        );
+        assert_eq!(model.range(), (80.0, 90.0));
+    }
+
+    #[test]
+    fn swap_respects_min_distance() {
+        let mut model = SliderModel::new(0.0, 100.0, 1.0)
+            .with_range((20.0, 80.0))
+            .with_min_distance(10.0)
+            .with_range_behavior(RangeBehavior::Swap);
+        let change = model.set_value(0, 85.0).unwrap();
+        assert_eq!((change.thumb, change.value), (1, 90.0));
+        assert_eq!(model.range(), (80.0, 90.0));
+    }
+
+    #[test]
+    fn builders_enforce_distance_on_initial_values() {
+        let model = SliderModel::new(0.0, 100.0, 1.0)
+            .with_range((50.0, 52.0))
+            .with_min_distance(10.0);
+        assert_eq!(model.range(), (50.0, 60.0));
+
+        let model = SliderModel::new(0.0, 100.0, 1.0)
+            .with_range((95.0, 100.0))
+            .with_min_distance(10.0);
+        assert_eq!(model.range(), (90.0, 100.0));
+    }
+
+    #[test]
+    fn keyboard_respects_constraints() {
+        let mut model = SliderModel::new(0.0, 10.0, 1.0)
+            .with_range((4.0, 6.0))
+            .with_min_distance(2.0);
+        assert_eq!(model.apply_key(0, KeyAction::Increment), None);
+        assert_eq!(model.apply_key(1, KeyAction::Decrement), None);
+        assert_eq!(model.apply_key(1, KeyAction::Increment).unwrap().value, 7.0);
+    }
+
+    #[test]
+    fn closest_thumb_separates_stacked_thumbs() {
+        let model = SliderModel::new(0.0, 100.0, 1.0).with_values(vec![10.0, 50.0, 50.0, 90.0]);
+        assert_eq!(model.closest_thumb(0.0), 0);
+        assert_eq!(model.closest_thumb(35.0), 1);
+        assert_eq!(model.closest_thumb(55.0), 2);
+        assert_eq!(model.closest_thumb(75.0), 3);
+    }
+
+    #[test]
+    fn many_thumbs_keep_their_order() {
+        let mut model = SliderModel::new(0.0, 100.0, 1.0)
+            .with_values(vec![60.0, 10.0, 30.0, 90.0])
+            .with_min_distance(5.0);
+        assert_eq!(model.values(), &[10.0, 30.0, 60.0, 90.0]);
+
+        model.set_value(1, 70.0);
+        assert_eq!(model.values(), &[10.0, 55.0, 60.0, 90.0]);
+
+        let mut model = model.with_range_behavior(RangeBehavior::Push);
+        model.set_value(1, 90.0);
+        assert_eq!(model.values(), &[10.0, 90.0, 95.0, 100.0]);
+    }
+
+    #[test]
+    fn log_scale_positions_thumbs_and_ticks() {
+        let model = SliderModel::new(20.0, 20_000.0, 1.0)
+            .with_scale(Scale::Log)
+            .with_value(632.0);
+        assert!((model.percent(model.value()) - 50.0).abs() < 0.05);
+        assert_eq!(model.input_bounds(), (0.0, 100.0));
+        assert_eq!(model.input_step(), "any");
+        assert_eq!(model.from_input(model.to_input(200.0)), 200.0);
+        assert_eq!(
+            model.step_values(),
+            vec![
+                20.0, 40.0, 80.0, 159.0, 317.0, 632.0, 1262.0, 2518.0, 5024.0, 10024.0, 20000.0
+            ]
+        );
+        assert_eq!(model.tick_values().len(), 11);
+    }
+
+    #[test]
+    fn linear_scale_keeps_native_input_space() {
+        let model = SliderModel::new(0.0, 10.0, 0.5).with_value(2.5);
+        assert_eq!(model.input_bounds(), (0.0, 10.0));
+        assert_eq!(model.input_step(), "0.5");
+        assert_eq!(model.to_input(2.5), 2.5);
+        assert_eq!(model.tick_values(), model.step_values());
+    }
+
+    #[test]
+    fn keyboard_steps_along_the_track_on_non_linear_scales() {
+        let mut model = SliderModel::new(20.0, 20_000.0, 1.0)
+            .with_scale(Scale::Log)
+            .with_keyboard_step(999.0)
+            .with_value(20.0);
+        let change = model.apply_key(0, KeyAction::Increment).unwrap();
+        assert!((model.percent(change.value) - 5.0).abs() < 0.2);
+
+        let mut model = SliderModel::new(1.0, 1000.0, 1.0)
+            .with_scale(Scale::Log)
+            .with_keyboard_step(0.1)
+            .with_value(500.0);
+        assert_eq!(
+            model.apply_key(0, KeyAction::Decrement).unwrap().value,
+            499.0
+        );
+    }
+
+    #[test]
+    fn step_index_follows_the_step_values() {
+        let model = SliderModel::new(10.0, 20.0, 2.5);
+        assert_eq!(model.step_index(10.0), Some(0));
+        assert_eq!(model.step_index(15.0), Some(2));
+        assert_eq!(model.step_index(99.0), None);
+        let model = SliderModel::new(0.0, 1.0, 0.0);
+        assert_eq!(model.step_index(1.0), Some(1));
+        assert_eq!(model.step_index(0.5), None);
+        // Steps spread along the track: 0, 1, 4, 9, ...
+        let model = SliderModel::new(0.0, 100.0, 1.0).with_scale(Scale::Pow(2.0));
+        assert_eq!(model.step_index(4.0), Some(2));
+        assert_eq!(model.step_index(2.0), None);
+    }
+
+    #[test]
+    fn snap_points_replace_the_step_grid() {
+        let mut model = SliderModel::new(0.0, 100.0, 1.0)
+            .with_snap_points(vec![80.0, 10.0, 150.0, 35.0])
+            .with_value(50.0);
+        assert_eq!(model.snap_points(), &[10.0, 35.0, 80.0]);
+        assert_eq!(model.value(), 35.0);
+        assert_eq!(model.set_value(0, 60.0).unwrap().value, 80.0);
+        assert_eq!(model.apply_key(0, KeyAction::Increment), None);
+        assert_eq!(
+            model.apply_key(0, KeyAction::Decrement).unwrap().value,
+            35.0
+        );
+    }
+
+    #[test]
+    fn snap_points_respect_neighbours() {
+        let mut model = SliderModel::new(0.0, 100.0, 1.0)
+            .with_snap_points(vec![0.0, 25.0, 50.0, 75.0, 100.0])
+            .with_min_distance(10.0)
+            .with_range((25.0, 50.0));
+        assert_eq!(model.set_value(0, 48.0), None);
+        assert_eq!(model.range(), (25.0, 50.0));
+        assert_eq!(model.apply_key(1, KeyAction::Decrement), None);
+    }
+
+    #[test]
+    fn fill_runs_from_the_origin_in_either_direction() {
+        let model = SliderModel::new(-50.0, 50.0, 1.0)
+            .with_fill_origin(Some(0.0))
+            .with_value(25.0);
+        assert_eq!(model.fill_percent(), (50.0, 75.0));
+        assert_eq!(model.clone().with_value(-30.0).fill_percent(), (20.0, 50.0));
+        let model = model.with_fill_origin(Some(80.0));
+        assert_eq!(model.fill_origin(), Some(50.0));
+        assert_eq!(model.fill_percent(), (75.0, 100.0));
+    }
+
+    #[test]
+    fn fill_origin_snaps_to_the_grid_and_the_snap_points() {
+        let model = SliderModel::new(-50.0, 50.0, 5.0).with_fill_origin(Some(1.5));
+        assert_eq!(model.fill_origin(), Some(0.0));
+        let model = model
+            .with_origin_detent(Some(4.0))
+            .with_snap_points(vec![-20.0, 5.0, 30.0]);
+        assert_eq!(model.fill_origin(), Some(5.0));
+        assert_eq!(model.detent(8.0), 5.0);
+    }
+
+    #[test]
+    fn detent_snaps_to_the_origin_within_the_tolerance() {
+        let model = SliderModel::new(-50.0, 50.0, 1.0)
+            .with_fill_origin(Some(0.0))
+            .with_origin_detent(Some(3.0));
+        assert_eq!(model.detent(2.0), 0.0);
+        assert_eq!(model.detent(-3.0), 0.0);
+        assert_eq!(model.detent(4.0), 4.0);
+        assert_eq!(model.clone().with_origin_detent(None).detent(2.0), 2.0);
+        assert_eq!(model.with_fill_origin(None).detent(2.0), 2.0);
+    }
+
+    #[test]
+    fn shift_range_keeps_the_width_and_stops_at_the_bounds() {
+        let mut model = SliderModel::new(0.0, 100.0, 5.0).with_range((20.0, 50.0));
+        let change = model.shift_range(12.0).unwrap();
+        assert_eq!(model.range(), (30.0, 60.0));
+        assert_eq!(
+            (change.thumb, change.value, change.previous),
+            (0, 30.0, 20.0)
+        );
+        model.shift_range(80.0);
+        assert_eq!(model.range(), (70.0, 100.0));
+        assert_eq!(model.shift_range(5.0), None);
+        assert_eq!(model.shift_range(2.0), None);
+        model.apply_range_key(KeyAction::Home);
+        assert_eq!(model.range(), (0.0, 30.0));
+        let change = model.apply_range_key(KeyAction::PageIncrement).unwrap();
+        assert_eq!(change.source, ChangeSource::Keyboard);
+        assert_eq!(model.range(), (10.0, 40.0));
+        model.apply_range_key(KeyAction::End);
+        assert_eq!(model.range(), (70.0, 100.0));
+    }
+
+    #[test]
+    fn shift_range_keeps_the_thumbs_on_snap_points() {
+        let mut model = SliderModel::new(0.0, 100.0, 1.0)
+            .with_snap_points(vec![0.0, 10.0, 25.0, 35.0, 50.0, 60.0, 90.0])
+            .with_range((10.0, 35.0));
+        assert_eq!(model.shift_range(4.0), None);
+        assert_eq!(model.range(), (10.0, 35.0));
+        model.shift_range(30.0).unwrap();
+        assert_eq!(model.range(), (35.0, 60.0));
+        model.shift_range(-12.0).unwrap();
+        assert_eq!(model.range(), (25.0, 50.0));
+        model.apply_range_key(KeyAction::Increment).unwrap();
+        assert_eq!(model.range(), (35.0, 60.0));
+        model.apply_range_key(KeyAction::Home).unwrap();
+        assert_eq!(model.range(), (0.0, 25.0));
+        assert_eq!(model.apply_range_key(KeyAction::Decrement), None);
+        model.apply_range_key(KeyAction::End).unwrap();
+        assert_eq!(model.range(), (35.0, 60.0));
+    }
+}
@@ -0,0 +1,247 @@
+use super::SliderChange;
+use std::cell::RefCell;
+use std::rc::Rc;
+use web_sys::js_sys::Date;
+use web_sys::wasm_bindgen::JsCast;
+use web_sys::wasm_bindgen::closure::Closure;
+
+/// How often the live `on_change` may fire while a thumb moves.
+///
+/// Committed changes are never held back; see [`ChangePacer`].
+///
+/// # Examples
+/// ```rust
+/// use slider_rs::common::RateLimit;
+///
+/// let limit = RateLimit::throttle(100);
+/// assert_eq!(limit.delay(1_000.0, None), None);
+/// assert_eq!(limit.delay(1_040.0, Some(1_000.0)), Some(60.0));
+/// assert_eq!(RateLimit::debounce(250).delay(0.0, None), Some(250.0));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub struct RateLimit {
+    /// At most one change per this many milliseconds; the last change of a burst is sent once
+    /// the interval is over.
+    pub throttle_ms: Option<u32>,
+    /// Only the last change after the slider rested for this many milliseconds. Takes
+    /// precedence over `throttle_ms`.
+    pub debounce_ms: Option<u32>,
+}
+
+impl RateLimit {
+    pub fn throttle(ms: u32) -> Self {
+        Self {
+            throttle_ms: Some(ms),
+            debounce_ms: None,
+        }
+    }
+
+    pub fn debounce(ms: u32) -> Self {
+        Self {
+            throttle_ms: None,
+            debounce_ms: Some(ms),
+        }
+    }
+
+    /// Whether changes go out as they happen.
+    pub fn is_unlimited(&self) -> bool {
+        self.throttle_ms.is_none() && self.debounce_ms.is_none()
+    }
+
+    /// Milliseconds to hold back a change made at `now` when the previous change went out at
+    /// `last`, or `None` to send it right away.
+    pub fn delay(&self, now: f64, last: Option<f64>) -> Option<f64> {
+        if let Some(debounce) = self.debounce_ms {
+            return Some(debounce as f64);
+        }
+        let throttle = self.throttle_ms? as f64;
+        let elapsed = now - last?;
+        (elapsed < throttle).then_some(throttle - elapsed)
+    }
+}
+
+/// Delivers live changes under a [`RateLimit`] and remembers the last one for the commit.
+///
+/// Cloning shares the state, so the adapters keep one pacer per slider and hand clones to
+/// their event handlers.
+#[derive(Clone, Default)]
+pub struct ChangePacer {
+    state: Rc<RefCell<PacerState>>,
+}
+
+#[derive(Default)]
+struct PacerState {
+    limit: RateLimit,
+    /// Sends the change that is being held back.
+    pending: Option<Box<dyn FnOnce()>>,
+    /// Bumped for every timer, so a timer only fires if no later one replaced it.
+    generation: u64,
+    timer_running: bool,
+    last_sent: Option<f64>,
+    uncommitted: Option<SliderChange>,
+}
+
+impl PartialEq for ChangePacer {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.state, &other.state)
+    }
+}
+
+impl ChangePacer {
+    pub fn set_limit(&self, limit: RateLimit) {
+        self.state.borrow_mut().limit = limit;
+    }
+
+    /// Records `change` and runs `send` now or once the rate limit allows it, replacing any
+    /// change still held back.
+    pub fn send(&self, change: SliderChange, send: impl FnOnce() + 'static) {
+        let mut state = self.state.borrow_mut();
+        state.uncommitted = Some(change);
+        if state.limit.is_unlimited() {
+            drop(state);
+            send();
+            return;
+        }
+        let now = Date::now();
+        match state.limit.delay(now, state.last_sent) {
+            None => {
+                state.pending = None;
+                state.last_sent = Some(now);
+                drop(state);
+                send();
+            }
+            Some(delay) => {
+                state.pending = Some(Box::new(send));
+                // A throttled change rides on the running timer; a debounced one restarts it.
+                if !state.timer_running || state.limit.debounce_ms.is_some() {
+                    state.generation += 1;
+                    state.timer_running = true;
+                    let generation = state.generation;
+                    drop(state);
+                    self.start_timer(generation, delay);
+                }
+            }
+        }
+    }
+
+    /// Whether a change is being held back by the rate limit.
+    pub fn is_holding(&self) -> bool {
+        self.state.borrow().pending.is_some()
+    }
+
+    /// The values a slider renders: the `controlled` values when there are any, except while
+    /// a change is held back. The parent only hears of that change once it goes out, so until
+    /// then the `live` values of the change keep the thumb under the pointer.
+    pub fn displayed(&self, controlled: Option<&[f64]>, live: &[f64]) -> Vec<f64> {
+        match controlled {
+            Some(controlled) if !self.is_holding() => controlled.to_vec(),
+            _ => live.to_vec(),
+        }
+    }
+
+    /// Sends any change held back and returns the last change since the previous commit.
+    pub fn commit(&self) -> Option<SliderChange> {
+        self.flush();
+        self.state.borrow_mut().uncommitted.take()
+    }
+
+    fn flush(&self) {
+        let pending = {
+            let mut state = self.state.borrow_mut();
+            state.generation += 1;
+            state.timer_running = false;
+            if state.pending.is_some() {
+                state.last_sent = Some(Date::now());
+            }
+            state.pending.take()
+        };
+        if let Some(send) = pending {
+            send();
+        }
+    }
+
+    fn start_timer(&self, generation: u64, delay: f64) {
+        let pacer = self.clone();
+        let fire = Closure::once_into_js(move || {
+            if pacer.state.borrow().generation == generation {
+                pacer.flush();
+            }
+        });
+        if let Some(window) = web_sys::window() {
+            let _ = window.set_timeout_with_callback_and_timeout_and_arguments_0(
+                fire.unchecked_ref(),
+                delay.ceil() as i32,
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::{ChangeSource, SliderModel};
+
+    #[test]
+    fn unlimited_changes_are_not_delayed() {
+        assert!(RateLimit::default().is_unlimited());
+        assert_eq!(RateLimit::default().delay(10.0, Some(9.0)), None);
+    }
+
+    #[test]
+    fn throttle_holds_back_changes_within_the_interval() {
+        let limit = RateLimit::throttle(100);
+        assert_eq!(limit.delay(500.0, None), None);
+        assert_eq!(limit.delay(530.0, Some(500.0)), Some(70.0));
+        assert_eq!(limit.delay(600.0, Some(500.0)), None);
+    }
+
+    #[test]
+    fn debounce_wins_over_throttle() {
+        let limit = RateLimit {
+            throttle_ms: Some(50),
+            debounce_ms: Some(200),
+        };
+        assert_eq!(limit.delay(1_000.0, None), Some(200.0));
+        assert_eq!(limit.delay(1_000.0, Some(0.0)), Some(200.0));
+    }
+
+    #[test]
+    fn held_back_changes_show_their_live_values() {
+        let pacer = ChangePacer::default();
+        pacer.set_limit(RateLimit::debounce(250));
+        // A debounced drag: the parent still passes 2.0 while the change to 4.0 waits.
+        pacer.state.borrow_mut().pending = Some(Box::new(|| ()));
+        assert_eq!(pacer.displayed(Some(&[2.0]), &[4.0]), [4.0]);
+        pacer.state.borrow_mut().pending.take();
+        assert_eq!(pacer.displayed(Some(&[2.0]), &[4.0]), [2.0]);
+        assert_eq!(pacer.displayed(None, &[4.0]), [4.0]);
+    }
+
+    #[test]
+    fn unlimited_controlled_values_stay_put() {
+        let pacer = ChangePacer::default();
+        let change = SliderChange {
+            thumb: 0,
+            value: 3.0,
+            previous: 2.0,
+            source: ChangeSource::Pointer,
+            committed: false,
+        };
+        pacer.send(change, || ());
+        assert!(!pacer.is_holding());
+        // The parent ignored the change and still passes 2.0.
+        assert_eq!(pacer.displayed(Some(&[2.0]), &[3.0]), [2.0]);
+    }
+
+    #[test]
+    fn controlled_value_holds_after_an_input_event() {
+        let pacer = ChangePacer::default();
+        let rendered = SliderModel::new(0.0, 10.0, 1.0).with_value(2.0);
+        // The input reports 7; the slider sends the change and the parent passes 2.0 again.
+        let mut next = rendered.clone();
+        let change = next.set_value(0, rendered.from_input(7.0)).unwrap();
+        pacer.send(change.with_source(ChangeSource::Pointer), || ());
+        let current = pacer.displayed(Some(rendered.values()), next.values());
+        assert_eq!(rendered.clone().with_values(current).value(), 2.0);
+    }
+}
@@ -0,0 +1,218 @@
+use super::{Color, Height, Orientation, Size, Width};
+use web_sys::wasm_bindgen::JsCast;
+use web_sys::{Element, EventTarget, HtmlElement, PointerEvent};
+
+/// How the track and thumbs are drawn.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum Renderer {
+    /// One native `<input type="range">` per thumb.
+    #[default]
+    Native,
+    /// A `div` track with `role="slider"` thumbs driven by pointer events, which can hold any
+    /// number of thumbs, custom thumb content and a fill between them.
+    Custom,
+}
+
+/// Class of the track element of the custom renderer.
+pub const TRACK_CLASS: &str = "slider-track";
+
+/// Class of the fill element of the custom renderer.
+pub const RANGE_CLASS: &str = "slider-range";
+
+/// Class of the thumb elements of the custom renderer.
+pub const THUMB_CLASS: &str = "slider-thumb";
+
+/// Class of the segment between the outer thumbs that drags the whole range.
+pub const RANGE_DRAG_CLASS: &str = "slider-range-drag";
+
+/// Shows the drag segment as grabbable and keeps stacked native inputs above it, so their
+/// thumbs stay on top while the track around them lets pointer events through to the segment.
+pub const RANGE_DRAG_CSS: &str = ".slider-thumbs { position: relative; } \
+.slider-thumbs input[type=range] { z-index: 1; } \
+.slider-range-drag { cursor: grab; touch-action: none; } \
+.slider-range-drag:active { cursor: grabbing; } \
+.slider-range-drag:focus-visible { outline: 2px solid var(--slider-focus-ring, rgba(13, 110, 253, 0.5)); outline-offset: 2px; }";
+
+/// Inline style of the custom track.
+///
+/// The track leaves half a thumb of room on either end so thumbs at `min` and `max` stay
+/// inside the slider.
+pub fn custom_track_style(
+    orientation: &Orientation,
+    width: &Width,
+    height: &Height,
+    size: &Size,
+    disabled: bool,
+) -> String {
+    let layout = if orientation.is_vertical() {
+        "height: 100%; min-height: 150px; width: var(--slider-track-height, 8px); margin: calc(var(--slider-thumb-size, 16px) / 2) 8px;"
+    } else {
+        "width: 100%; height: var(--slider-track-height, 8px); margin: 8px calc(var(--slider-thumb-size, 16px) / 2);"
+    };
+    format!(
+        "position: relative; box-sizing: border-box; flex: 1; touch-action: none; user-select: none; \
+         background: var(--slider-track, #ccc); border-radius: var(--slider-track-radius, 8px); \
+         cursor: {}; {} {} {} {}",
+        if disabled { "not-allowed" } else { "pointer" },
+        layout,
+        width.to_style(),
+        height.to_style(),
+        size.to_style(),
+    )
+}
+
+/// Layout a headless track needs: a positioning context that keeps touch gestures and text
+/// selection from interfering with dragging.
+pub const TRACK_LAYOUT: &str = "position: relative; touch-action: none; user-select: none;";
+
+/// Inline style placing a fill between `start` and `end` percent of the track, without colours.
+pub fn range_layout(orientation: &Orientation, start: f64, end: f64) -> String {
+    format!(
+        "{} pointer-events: none;",
+        span_layout(orientation, start, end)
+    )
+}
+
+/// Inline style placing the range drag segment between `start` and `end` percent of the track.
+pub fn range_drag_style(orientation: &Orientation, start: f64, end: f64) -> String {
+    span_layout(orientation, start, end)
+}
+
+fn span_layout(orientation: &Orientation, start: f64, end: f64) -> String {
+    let (side, length, cross) = if orientation.is_vertical() {
+        ("top", "height", "left: 0; right: 0;")
+    } else {
+        ("left", "width", "top: 0; bottom: 0;")
+    };
+    format!(
+        "position: absolute; {} {}: {:.2}%; {}: {:.2}%;",
+        cross,
+        side,
+        start,
+        length,
+        end - start,
+    )
+}
+
+/// Inline style of the custom fill between `start` and `end` percent of the track.
+pub fn custom_range_style(
+    orientation: &Orientation,
+    color: &Color,
+    start: f64,
+    end: f64,
+) -> String {
+    format!(
+        "{} background: {}; border-radius: inherit;",
+        range_layout(orientation, start, end),
+        color.to_color_code()
+    )
+}
+
+/// Inline style placing a custom thumb at `percent` of the track; its look comes from
+/// [`thumb_css`](crate::common::thumb_css).
+pub fn custom_thumb_style(orientation: &Orientation, percent: f64) -> String {
+    if orientation.is_vertical() {
+        format!(
+            "position: absolute; left: 50%; top: {:.2}%; transform: translate(-50%, -50%); display: flex; align-items: center; justify-content: center; box-sizing: border-box;",
+            percent
+        )
+    } else {
+        format!(
+            "position: absolute; top: 50%; left: {:.2}%; transform: translate(-50%, -50%); display: flex; align-items: center; justify-content: center; box-sizing: border-box;",
+            percent
+        )
+    }
+}
+
+/// Position of `pointer` on a track starting at `start` with the given `length`, in percent.
+pub fn position_percent(start: f64, length: f64, pointer: f64) -> f64 {
+    if length <= 0.0 {
+        return 0.0;
+    }
+    ((pointer - start) / length * 100.0).clamp(0.0, 100.0)
+}
+
+/// The custom track under a pointer event and the pointer position on it, in percent.
+///
+/// Frameworks delegate events, so the track is looked up from the event target rather than
+/// taken from `currentTarget`.
+pub fn pointer_on_track(event: &PointerEvent, orientation: &Orientation) -> Option<(Element, f64)> {
+    let track = track_of(&event.target()?)?;
+    let percent = pointer_percent(&track, event, orientation);
+    Some((track, percent))
+}
+
+/// The range drag segment under a pointer event and the pointer position, in percent of the
+/// element holding the segment.
+pub fn pointer_on_range(event: &PointerEvent, orientation: &Orientation) -> Option<(Element, f64)> {
+    let segment = event
+        .target()?
+        .dyn_ref::<Element>()?
+        .closest(&format!(".{}", RANGE_DRAG_CLASS))
+        .ok()??;
+    let percent = pointer_percent(&segment.parent_element()?, event, orientation);
+    Some((segment, percent))
+}
+
+fn pointer_percent(element: &Element, event: &PointerEvent, orientation: &Orientation) -> f64 {
+    let rect = element.get_bounding_client_rect();
+    if orientation.is_vertical() {
+        position_percent(rect.top(), rect.height(), event.client_y() as f64)
+    } else {
+        position_percent(rect.left(), rect.width(), event.client_x() as f64)
+    }
+}
+
+/// Moves keyboard focus to a custom thumb of the track containing `target`.
+pub fn focus_thumb(target: &EventTarget, thumb: usize) {
+    let thumb = track_of(target)
+        .and_then(|track| track.query_selector_all(&format!(".{}", THUMB_CLASS)).ok())
+        .and_then(|thumbs| thumbs.get(thumb as u32))
+        .and_then(|thumb| thumb.dyn_into::<HtmlElement>().ok());
+    if let Some(thumb) = thumb {
+        let _ = thumb.focus();
+    }
+}
+
+/// Whether `target` is laid out right-to-left, in which case native range inputs run from
+/// right to left and the horizontal arrow keys swap.
+pub fn is_rtl(target: &EventTarget) -> bool {
+    let Some(element) = target.dyn_ref::<Element>() else {
+        return false;
+    };
+    web_sys::window()
+        .and_then(|window| window.get_computed_style(element).ok().flatten())
+        .and_then(|style| style.get_property_value("direction").ok())
+        .is_some_and(|direction| direction == "rtl")
+}
+
+fn track_of(target: &EventTarget) -> Option<Element> {
+    target
+        .dyn_ref::<Element>()?
+        .closest(&format!(".{}", TRACK_CLASS))
+        .ok()
+        .flatten()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn position_percent_is_clamped_to_the_track() {
+        assert_eq!(position_percent(100.0, 200.0, 150.0), 25.0);
+        assert_eq!(position_percent(100.0, 200.0, 50.0), 0.0);
+        assert_eq!(position_percent(100.0, 200.0, 400.0), 100.0);
+        assert_eq!(position_percent(100.0, 0.0, 100.0), 0.0);
+    }
+
+    #[test]
+    fn custom_range_spans_start_to_end() {
+        let style = custom_range_style(&Orientation::Horizontal, &Color::Danger, 20.0, 70.0);
+        assert!(style.contains("left: 20.00%; width: 50.00%;"));
+        assert!(style.contains("pointer-events: none;"));
+        assert!(style.contains("background: #dc3545;"));
+        let style = custom_range_style(&Orientation::Vertical, &Color::Danger, 0.0, 40.0);
+        assert!(style.contains("top: 0.00%; height: 40.00%;"));
+    }
+}
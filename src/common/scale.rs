@@ -0,0 +1,189 @@
+use std::fmt;
+use std::sync::Arc;
+
+/// A custom mapping between slider values and track positions.
+///
+/// Implementations must be monotonic and map `min` to `0.0` and `max` to `1.0`; `to_value`
+/// must be the inverse of `to_position`.
+///
+/// # Examples
+/// ```rust
+/// use slider_rs::common::{Scale, ScaleMapping};
+///
+/// struct Sqrt;
+///
+/// impl ScaleMapping for Sqrt {
+///     fn to_position(&self, value: f64, min: f64, max: f64) -> f64 {
+///         ((value - min) / (max - min)).sqrt()
+///     }
+///
+///     fn to_value(&self, position: f64, min: f64, max: f64) -> f64 {
+///         min + (max - min) * position * position
+///     }
+/// }
+///
+/// let scale = Scale::custom(Sqrt);
+/// assert_eq!(scale.to_position(25.0, 0.0, 100.0), 0.5);
+/// ```
+pub trait ScaleMapping: Send + Sync {
+    /// Maps a value in `[min, max]` to a track position in `[0.0, 1.0]`.
+    fn to_position(&self, value: f64, min: f64, max: f64) -> f64;
+
+    /// Maps a track position in `[0.0, 1.0]` back to a value in `[min, max]`.
+    fn to_value(&self, position: f64, min: f64, max: f64) -> f64;
+}
+
+/// How values are distributed along the track.
+#[derive(Clone, Default)]
+pub enum Scale {
+    /// Equal distances on the track cover equal value spans.
+    #[default]
+    Linear,
+    /// Equal distances cover equal ratios, e.g. 20 Hz to 20 kHz. Needs positive bounds and
+    /// falls back to [`Scale::SymLog`] otherwise.
+    Log,
+    /// `value = min + (max - min) * position^exp`; an exponent above `1.0` gives finer control
+    /// near `min`. Non-positive exponents behave like [`Scale::Linear`].
+    Pow(f64),
+    /// Logarithmic on both sides of zero and linear around it, for bounds that cross zero.
+    SymLog,
+    /// A user supplied mapping.
+    Custom(Arc<dyn ScaleMapping>),
+}
+
+impl Scale {
+    /// Wraps a [`ScaleMapping`] into a [`Scale::Custom`].
+    pub fn custom(mapping: impl ScaleMapping + 'static) -> Self {
+        Scale::Custom(Arc::new(mapping))
+    }
+
+    pub fn is_linear(&self) -> bool {
+        match self {
+            Scale::Linear => true,
+            Scale::Pow(exp) => *exp <= 0.0 || *exp == 1.0,
+            _ => false,
+        }
+    }
+
+    /// Track position of `value`, from `0.0` to `1.0`.
+    pub fn to_position(&self, value: f64, min: f64, max: f64) -> f64 {
+        if max == min {
+            return 0.0;
+        }
+        let position = match self {
+            Scale::Log if min > 0.0 => (value / min).ln() / (max / min).ln(),
+            Scale::Log | Scale::SymLog => {
+                (symlog(value) - symlog(min)) / (symlog(max) - symlog(min))
+            }
+            Scale::Pow(exp) if *exp > 0.0 => ((value - min) / (max - min)).powf(1.0 / exp),
+            Scale::Linear | Scale::Pow(_) => (value - min) / (max - min),
+            Scale::Custom(mapping) => mapping.to_position(value, min, max),
+        };
+        position.clamp(0.0, 1.0)
+    }
+
+    /// Value at a track position from `0.0` to `1.0`.
+    pub fn to_value(&self, position: f64, min: f64, max: f64) -> f64 {
+        let position = position.clamp(0.0, 1.0);
+        let value = match self {
+            Scale::Log if min > 0.0 => min * (max / min).powf(position),
+            Scale::Log | Scale::SymLog => {
+                symexp(symlog(min) + (symlog(max) - symlog(min)) * position)
+            }
+            Scale::Pow(exp) if *exp > 0.0 => min + (max - min) * position.powf(*exp),
+            Scale::Linear | Scale::Pow(_) => min + (max - min) * position,
+            Scale::Custom(mapping) => mapping.to_value(position, min, max),
+        };
+        value.clamp(min, max)
+    }
+}
+
+impl PartialEq for Scale {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Scale::Linear, Scale::Linear)
+            | (Scale::Log, Scale::Log)
+            | (Scale::SymLog, Scale::SymLog) => true,
+            (Scale::Pow(a), Scale::Pow(b)) => a == b,
+            (Scale::Custom(a), Scale::Custom(b)) => Arc::ptr_eq(a, b),
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Debug for Scale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Scale::Linear => f.write_str("Linear"),
+            Scale::Log => f.write_str("Log"),
+            Scale::Pow(exp) => f.debug_tuple("Pow").field(exp).finish(),
+            Scale::SymLog => f.write_str("SymLog"),
+            Scale::Custom(_) => f.write_str("Custom(..)"),
+        }
+    }
+}
+
+fn symlog(value: f64) -> f64 {
+    value.signum() * value.abs().ln_1p()
+}
+
+fn symexp(value: f64) -> f64 {
+    value.signum() * value.abs().exp_m1()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip(scale: &Scale, value: f64, min: f64, max: f64) -> f64 {
+        scale.to_value(scale.to_position(value, min, max), min, max)
+    }
+
+    #[test]
+    fn linear_maps_proportionally() {
+        assert_eq!(Scale::Linear.to_position(25.0, 0.0, 100.0), 0.25);
+        assert_eq!(Scale::Linear.to_value(0.75, 0.0, 100.0), 75.0);
+    }
+
+    #[test]
+    fn log_spreads_decades_evenly() {
+        let scale = Scale::Log;
+        assert!((scale.to_position(200.0, 20.0, 20_000.0) - 1.0 / 3.0).abs() < 1e-12);
+        assert!((scale.to_position(2_000.0, 20.0, 20_000.0) - 2.0 / 3.0).abs() < 1e-12);
+        assert!((round_trip(&scale, 440.0, 20.0, 20_000.0) - 440.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn log_without_positive_bounds_uses_symlog() {
+        assert_eq!(
+            Scale::Log.to_position(0.0, -100.0, 100.0),
+            Scale::SymLog.to_position(0.0, -100.0, 100.0)
+        );
+    }
+
+    #[test]
+    fn pow_gives_finer_control_near_min() {
+        let scale = Scale::Pow(2.0);
+        assert_eq!(scale.to_position(25.0, 0.0, 100.0), 0.5);
+        assert_eq!(scale.to_value(0.5, 0.0, 100.0), 25.0);
+        assert!(Scale::Pow(1.0).is_linear());
+        assert!(Scale::Pow(-1.0).is_linear());
+    }
+
+    #[test]
+    fn symlog_is_symmetric_around_zero() {
+        let scale = Scale::SymLog;
+        assert_eq!(scale.to_position(0.0, -1000.0, 1000.0), 0.5);
+        let low = scale.to_position(-10.0, -1000.0, 1000.0);
+        let high = scale.to_position(10.0, -1000.0, 1000.0);
+        assert!((low + high - 1.0).abs() < 1e-12);
+        assert!((round_trip(&scale, -42.0, -1000.0, 1000.0) + 42.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn positions_are_clamped() {
+        assert_eq!(Scale::Log.to_position(1.0, 20.0, 20_000.0), 0.0);
+        assert_eq!(Scale::Linear.to_value(2.0, 0.0, 10.0), 10.0);
+        assert_eq!(Scale::Linear.to_position(5.0, 5.0, 5.0), 0.0);
+    }
+}
@@ -0,0 +1,168 @@
+/// Colours, radii and sizes of a slider, emitted as `--slider-*` CSS custom properties on the
+/// slider container.
+///
+/// Every built-in style reads these variables with the old hard-coded value as fallback, so a
+/// slider without a theme looks as before. Fields hold any CSS value, e.g. `"#0d6efd"`,
+/// `"rgb(0 0 0 / 50%)"` or `"var(--brand)"`.
+///
+/// # Examples
+/// ```rust
+/// use slider_rs::common::Theme;
+///
+/// let theme = Theme {
+///     fill: "#e83e8c".to_string(),
+///     thumb: "#e83e8c".to_string(),
+///     ..Theme::dark()
+/// };
+/// assert!(theme.to_css_vars().contains("--slider-fill: #e83e8c;"));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Theme {
+    /// Unfilled part of the track.
+    pub track: String,
+    /// Filled part of the track for the default `Color::Primary`.
+    pub fill: String,
+    /// Thumb background.
+    pub thumb: String,
+    /// Ring drawn around a thumb focused from the keyboard.
+    pub focus_ring: String,
+    /// Tooltip background.
+    pub tooltip_background: String,
+    /// Tooltip text.
+    pub tooltip_text: String,
+    /// Thumb and fill of a disabled slider.
+    pub disabled: String,
+    /// Mark ticks.
+    pub tick: String,
+    /// Corner radius of the track.
+    pub track_radius: String,
+    /// Corner radius of the thumb.
+    pub thumb_radius: String,
+    /// Thickness of the track.
+    pub track_height: String,
+    /// Width and height of the thumb.
+    pub thumb_size: String,
+}
+
+impl Theme {
+    /// Dark thumb and fill on a light page, matching the unthemed look.
+    pub fn light() -> Self {
+        Self {
+            track: "#ccc".to_string(),
+            fill: "#0d6efd".to_string(),
+            thumb: "#0d6efd".to_string(),
+            focus_ring: "rgba(13, 110, 253, 0.5)".to_string(),
+            tooltip_background: "#333".to_string(),
+            tooltip_text: "#fff".to_string(),
+            disabled: "#aaa".to_string(),
+            tick: "#6c757d".to_string(),
+            track_radius: "8px".to_string(),
+            thumb_radius: "50%".to_string(),
+            track_height: "8px".to_string(),
+            thumb_size: "16px".to_string(),
+        }
+    }
+
+    /// Light thumb and fill on a dark page.
+    pub fn dark() -> Self {
+        Self {
+            track: "#495057".to_string(),
+            fill: "#6ea8fe".to_string(),
+            thumb: "#6ea8fe".to_string(),
+            focus_ring: "rgba(110, 168, 254, 0.5)".to_string(),
+            tooltip_background: "#f8f9fa".to_string(),
+            tooltip_text: "#212529".to_string(),
+            disabled: "#6c757d".to_string(),
+            tick: "#adb5bd".to_string(),
+            ..Self::light()
+        }
+    }
+
+    /// Black on white with a solid focus ring and a larger thumb.
+    pub fn high_contrast() -> Self {
+        Self {
+            track: "#767676".to_string(),
+            fill: "#000".to_string(),
+            thumb: "#000".to_string(),
+            focus_ring: "#005fcc".to_string(),
+            tooltip_background: "#000".to_string(),
+            tooltip_text: "#fff".to_string(),
+            disabled: "#595959".to_string(),
+            tick: "#000".to_string(),
+            track_radius: "0".to_string(),
+            thumb_radius: "50%".to_string(),
+            track_height: "10px".to_string(),
+            thumb_size: "20px".to_string(),
+        }
+    }
+
+    /// The `--slider-*` declarations for the container's `style` attribute.
+    pub fn to_css_vars(&self) -> String {
+        format!(
+            "--slider-track: {}; --slider-fill: {}; --slider-thumb: {}; --slider-focus-ring: {}; \
+             --slider-tooltip-bg: {}; --slider-tooltip-text: {}; --slider-disabled: {}; \
+             --slider-tick: {}; --slider-track-radius: {}; --slider-thumb-radius: {}; \
+             --slider-track-height: {}; --slider-thumb-size: {};",
+            self.track,
+            self.fill,
+            self.thumb,
+            self.focus_ring,
+            self.tooltip_background,
+            self.tooltip_text,
+            self.disabled,
+            self.tick,
+            self.track_radius,
+            self.thumb_radius,
+            self.track_height,
+            self.thumb_size,
+        )
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::light()
+    }
+}
+
+/// Class added to the container of a themed slider.
+pub const THEMED_CLASS: &str = "slider-themed";
+
+/// Sets the track thickness of a themed slider, which the inline `Height` would otherwise fix;
+/// the thumbs read the other variables from [`thumb_css`](crate::common::thumb_css).
+pub const THEME_CSS: &str =
+    ".slider-themed input[type=range] { block-size: var(--slider-track-height); }";
+
+/// Container `class` and `style` for an optional theme.
+pub fn themed_container(theme: Option<&Theme>, class: &str, style: &str) -> (String, String) {
+    match theme {
+        Some(theme) => (
+            format!("{} {}", class, THEMED_CLASS),
+            format!("{} {}", theme.to_css_vars(), style),
+        ),
+        None => (class.to_string(), style.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn css_vars_cover_every_field() {
+        let vars = Theme::high_contrast().to_css_vars();
+        assert_eq!(vars.matches("--slider-").count(), 12);
+        assert!(vars.contains("--slider-thumb-size: 20px;"));
+        assert!(vars.contains("--slider-tooltip-bg: #000;"));
+    }
+
+    #[test]
+    fn themed_container_adds_class_and_vars() {
+        let (class, style) = themed_container(Some(&Theme::dark()), "slider", "padding: 0;");
+        assert_eq!(class, "slider slider-themed");
+        assert!(style.starts_with("--slider-track: #495057;"));
+        assert!(style.ends_with("padding: 0;"));
+        let (class, style) = themed_container(None, "slider", "padding: 0;");
+        assert_eq!((class.as_str(), style.as_str()), ("slider", "padding: 0;"));
+    }
+}
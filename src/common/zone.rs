@@ -0,0 +1,141 @@
+use super::{Color, Orientation, SliderModel};
+
+/// A coloured region of the slider track, such as "normal", "high" or "critical".
+///
+/// # Examples
+/// ```rust
+/// use slider_rs::common::{Color, Zone};
+///
+/// let zones = vec![
+///     Zone::new(0.0, 60.0, Color::Success),
+///     Zone::new(60.0, 80.0, Color::Warning).with_label("High"),
+///     Zone::new(80.0, 100.0, Color::Danger).with_label("Critical"),
+/// ];
+/// assert!(Zone::at(&zones, 85.0) == Some(&zones[2]));
+/// assert!(Zone::at(&zones, 60.0) == Some(&zones[1]));
+/// ```
+#[derive(Clone, PartialEq, Default)]
+pub struct Zone {
+    /// Value the zone starts at.
+    pub from: f64,
+    /// Value the zone ends at.
+    pub to: f64,
+    /// Colour of the track within the zone.
+    pub color: Color,
+    /// Text shown along the zone.
+    pub label: Option<String>,
+}
+
+impl Zone {
+    /// Creates a zone between `from` and `to`; swapped bounds are reordered.
+    pub fn new(from: f64, to: f64, color: Color) -> Self {
+        let (from, to) = if to < from { (to, from) } else { (from, to) };
+        Self {
+            from,
+            to,
+            color,
+            label: None,
+        }
+    }
+
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    pub fn contains(&self, value: f64) -> bool {
+        (self.from..=self.to).contains(&value)
+    }
+
+    /// The zone holding `value`. Where zones overlap, or meet at `value`, the one starting
+    /// last wins.
+    pub fn at(zones: &[Zone], value: f64) -> Option<&Zone> {
+        zones
+            .iter()
+            .filter(|zone| zone.contains(value))
+            .max_by(|a, b| a.from.total_cmp(&b.from))
+    }
+}
+
+/// A `linear-gradient` painting `zones` over `track_color`, running in `direction`.
+///
+/// Zones are placed with [`SliderModel::percent`], so they follow the thumb on any scale. Where
+/// zones overlap, the one starting last wins, as in [`Zone::at`].
+pub fn zones_gradient(
+    zones: &[Zone],
+    model: &SliderModel,
+    direction: &str,
+    track_color: &str,
+) -> String {
+    let mut sorted: Vec<&Zone> = zones.iter().collect();
+    sorted.sort_by(|a, b| a.from.total_cmp(&b.from));
+    let mut stops = vec![format!("{} 0%", track_color)];
+    for (index, zone) in sorted.iter().enumerate() {
+        let to = sorted
+            .get(index + 1)
+            .map_or(zone.to, |next| zone.to.min(next.from));
+        let (start, end) = (model.percent(zone.from), model.percent(to));
+        if end <= start {
+            continue;
+        }
+        let color = zone.color.to_color_code();
+        stops.push(format!("{} {:.2}%", track_color, start));
+        stops.push(format!("{} {:.2}%", color, start));
+        stops.push(format!("{} {:.2}%", color, end));
+        stops.push(format!("{} {:.2}%", track_color, end));
+    }
+    stops.push(format!("{} 100%", track_color));
+    format!("linear-gradient({}, {})", direction, stops.join(", "))
+}
+
+/// Inline style of a zone label, centred over its zone.
+pub fn zone_label_style(model: &SliderModel, zone: &Zone, orientation: &Orientation) -> String {
+    let (start, end) = (model.percent(zone.from), model.percent(zone.to));
+    let (side, length) = if orientation.is_vertical() {
+        ("top", "height")
+    } else {
+        ("left", "width")
+    };
+    format!(
+        "position: absolute; {}: {:.2}%; {}: {:.2}%; display: flex; align-items: center; justify-content: center; font-size: 10px; white-space: nowrap; color: {};",
+        side,
+        start,
+        length,
+        end - start,
+        zone.color.to_color_code()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zones_paint_in_order_and_later_zones_win() {
+        let model = SliderModel::new(0.0, 100.0, 1.0);
+        let zones = vec![
+            Zone::new(80.0, 100.0, Color::Danger),
+            Zone::new(0.0, 70.0, Color::Success),
+            Zone::new(60.0, 80.0, Color::Warning),
+        ];
+        let gradient = zones_gradient(&zones, &model, "to right", "#ccc");
+        assert!(
+            gradient.starts_with("linear-gradient(to right, #ccc 0%, #ccc 0.00%, #198754 0.00%")
+        );
+        assert!(gradient.contains("#198754 60.00%, #ccc 60.00%, #ccc 60.00%, #ffc107 60.00%"));
+        assert!(gradient.ends_with("#dc3545 100.00%, #ccc 100.00%, #ccc 100%)"));
+        assert!(Zone::at(&zones, 65.0) == Some(&zones[2]));
+        assert!(Zone::at(&zones, 80.0) == Some(&zones[0]));
+        assert!(Zone::at(&zones, 120.0).is_none());
+    }
+
+    #[test]
+    fn zone_labels_span_their_zone() {
+        let model = SliderModel::new(0.0, 200.0, 1.0);
+        let zone = Zone::new(150.0, 50.0, Color::Warning).with_label("Mid");
+        assert_eq!((zone.from, zone.to), (50.0, 150.0));
+        let style = zone_label_style(&model, &zone, &Orientation::Horizontal);
+        assert!(style.contains("left: 25.00%; width: 50.00%;"));
+        assert!(style.contains("color: #ffc107;"));
+    }
+}
@@ -1,25 +1,44 @@
 #![doc = include_str!("../DIOXUS.md")]
 
-use crate::common::{Color, Cursor, Height, Orientation, Size, Width};
+use crate::common::{
+    ChangePacer, ChangeSource, Color, Cursor, HISTOGRAM_VIEW_BOX, Height, KeyAction, Mark,
+    Orientation, RANGE_CLASS, RANGE_DRAG_CLASS, RANGE_DRAG_CSS, RangeBehavior, RateLimit, Renderer,
+    STACKED_THUMBS_CLASS, STACKED_THUMBS_CSS, Scale, Size, SliderChange, SliderIds, SliderModel,
+    THEME_CSS, THUMB_CLASS, TRACK_CLASS, TRACK_LAYOUT, Theme, ValueFormat, Width, Zone,
+    custom_range_style, custom_thumb_style, custom_track_style, focus_thumb, histogram_bars,
+    histogram_style, histogram_track_style, is_rtl, mark_style, mark_tick_style,
+    marks_container_style, pointer_on_range, pointer_on_track, range_background, range_drag_style,
+    range_layout, themed_container, thumb_css, thumb_size, track_background, value_text,
+    zone_label_style, zoned_background, zones_track_background,
+};
 use dioxus::prelude::*;
+use std::cell::{Cell, RefCell};
+use std::fmt::Display;
 use std::rc::Rc;
-use uuid::Uuid;
-use web_sys::HtmlInputElement;
+use web_sys::wasm_bindgen::JsCast;
+use web_sys::{HtmlElement, HtmlInputElement};
 
 #[derive(Props, PartialEq, Clone)]
 pub struct LabelProps {
     #[props(default)]
-    label: &'static str,
-    #[props(default = "font-size: 14px; margin-bottom: 8px; text-align: center;")]
-    label_style: &'static str,
-    #[props(default = "slider-label")]
-    label_class: &'static str,
+    id: Option<String>,
+    /// Id of the input the label names; custom thumbs point back with `aria-labelledby`.
+    #[props(default)]
+    for_id: Option<String>,
+    #[props(default)]
+    label: String,
+    #[props(default = "font-size: 14px; margin-bottom: 8px; text-align: center;".to_string())]
+    label_style: String,
+    #[props(default = "slider-label".to_string())]
+    label_class: String,
 }
 
 #[component]
 fn Label(props: LabelProps) -> Element {
     rsx! {
         label {
+            id: props.id,
+            r#for: props.for_id,
             class: "{props.label_class}",
             style: "{props.label_style}",
             "{props.label}"
@@ -29,26 +48,19 @@ fn Label(props: LabelProps) -> Element {
 
 #[derive(Props, PartialEq, Clone)]
 pub struct StepsProps {
-    #[props(default = 0.0)]
-    min: f64,
-    #[props(default = 10.0)]
-    max: f64,
-    #[props(default = 1.0)]
-    step: f64,
+    #[props(default)]
+    labels: Vec<String>,
     #[props(
-        default = "width: 100%; display: flex; justify-content: space-between; margin-top: 8px; font-size: 10px;"
+        default = "width: 100%; display: flex; justify-content: space-between; margin-top: 8px; font-size: 10px;".to_string()
     )]
-    steps_style: &'static str,
+    steps_style: String,
     #[props(default)]
     orientation: Orientation,
 }
 
 #[component]
 fn Steps(props: StepsProps) -> Element {
-    let count = ((props.max - props.min) / props.step).floor() as usize;
-
-    let steps = (0..=count).map(|i| {
-        let val = props.min + (i as f64 * props.step);
+    let steps = props.labels.iter().map(|label| {
         let style = if props.orientation.is_vertical() {
             "margin: 4px 0; writing-mode: vertical-rl; text-align: center;"
         } else {
@@ -57,7 +69,7 @@ fn Steps(props: StepsProps) -> Element {
         rsx! {
             span {
                 style: "{style}",
-                "{val:.0}"
+                "{label}"
             }
         }
     });
@@ -65,7 +77,7 @@ fn Steps(props: StepsProps) -> Element {
     let container_style = if props.orientation.is_vertical() {
         "display: flex; flex-direction: column; align-items: center; height: 100%; font-size: 10px;"
     } else {
-        props.steps_style
+        &props.steps_style
     };
 
     rsx! {
@@ -78,16 +90,21 @@ fn Steps(props: StepsProps) -> Element {
 
 #[derive(Props, PartialEq, Clone)]
 pub struct OutputProps {
+    #[props(default)]
+    id: Option<String>,
+    /// Ids of the inputs the output shows the value of.
+    #[props(default)]
+    for_ids: Option<String>,
     #[props(default)]
     value_display: String,
-    #[props(default = "font-size: 12px; margin-top: 8px; text-align: center;")]
-    output_style: &'static str,
-    #[props(default = "slider-output")]
-    output_class: &'static str,
+    #[props(default = "font-size: 12px; margin-top: 8px; text-align: center;".to_string())]
+    output_style: String,
+    #[props(default = "slider-output".to_string())]
+    output_class: String,
     #[props(
-        default = "background-color: #333; color: #fff; padding: 4px 8px; border-radius: 4px; font-size: 12px; position: absolute; transform: translate(-50%, -120%); display: block; pointer-events: none;"
+        default = "background-color: var(--slider-tooltip-bg, #333); color: var(--slider-tooltip-text, #fff); padding: 4px 8px; border-radius: 4px; font-size: 12px; position: absolute; transform: translate(-50%, -120%); display: block; pointer-events: none;".to_string()
     )]
-    tooltip_style: &'static str,
+    tooltip_style: String,
     #[props(default = false)]
     show_tooltip: bool,
     #[props(default)]
@@ -99,6 +116,8 @@ fn Output(props: OutputProps) -> Element {
     let style = format!("{} left: {};", props.tooltip_style, props.tooltip_left);
     rsx! {
         output {
+            id: props.id,
+            "for": props.for_ids,
             class: "{props.output_class}",
             style: "{props.output_style}",
             aria_live: "polite",
@@ -118,32 +137,130 @@ fn Output(props: OutputProps) -> Element {
 pub struct TicksProps {
     #[props(default)]
     id: String,
-    #[props(default = 0.0)]
-    min: f64,
-    #[props(default = 10.0)]
-    max: f64,
-    #[props(default = 1.0)]
-    step: f64,
+    #[props(default)]
+    values: Vec<f64>,
 }
 
 #[component]
 fn Ticks(props: TicksProps) -> Element {
-    let mut current = props.min;
-    let mut options = vec![];
+    rsx! {
+        datalist {
+            id: "{props.id}",
+            for value in props.values.iter() {
+                option {
+                    value: "{value}"
+                }
+            }
+        }
+    }
+}
+
+#[derive(Props, PartialEq, Clone)]
+pub struct ZoneLabelsProps {
+    model: SliderModel,
+    #[props(default)]
+    zones: Vec<Zone>,
+    #[props(default)]
+    orientation: Orientation,
+}
 
-    while current <= props.max {
-        options.push(rsx! {
-            option {
-                value: "{current}"
+#[component]
+fn ZoneLabels(props: ZoneLabelsProps) -> Element {
+    rsx! {
+        div {
+            class: "slider-zones",
+            style: marks_container_style(&props.orientation),
+            for zone in props.zones.iter().filter(|zone| zone.label.is_some()) {
+                span {
+                    class: "slider-zone-label",
+                    style: zone_label_style(&props.model, zone, &props.orientation),
+                    {zone.label.clone().unwrap_or_default()}
+                }
             }
-        });
-        current += props.step;
+        }
     }
+}
+
+#[derive(Props, PartialEq, Clone)]
+pub struct HistogramProps {
+    model: SliderModel,
+    #[props(default)]
+    counts: Vec<f64>,
+    #[props(default)]
+    orientation: Orientation,
+    #[props(default)]
+    color: Color,
+    #[props(default = false)]
+    rtl_fill: bool,
+    /// Thumb size the bars are inset by, see [`histogram_style`].
+    #[props(default = "0px".to_string())]
+    thumb_size: String,
+}
 
+#[component]
+fn Histogram(props: HistogramProps) -> Element {
+    let fill = props.color.to_color_code();
+    let bars = histogram_bars(
+        &props.counts,
+        &props.model,
+        &props.orientation,
+        props.rtl_fill,
+    );
     rsx! {
-        datalist {
-            id: "{props.id}",
-            {options.into_iter()}
+        svg {
+            class: "slider-histogram",
+            view_box: HISTOGRAM_VIEW_BOX,
+            preserve_aspect_ratio: "none",
+            "aria-hidden": "true",
+            style: histogram_style(&props.orientation, &props.thumb_size),
+            for bar in bars {
+                rect {
+                    class: "slider-histogram-bar",
+                    x: "{bar.x:.2}",
+                    y: "{bar.y:.2}",
+                    width: "{bar.width:.2}",
+                    height: "{bar.height:.2}",
+                    fill: fill,
+                    opacity: bar.opacity(),
+                }
+            }
+        }
+    }
+}
+
+#[derive(Props, PartialEq, Clone)]
+pub struct MarksProps {
+    model: SliderModel,
+    #[props(default)]
+    marks: Vec<Mark>,
+    #[props(default)]
+    orientation: Orientation,
+    /// Thumb size the marks are inset by, see [`mark_style`].
+    #[props(default = "0px".to_string())]
+    thumb_size: String,
+}
+
+#[component]
+fn Marks(props: MarksProps) -> Element {
+    rsx! {
+        div {
+            class: "slider-marks",
+            style: marks_container_style(&props.orientation),
+            for mark in props.marks.iter() {
+                span {
+                    class: mark.class_name(),
+                    style: mark_style(
+                        props.model.percent(mark.value),
+                        &props.orientation,
+                        &props.thumb_size,
+                    ),
+                    span {
+                        class: "slider-mark-tick",
+                        style: mark_tick_style(&props.orientation),
+                    }
+                    {mark.label.clone().unwrap_or_default()}
+                }
+            }
         }
     }
 }
@@ -152,14 +269,9 @@ fn Ticks(props: TicksProps) -> Element {
 pub struct InputProps {
     #[props(default)]
     input_ref: Signal<Option<Rc<MountedData>>>,
-    #[props(default = 0.0)]
-    min: f64,
-    #[props(default = 10.0)]
-    max: f64,
-    #[props(default = 1.0)]
-    step: f64,
-    #[props(default = 0.0)]
-    value: f64,
+    model: SliderModel,
+    #[props(default = 0)]
+    thumb: usize,
     #[props(default)]
     orientation: Orientation,
     #[props(default)]
@@ -171,120 +283,173 @@ pub struct InputProps {
     #[props(default)]
     color: Color,
     #[props(default)]
-    cursor_style: Cursor,
+    outside_color: Option<Color>,
     #[props(default = false)]
     disabled: bool,
     #[props(default)]
-    on_input: Callback<FormEvent>,
+    on_input: Callback<f64>,
+    #[props(default)]
+    on_key: Callback<KeyAction>,
+    /// Receives the vertical wheel delta; without it the wheel scrolls the page.
+    #[props(default)]
+    on_wheel: Option<Callback<f64>>,
+    /// Fires when a key is released or the browser reports the final value.
+    #[props(default)]
+    on_commit: Callback<()>,
+    #[props(default)]
+    on_drag_start: Callback<()>,
+    #[props(default)]
+    on_drag_end: Callback<()>,
     #[props(default)]
     on_focus: Callback<FocusEvent>,
     #[props(default)]
     on_blur: Callback<FocusEvent>,
     #[props(default)]
-    aria_label: Option<&'static str>,
+    id: Option<String>,
+    #[props(default)]
+    aria_label: Option<String>,
+    #[props(default)]
+    aria_describedby: Option<String>,
     #[props(default)]
-    aria_describedby: Option<&'static str>,
+    aria_valuetext: Option<String>,
     #[props(default)]
     datalist_id: Option<String>,
-    #[props(default = "slider-input")]
-    input_class: &'static str,
-    #[props(default = "border-radius: 8px; appearance: none; outline: none;")]
-    input_style: &'static str,
+    #[props(default = "slider-input".to_string())]
+    input_class: String,
+    #[props(
+        default = "border-radius: var(--slider-track-radius, 8px); appearance: none; outline: none;".to_string()
+    )]
+    input_style: String,
     #[props(default = true)]
     use_gradient: bool,
     #[props(default)]
-    custom_thumb_css: Option<&'static str>,
-    #[props(default)]
     custom_thumb_html: Option<Element>,
-    #[props(default = 1.0)]
-    keyboard_step: f64,
     #[props(default = false)]
     rtl_fill: bool,
+    /// Overrides the computed track `background` declaration.
+    #[props(default)]
+    background: Option<String>,
 }
 
 #[component]
 fn Input(props: InputProps) -> Element {
-    let mut props = props.clone();
-    let value_percent = ((props.value - props.min) / (props.max - props.min)) * 100.0;
-    let fill_color = props.color.to_color_code();
-    let gradient = if props.use_gradient {
-        if props.orientation.is_vertical() {
-            if props.rtl_fill {
-                format!(
-                    "background: linear-gradient(to top, {} 0%, {} {:.2}%, #ccc {:.2}%, #ccc 100%);",
-                    fill_color, fill_color, value_percent, value_percent
-                )
-            } else {
-                format!(
-                    "background: linear-gradient(to bottom, {} 0%, {} {:.2}%, #ccc {:.2}%, #ccc 100%);",
-                    fill_color, fill_color, value_percent, value_percent
-                )
-            }
-        } else if props.rtl_fill {
-            format!(
-                "background: linear-gradient(to left, {} 0%, {} {:.2}%, #ccc {:.2}%, #ccc 100%);",
-                fill_color, fill_color, value_percent, value_percent
-            )
-        } else {
-            format!(
-                "background: linear-gradient(to right, {} 0%, {} {:.2}%, #ccc {:.2}%, #ccc 100%);",
-                fill_color, fill_color, value_percent, value_percent
-            )
-        }
-    } else {
-        format!("background: {};", fill_color)
-    };
+    let mut input_ref = props.input_ref;
+    let model = &props.model;
+    let value = model.thumb_value(props.thumb).unwrap_or(model.min());
 
     let base_style = format!(
         "cursor: pointer; transition: background 0.3s; {} {} {} {} {} {}",
         props.input_style,
         props.width.to_style(),
         props.height.to_style(),
-        gradient,
+        props.background.clone().unwrap_or_else(|| {
+            if model.fill_origin().is_some() {
+                let (start, end) = model.fill_percent();
+                range_background(
+                    &props.color,
+                    props.outside_color.as_ref(),
+                    &props.orientation,
+                    props.rtl_fill,
+                    props.use_gradient,
+                    start,
+                    end,
+                )
+            } else {
+                track_background(
+                    &props.color,
+                    props.outside_color.as_ref(),
+                    &props.orientation,
+                    props.rtl_fill,
+                    props.use_gradient,
+                    model.percent(value),
+                )
+            }
+        }),
         props.orientation.to_style(),
         props.size.to_style(),
     );
 
-    let on_key_down = Callback::new({
-        move |e: Event<KeyboardData>| {
-            let data = e.data;
-            if let Some(el) = (props.input_ref)() {
-                if let Some(input) = el.downcast::<HtmlInputElement>() {
-                    let current = input.value().parse::<f64>().unwrap_or(0.0);
-                    let new_val = match data.key() {
-                        Key::ArrowLeft | Key::ArrowDown => current - props.keyboard_step,
-                        Key::ArrowRight | Key::ArrowUp => current + props.keyboard_step,
-                        _ => current,
-                    }
-                    .clamp(props.min, props.max);
-
-                    input.set_value(&new_val.to_string());
-
-                    if let Ok(event) = web_sys::Event::new("input") {
-                        let _ = input.dispatch_event(&event);
-                    }
-                }
-            }
-        }
-    });
+    let on_input = props.on_input;
+    let on_key = props.on_key;
+    let (input_min, input_max) = model.input_bounds();
+    let input_value = model.to_input(value);
+    let from_input = {
+        let model = model.clone();
+        move |v: f64| model.from_input(v)
+    };
 
     rsx! {
         input {
-            onmounted: move |cx| props.input_ref.set(Some(cx.data())),
+            onmounted: move |cx| input_ref.set(Some(cx.data())),
+            id: props.id,
             r#type: "range",
             class: "{props.input_class}",
-            min: "{props.min}",
-            max: "{props.max}",
-            step: if props.step == 0.0 { "any".to_string() } else { props.step.to_string() },
-            value: "{props.value}",
+            min: "{input_min}",
+            max: "{input_max}",
+            step: model.input_step(),
+            value: "{input_value}",
             list: props.datalist_id.clone().unwrap_or_default(),
-            oninput: move |e| props.on_input.call(e),
+            oninput: move |e: FormEvent| {
+                if let Ok(v) = e.value().parse::<f64>() {
+                    // Snap the DOM back to the rendered value; the next render moves the thumb
+                    // once the new value is accepted, which keeps controlled sliders in sync.
+                    if let Some(input) = input_ref()
+                        .as_ref()
+                        .and_then(|el| el.downcast::<web_sys::Element>())
+                        .and_then(|el| el.dyn_ref::<HtmlInputElement>())
+                    {
+                        input.set_value(&input_value.to_string());
+                    }
+                    on_input.call(from_input(v));
+                }
+            },
             onfocus: move |e| props.on_focus.call(e),
             onblur: move |e| props.on_blur.call(e),
-            onkeydown: on_key_down,
+            onkeydown: move |e: KeyboardEvent| {
+                let rtl = e
+                    .data()
+                    .downcast::<web_sys::KeyboardEvent>()
+                    .and_then(|event| event.target())
+                    .is_some_and(|target| is_rtl(&target));
+                let shift = e.modifiers().shift();
+                if let Some(action) = KeyAction::from_key_event(&e.key().to_string(), shift, rtl) {
+                    e.prevent_default();
+                    on_key.call(action);
+                }
+            },
+            onkeyup: move |_| props.on_commit.call(()),
+            onchange: move |_| props.on_commit.call(()),
+            onpointerdown: move |e: PointerEvent| {
+                let Some(event) = e.data().downcast::<web_sys::PointerEvent>().cloned() else {
+                    return;
+                };
+                if props.disabled || event.button() != 0 {
+                    return;
+                }
+                // Keeps the release on this input when the pointer leaves it mid-drag.
+                if let Some(input) = event.target().and_then(|t| t.dyn_into::<web_sys::Element>().ok()) {
+                    let _ = input.set_pointer_capture(event.pointer_id());
+                }
+                props.on_drag_start.call(());
+            },
+            onpointerup: move |_| props.on_drag_end.call(()),
+            onpointercancel: move |_| props.on_drag_end.call(()),
+            onwheel: move |e: WheelEvent| {
+                if let Some(on_wheel) = props.on_wheel {
+                    e.prevent_default();
+                    on_wheel.call(e.delta().strip_units().y);
+                }
+            },
             disabled: props.disabled,
-            aria_label: props.aria_label.unwrap_or("Slider"),
-            aria_describedby: props.aria_describedby.unwrap_or("Slider description"),
+            "orient": props.orientation.to_orient(),
+            aria_valuemin: "{model.min()}",
+            aria_valuemax: "{model.max()}",
+            aria_valuenow: "{value}",
+            aria_orientation: props.orientation.to_aria(),
+            aria_disabled: "{props.disabled}",
+            aria_label: props.aria_label.clone(),
+            aria_describedby: props.aria_describedby.clone(),
+            aria_valuetext: props.aria_valuetext.clone(),
             style: "{base_style}",
         }
         if let Some(custom_html) = props.custom_thumb_html.clone() {
@@ -293,6 +458,955 @@ fn Input(props: InputProps) -> Element {
     }
 }
 
+/// Configuration of [`use_slider`].
+///
+/// Start from a [`SliderModel`] and override the fields you need, as shown on [`use_slider`].
+#[derive(Clone, PartialEq)]
+pub struct SliderConfig {
+    /// Bounds, steps and thumb rules; its values are the initial values of an uncontrolled
+    /// slider.
+    pub model: SliderModel,
+    /// Values of a controlled slider, one per thumb.
+    pub values: Option<Vec<f64>>,
+    /// Root of the element ids, see [`SliderIds`]; derived from the component scope when unset.
+    pub id: Option<String>,
+    pub orientation: Orientation,
+    pub disabled: bool,
+    /// Lets the mouse wheel move a focused thumb.
+    pub wheel: bool,
+    /// Paces `on_change` and `on_values`.
+    pub rate_limit: RateLimit,
+    /// Text of the values in `aria-valuetext` and the output.
+    pub value_format: ValueFormat,
+    /// Text for each of [`SliderModel::step_values`], replacing the formatted value.
+    pub labels: Vec<String>,
+    /// Accessible name of each thumb, falling back to `aria_label`.
+    pub thumb_labels: Vec<String>,
+    pub aria_label: Option<String>,
+    /// Whether an element with the id [`SliderIds::label`] names thumbs without an
+    /// `aria_label`.
+    pub labelled: bool,
+    /// Id of an element describing the slider.
+    pub aria_describedby: Option<String>,
+    /// Whether an element with the id [`SliderIds::description`] describes the slider when
+    /// `aria_describedby` is unset.
+    pub described: bool,
+    /// Every live change.
+    pub on_change: Callback<SliderChange>,
+    /// All values after a live change, sent right before `on_change`.
+    pub on_values: Callback<Vec<f64>>,
+    /// The last change of an interaction once it ends, marked as committed.
+    pub on_commit: Callback<SliderChange>,
+    pub on_drag_start: Callback<usize>,
+    pub on_drag_end: Callback<usize>,
+    pub on_focus: Callback<usize>,
+    pub on_blur: Callback<usize>,
+}
+
+impl SliderConfig {
+    /// Must be called inside a component, like every [`Callback`].
+    pub fn new(model: SliderModel) -> Self {
+        Self {
+            model,
+            values: None,
+            id: None,
+            orientation: Orientation::default(),
+            disabled: false,
+            wheel: false,
+            rate_limit: RateLimit::default(),
+            value_format: ValueFormat::default(),
+            labels: Vec::new(),
+            thumb_labels: Vec::new(),
+            aria_label: None,
+            labelled: false,
+            aria_describedby: None,
+            described: false,
+            on_change: Callback::default(),
+            on_values: Callback::default(),
+            on_commit: Callback::default(),
+            on_drag_start: Callback::default(),
+            on_drag_end: Callback::default(),
+            on_focus: Callback::default(),
+            on_blur: Callback::default(),
+        }
+    }
+}
+
+/// Attributes and handlers of the track, which moves the closest thumb to a pressed point and
+/// drags it until the pointer is released.
+pub struct TrackAttributes {
+    pub class: &'static str,
+    pub style: &'static str,
+    pub onpointerdown: EventHandler<PointerEvent>,
+    pub onpointermove: EventHandler<PointerEvent>,
+    /// Ends the drag; also use it for `onpointercancel`.
+    pub onpointerup: EventHandler<PointerEvent>,
+}
+
+/// Attributes of the fill inside the track.
+pub struct RangeAttributes {
+    pub class: &'static str,
+    pub style: String,
+}
+
+/// Attributes and handlers of a `role="slider"` thumb inside the track.
+pub struct ThumbAttributes {
+    pub id: String,
+    pub class: &'static str,
+    pub style: String,
+    pub role: &'static str,
+    pub tabindex: &'static str,
+    pub aria_valuemin: String,
+    pub aria_valuemax: String,
+    pub aria_valuenow: String,
+    pub aria_valuetext: String,
+    pub aria_orientation: &'static str,
+    pub aria_disabled: &'static str,
+    pub aria_label: Option<String>,
+    pub aria_labelledby: Option<String>,
+    pub aria_describedby: Option<String>,
+    pub onkeydown: EventHandler<KeyboardEvent>,
+    pub onkeyup: EventHandler<KeyboardEvent>,
+    /// Does nothing unless the wheel is enabled, letting the page scroll.
+    pub onwheel: EventHandler<WheelEvent>,
+    pub onfocus: EventHandler<FocusEvent>,
+    pub onblur: EventHandler<FocusEvent>,
+}
+
+/// Attributes and handlers of a focusable segment between the outer thumbs, which moves the
+/// whole range when dragged or with the arrow, page, Home and End keys.
+pub struct RangeDragAttributes {
+    pub class: &'static str,
+    pub style: String,
+    pub role: &'static str,
+    pub tabindex: &'static str,
+    pub aria_valuemin: String,
+    pub aria_valuemax: String,
+    pub aria_valuenow: String,
+    pub aria_valuetext: String,
+    pub aria_orientation: &'static str,
+    pub aria_disabled: &'static str,
+    pub aria_label: Option<String>,
+    pub aria_labelledby: Option<String>,
+    pub onpointerdown: EventHandler<PointerEvent>,
+    pub onpointermove: EventHandler<PointerEvent>,
+    /// Ends the drag; also use it for `onpointercancel`.
+    pub onpointerup: EventHandler<PointerEvent>,
+    pub onkeydown: EventHandler<KeyboardEvent>,
+    pub onkeyup: EventHandler<KeyboardEvent>,
+    pub onblur: EventHandler<FocusEvent>,
+}
+
+/// Attributes and text of an `<output>` showing the values.
+pub struct OutputAttributes {
+    pub id: String,
+    pub for_ids: String,
+    pub aria_live: &'static str,
+    pub text: String,
+}
+
+/// State and handlers of a headless slider, see [`use_slider`].
+#[derive(Clone)]
+pub struct SliderHandle {
+    /// The slider with its current values.
+    pub model: SliderModel,
+    pub ids: SliderIds,
+    config: Rc<SliderConfig>,
+    values: Signal<Vec<f64>>,
+    pacer: ChangePacer,
+    dragging: Rc<Cell<Option<usize>>>,
+    /// Value under the pointer and start of the range when a range drag began.
+    range_drag: Rc<Cell<Option<(f64, f64)>>>,
+}
+
+impl SliderHandle {
+    pub fn values(&self) -> &[f64] {
+        self.model.values()
+    }
+
+    /// Text of `value`, see [`value_text`].
+    pub fn value_text(&self, value: f64) -> String {
+        value_text(
+            &self.model,
+            &self.config.labels,
+            &self.config.value_format,
+            value,
+        )
+    }
+
+    /// Text of all values, as shown by the output.
+    pub fn output_text(&self) -> String {
+        self.values()
+            .iter()
+            .map(|&value| self.value_text(value))
+            .collect::<Vec<_>>()
+            .join(" – ")
+    }
+
+    pub fn is_dragging(&self) -> bool {
+        self.dragging.get().is_some() || self.range_drag.get().is_some()
+    }
+
+    pub fn aria_label(&self, thumb: usize) -> Option<String> {
+        self.config
+            .thumb_labels
+            .get(thumb)
+            .or(self.config.aria_label.as_ref())
+            .cloned()
+    }
+
+    pub fn aria_describedby(&self) -> Option<String> {
+        self.config
+            .aria_describedby
+            .clone()
+            .or_else(|| self.config.described.then(|| self.ids.description()))
+    }
+
+    /// Moves `thumb` to `value` and returns the index the thumb ended up at, which differs
+    /// from `thumb` when [`RangeBehavior::Swap`] reorders the thumbs.
+    ///
+    /// Pointer input snaps onto the fill origin within its detent, see [`SliderModel::detent`].
+    pub fn set_value(&self, thumb: usize, value: f64, source: ChangeSource) -> Option<usize> {
+        let mut model = self.model.clone();
+        let value = if source == ChangeSource::Pointer {
+            model.detent(value)
+        } else {
+            value
+        };
+        let change = model.set_value(thumb, value)?.with_source(source);
+        Some(self.send(model, change))
+    }
+
+    /// Applies a key to `thumb`, see [`SliderHandle::set_value`].
+    pub fn apply_key(&self, thumb: usize, action: KeyAction) -> Option<usize> {
+        let mut model = self.model.clone();
+        let change = model.apply_key(thumb, action)?;
+        Some(self.send(model, change))
+    }
+
+    /// Moves `thumb` for a wheel delta and commits, see [`SliderHandle::set_value`].
+    pub fn apply_wheel(&self, thumb: usize, delta_y: f64) -> Option<usize> {
+        let mut model = self.model.clone();
+        let change = model.apply_wheel(thumb, delta_y)?;
+        let moved = self.send(model, change);
+        self.commit();
+        Some(moved)
+    }
+
+    /// Moves every thumb by `delta`, see [`SliderModel::shift_range`].
+    pub fn shift_range(&self, delta: f64, source: ChangeSource) {
+        let mut model = self.model.clone();
+        if let Some(change) = model.shift_range(delta) {
+            self.send(model, change.with_source(source));
+        }
+    }
+
+    /// Applies a key to the whole range, see [`SliderModel::apply_range_key`].
+    pub fn apply_range_key(&self, action: KeyAction) {
+        let mut model = self.model.clone();
+        if let Some(change) = model.apply_range_key(action) {
+            self.send(model, change);
+        }
+    }
+
+    /// Sends any change held back and reports the last change as committed.
+    pub fn commit(&self) {
+        if let Some(change) = self.pacer.commit() {
+            self.config.on_commit.call(change.committed());
+        }
+    }
+
+    pub fn drag_start(&self, thumb: usize) {
+        if self.dragging.get().is_none() {
+            self.dragging.set(Some(thumb));
+            self.config.on_drag_start.call(thumb);
+        }
+    }
+
+    /// Ends a drag and commits it.
+    pub fn drag_end(&self) {
+        if let Some(thumb) = self.dragging.take() {
+            self.config.on_drag_end.call(thumb);
+            self.commit();
+        }
+    }
+
+    pub fn focus(&self, thumb: usize) {
+        self.config.on_focus.call(thumb);
+    }
+
+    /// Commits unless a drag is running; swapping thumbs mid-drag moves the focus, and the
+    /// drag commits on release instead.
+    pub fn blur(&self, thumb: usize) {
+        if !self.is_dragging() {
+            self.commit();
+        }
+        self.config.on_blur.call(thumb);
+    }
+
+    pub fn track_props(&self) -> TrackAttributes {
+        let on_pointer_down = {
+            let handle = self.clone();
+            Callback::new(move |e: PointerEvent| {
+                let Some(event) = e.data().downcast::<web_sys::PointerEvent>().cloned() else {
+                    return;
+                };
+                if handle.config.disabled || event.button() != 0 {
+                    return;
+                }
+                if let Some((track, percent)) = pointer_on_track(&event, &handle.config.orientation)
+                {
+                    e.prevent_default();
+                    let _ = track.set_pointer_capture(event.pointer_id());
+                    let value = handle.model.value_from_percent(percent);
+                    let thumb = handle.model.closest_thumb(value);
+                    let thumb = handle
+                        .set_value(thumb, value, ChangeSource::Pointer)
+                        .unwrap_or(thumb);
+                    handle.drag_start(thumb);
+                    focus_thumb(&track, thumb);
+                }
+            })
+        };
+        let on_pointer_move = {
+            let handle = self.clone();
+            Callback::new(move |e: PointerEvent| {
+                let (Some(thumb), Some(event)) = (
+                    handle.dragging.get(),
+                    e.data().downcast::<web_sys::PointerEvent>().cloned(),
+                ) else {
+                    return;
+                };
+                if let Some((track, percent)) = pointer_on_track(&event, &handle.config.orientation)
+                {
+                    let value = handle.model.value_from_percent(percent);
+                    let moved = handle
+                        .set_value(thumb, value, ChangeSource::Pointer)
+                        .unwrap_or(thumb);
+                    if moved != thumb {
+                        focus_thumb(&track, moved);
+                    }
+                    handle.dragging.set(Some(moved));
+                }
+            })
+        };
+        let on_pointer_up = {
+            let handle = self.clone();
+            Callback::new(move |_: PointerEvent| handle.drag_end())
+        };
+        TrackAttributes {
+            class: TRACK_CLASS,
+            style: TRACK_LAYOUT,
+            onpointerdown: on_pointer_down,
+            onpointermove: on_pointer_move,
+            onpointerup: on_pointer_up,
+        }
+    }
+
+    pub fn range_props(&self) -> RangeAttributes {
+        let (start, end) = self.model.fill_percent();
+        RangeAttributes {
+            class: RANGE_CLASS,
+            style: range_layout(&self.config.orientation, start, end),
+        }
+    }
+
+    pub fn thumb_props(&self, thumb: usize) -> ThumbAttributes {
+        let value = self.model.thumb_value(thumb).unwrap_or(self.model.min());
+        let disabled = self.config.disabled;
+        let aria_label = self.aria_label(thumb);
+        let on_key_down = {
+            let handle = self.clone();
+            Callback::new(move |e: KeyboardEvent| {
+                let rtl = e
+                    .data()
+                    .downcast::<web_sys::KeyboardEvent>()
+                    .and_then(|event| event.target())
+                    .is_some_and(|target| is_rtl(&target));
+                let shift = e.modifiers().shift();
+                if let Some(action) = KeyAction::from_key_event(&e.key().to_string(), shift, rtl) {
+                    e.prevent_default();
+                    let moved = handle.apply_key(thumb, action).unwrap_or(thumb);
+                    let target = e
+                        .data()
+                        .downcast::<web_sys::KeyboardEvent>()
+                        .and_then(|event| event.target());
+                    if let (true, Some(target)) = (moved != thumb, target) {
+                        focus_thumb(&target, moved);
+                    }
+                }
+            })
+        };
+        let on_wheel = {
+            let handle = self.clone();
+            let wheel = self.config.wheel && !disabled;
+            Callback::new(move |e: WheelEvent| {
+                if !wheel {
+                    return;
+                }
+                e.prevent_default();
+                let moved = handle
+                    .apply_wheel(thumb, e.delta().strip_units().y)
+                    .unwrap_or(thumb);
+                let target = e
+                    .data()
+                    .downcast::<web_sys::WheelEvent>()
+                    .and_then(|event| event.target());
+                if let (true, Some(target)) = (moved != thumb, target) {
+                    focus_thumb(&target, moved);
+                }
+            })
+        };
+        ThumbAttributes {
+            id: self.ids.input(thumb),
+            class: THUMB_CLASS,
+            style: custom_thumb_style(&self.config.orientation, self.model.percent(value)),
+            role: "slider",
+            tabindex: if disabled { "-1" } else { "0" },
+            aria_valuemin: self.model.min().to_string(),
+            aria_valuemax: self.model.max().to_string(),
+            aria_valuenow: value.to_string(),
+            aria_valuetext: self.value_text(value),
+            aria_orientation: self.config.orientation.to_aria(),
+            aria_disabled: if disabled { "true" } else { "false" },
+            aria_labelledby: (aria_label.is_none() && self.config.labelled)
+                .then(|| self.ids.label()),
+            aria_label,
+            aria_describedby: self.aria_describedby(),
+            onkeydown: on_key_down,
+            onkeyup: {
+                let handle = self.clone();
+                Callback::new(move |_: KeyboardEvent| handle.commit())
+            },
+            onwheel: on_wheel,
+            onfocus: {
+                let handle = self.clone();
+                Callback::new(move |_: FocusEvent| handle.focus(thumb))
+            },
+            onblur: {
+                let handle = self.clone();
+                Callback::new(move |_: FocusEvent| handle.blur(thumb))
+            },
+        }
+    }
+
+    /// Getters for the range drag segment; range drags report the first thumb to
+    /// `on_drag_start` and `on_drag_end`.
+    pub fn range_drag_props(&self) -> RangeDragAttributes {
+        let disabled = self.config.disabled;
+        let (start, end) = self.model.range();
+        let (start_percent, end_percent) = self.model.fill_percent();
+        let on_pointer_down = {
+            let handle = self.clone();
+            Callback::new(move |e: PointerEvent| {
+                let Some(event) = e.data().downcast::<web_sys::PointerEvent>().cloned() else {
+                    return;
+                };
+                if handle.config.disabled || event.button() != 0 {
+                    return;
+                }
+                if let Some((segment, percent)) =
+                    pointer_on_range(&event, &handle.config.orientation)
+                {
+                    e.prevent_default();
+                    e.stop_propagation();
+                    let _ = segment.set_pointer_capture(event.pointer_id());
+                    if let Some(segment) = segment.dyn_ref::<HtmlElement>() {
+                        let _ = segment.focus();
+                    }
+                    let grab = handle.model.value_from_percent(percent);
+                    handle.range_drag.set(Some((grab, handle.model.range().0)));
+                    handle.config.on_drag_start.call(0);
+                }
+            })
+        };
+        let on_pointer_move = {
+            let handle = self.clone();
+            Callback::new(move |e: PointerEvent| {
+                let (Some((grab, grab_start)), Some(event)) = (
+                    handle.range_drag.get(),
+                    e.data().downcast::<web_sys::PointerEvent>().cloned(),
+                ) else {
+                    return;
+                };
+                if let Some((_, percent)) = pointer_on_range(&event, &handle.config.orientation) {
+                    let value = handle.model.value_from_percent(percent);
+                    let delta = grab_start + value - grab - handle.model.range().0;
+                    handle.shift_range(delta, ChangeSource::Pointer);
+                }
+            })
+        };
+        let on_pointer_up = {
+            let handle = self.clone();
+            Callback::new(move |_: PointerEvent| {
+                if handle.range_drag.take().is_some() {
+                    handle.config.on_drag_end.call(0);
+                    handle.commit();
+                }
+            })
+        };
+        let on_key_down = {
+            let handle = self.clone();
+            Callback::new(move |e: KeyboardEvent| {
+                let rtl = e
+                    .data()
+                    .downcast::<web_sys::KeyboardEvent>()
+                    .and_then(|event| event.target())
+                    .is_some_and(|target| is_rtl(&target));
+                let shift = e.modifiers().shift();
+                if let Some(action) = KeyAction::from_key_event(&e.key().to_string(), shift, rtl) {
+                    e.prevent_default();
+                    handle.apply_range_key(action);
+                }
+            })
+        };
+        RangeDragAttributes {
+            class: RANGE_DRAG_CLASS,
+            style: range_drag_style(&self.config.orientation, start_percent, end_percent),
+            role: "slider",
+            tabindex: if disabled { "-1" } else { "0" },
+            aria_valuemin: self.model.min().to_string(),
+            aria_valuemax: (self.model.max() - (end - start)).to_string(),
+            aria_valuenow: start.to_string(),
+            aria_valuetext: self.output_text(),
+            aria_orientation: self.config.orientation.to_aria(),
+            aria_disabled: if disabled { "true" } else { "false" },
+            aria_label: self.config.aria_label.clone(),
+            aria_labelledby: (self.config.aria_label.is_none() && self.config.labelled)
+                .then(|| self.ids.label()),
+            onpointerdown: on_pointer_down,
+            onpointermove: on_pointer_move,
+            onpointerup: on_pointer_up,
+            onkeydown: on_key_down,
+            onkeyup: {
+                let handle = self.clone();
+                Callback::new(move |_: KeyboardEvent| handle.commit())
+            },
+            onblur: {
+                let handle = self.clone();
+                Callback::new(move |_: FocusEvent| {
+                    if !handle.is_dragging() {
+                        handle.commit();
+                    }
+                })
+            },
+        }
+    }
+
+    pub fn output_props(&self) -> OutputAttributes {
+        OutputAttributes {
+            id: self.ids.output(),
+            for_ids: self.ids.inputs(self.values().len()),
+            aria_live: "polite",
+            text: self.output_text(),
+        }
+    }
+
+    /// Records the change and sends it under the rate limit, returning the moved thumb.
+    fn send(&self, model: SliderModel, change: SliderChange) -> usize {
+        let mut signal = self.values;
+        signal.set(model.values().to_vec());
+        let on_values = self.config.on_values;
+        let on_change = self.config.on_change;
+        let values = model.values().to_vec();
+        // A controlled slider renders its own values only while the change is held back, so it
+        // renders again once the change goes out, in case the parent keeps the old value.
+        let controlled = self.config.values.is_some();
+        self.pacer.send(change, move || {
+            on_values.call(values.clone());
+            on_change.call(change);
+            if controlled {
+                signal.set(values);
+            }
+        });
+        change.thumb
+    }
+}
+
+/// Headless slider: the values, ids, pacing and keyboard, wheel and pointer handling of
+/// [`Slider`], with prop getters to render it in your own markup.
+///
+/// The getters target a `div` track holding the fill and one `role="slider"` element per
+/// thumb; keep their `class` on the elements, as the pointer and focus handling look them up.
+///
+/// # Examples
+/// ```rust
+/// use dioxus::prelude::*;
+/// use slider_rs::common::SliderModel;
+/// use slider_rs::dioxus::{SliderConfig, use_slider};
+///
+/// #[component]
+/// fn Volume() -> Element {
+///     let slider = use_slider(SliderConfig {
+///         labelled: true,
+///         ..SliderConfig::new(SliderModel::new(0.0, 100.0, 1.0).with_value(40.0))
+///     });
+///     let track = slider.track_props();
+///     let range = slider.range_props();
+///     let thumb = slider.thumb_props(0);
+///     let output = slider.output_props();
+///
+///     rsx! {
+///         div { class: "volume",
+///             span { id: slider.ids.label(), "Volume" }
+///             div {
+///                 class: "{track.class} volume-track",
+///                 style: track.style,
+///                 onpointerdown: track.onpointerdown,
+///                 onpointermove: track.onpointermove,
+///                 onpointerup: track.onpointerup,
+///                 onpointercancel: track.onpointerup,
+///                 div { class: "{range.class} volume-fill", style: range.style }
+///                 div {
+///                     id: thumb.id,
+///                     class: "{thumb.class} volume-knob",
+///                     style: thumb.style,
+///                     role: thumb.role,
+///                     tabindex: thumb.tabindex,
+///                     aria_valuemin: thumb.aria_valuemin,
+///                     aria_valuemax: thumb.aria_valuemax,
+///                     aria_valuenow: thumb.aria_valuenow,
+///                     aria_valuetext: thumb.aria_valuetext,
+///                     aria_labelledby: thumb.aria_labelledby,
+///                     onkeydown: thumb.onkeydown,
+///                     onkeyup: thumb.onkeyup,
+///                     onblur: thumb.onblur,
+///                 }
+///             }
+///             output { id: output.id, "for": output.for_ids, aria_live: output.aria_live,
+///                 "{output.text}"
+///             }
+///         }
+///     }
+/// }
+/// ```
+pub fn use_slider(config: SliderConfig) -> SliderHandle {
+    use_slider_state(&config).handle(config)
+}
+
+/// The parts of [`SliderHandle`] kept between renders.
+#[derive(Clone)]
+struct SliderState {
+    scope_ids: SliderIds,
+    values: Signal<Vec<f64>>,
+    pacer: ChangePacer,
+    dragging: Rc<Cell<Option<usize>>>,
+    range_drag: Rc<Cell<Option<(f64, f64)>>>,
+}
+
+fn use_slider_state(config: &SliderConfig) -> SliderState {
+    let values = use_signal(|| config.model.values().to_vec());
+    // Scope ids follow the component tree, so the server and the hydrating client agree.
+    let scope_ids = use_hook(|| {
+        current_scope_id()
+            .map(|scope| SliderIds::numbered(scope.0))
+            .unwrap_or_else(|_| SliderIds::generated())
+    });
+    let pacer = use_hook(ChangePacer::default);
+    let dragging = use_hook(|| Rc::new(Cell::new(None::<usize>)));
+    let range_drag = use_hook(|| Rc::new(Cell::new(None::<(f64, f64)>)));
+    SliderState {
+        scope_ids,
+        values,
+        pacer,
+        dragging,
+        range_drag,
+    }
+}
+
+impl SliderState {
+    fn handle(&self, config: SliderConfig) -> SliderHandle {
+        self.pacer.set_limit(config.rate_limit);
+        let current = self
+            .pacer
+            .displayed(config.values.as_deref(), &self.values.read());
+        let ids = config
+            .id
+            .clone()
+            .map(SliderIds::new)
+            .unwrap_or_else(|| self.scope_ids.clone());
+        SliderHandle {
+            model: config.model.clone().with_values(current),
+            ids,
+            config: Rc::new(config),
+            values: self.values,
+            pacer: self.pacer.clone(),
+            dragging: self.dragging.clone(),
+            range_drag: self.range_drag.clone(),
+        }
+    }
+}
+
+/// State shared by the parts of a [`SliderRoot`].
+///
+/// Your own parts get the [`SliderHandle`] with `use_context::<SliderContext>().handle()`.
+#[derive(Clone)]
+pub struct SliderContext {
+    config: ReadOnlySignal<SliderConfig>,
+    state: SliderState,
+}
+
+impl SliderContext {
+    /// The handle for this render; the calling component re-renders when the values or the
+    /// configuration change.
+    pub fn handle(&self) -> SliderHandle {
+        self.state.handle(self.config.cloned())
+    }
+}
+
+#[derive(Props, PartialEq, Clone)]
+pub struct SliderRootProps {
+    /// Configuration of the state shared by the parts, see [`use_slider`].
+    config: ReadOnlySignal<SliderConfig>,
+    /// Colour of the thumbs.
+    #[props(default)]
+    color: Color,
+    #[props(default)]
+    cursor_style: Cursor,
+    #[props(default)]
+    class: String,
+    #[props(default)]
+    style: String,
+    children: Element,
+}
+
+/// SliderRoot Component
+///
+/// Holds the state of a slider assembled from parts: [`SliderTrack`], [`SliderRange`],
+/// [`SliderThumb`], [`SliderMarks`] and [`SliderValue`] read it through a [`SliderContext`]
+/// and can be reordered, wrapped or replaced.
+///
+/// # Examples
+/// ```rust
+/// use dioxus::prelude::*;
+/// use slider_rs::common::{Mark, SliderModel};
+/// use slider_rs::dioxus::{
+///     SliderConfig, SliderMarks, SliderRange, SliderRoot, SliderThumb, SliderTrack, SliderValue,
+/// };
+///
+/// #[component]
+/// fn Budget() -> Element {
+///     let config = SliderConfig {
+///         aria_label: Some("Budget".to_string()),
+///         ..SliderConfig::new(SliderModel::new(0.0, 1000.0, 50.0).with_values(vec![200.0, 600.0]))
+///     };
+///
+///     rsx! {
+///         SliderRoot { config, class: "budget",
+///             SliderTrack {
+///                 SliderRange {}
+///                 SliderThumb { index: 0 }
+///                 SliderThumb { index: 1, class: "upper" }
+///             }
+///             SliderMarks { marks: vec![Mark::new(0.0).with_label("0"), Mark::new(1000.0).with_label("1k")] }
+///             SliderValue {}
+///         }
+///     }
+/// }
+/// ```
+#[component]
+pub fn SliderRoot(props: SliderRootProps) -> Element {
+    let state = use_slider_state(&props.config.peek());
+    let context = use_context_provider(|| SliderContext {
+        config: props.config,
+        state,
+    });
+    let slider = context.handle();
+    let config = &slider.config;
+    rsx! {
+        div {
+            id: slider.ids.root().to_string(),
+            class: part_class("slider-root", &props.class),
+            style: props.style,
+            role: "group",
+            aria_labelledby: config.labelled.then(|| slider.ids.label()),
+            aria_orientation: config.orientation.to_aria(),
+            aria_disabled: "{config.disabled}",
+            style {
+                {thumb_css(slider.ids.root(), &props.cursor_style, &props.color, config.disabled, None)}
+            }
+            {props.children}
+        }
+    }
+}
+
+/// The class of a part followed by the classes passed to it.
+fn part_class(base: &str, class: &str) -> String {
+    if class.is_empty() {
+        base.to_string()
+    } else {
+        format!("{} {}", base, class)
+    }
+}
+
+#[derive(Props, PartialEq, Clone)]
+pub struct SliderTrackProps {
+    #[props(default)]
+    class: String,
+    /// Appended to the default track style.
+    #[props(default)]
+    style: String,
+    children: Element,
+}
+
+/// SliderTrack Component
+///
+/// The track of a [`SliderRoot`]; a press moves the closest thumb there. Place the
+/// [`SliderRange`] and the [`SliderThumb`]s inside it.
+#[component]
+pub fn SliderTrack(props: SliderTrackProps) -> Element {
+    let slider = use_context::<SliderContext>().handle();
+    let track = slider.track_props();
+    let style = format!(
+        "{} {}",
+        custom_track_style(
+            &slider.config.orientation,
+            &Width::default(),
+            &Height::default(),
+            &Size::default(),
+            slider.config.disabled,
+        ),
+        props.style
+    );
+    rsx! {
+        div {
+            class: part_class(track.class, &props.class),
+            style,
+            onpointerdown: track.onpointerdown,
+            onpointermove: track.onpointermove,
+            onpointerup: track.onpointerup,
+            onpointercancel: track.onpointerup,
+            {props.children}
+        }
+    }
+}
+
+#[derive(Props, PartialEq, Clone)]
+pub struct SliderRangeProps {
+    #[props(default)]
+    color: Color,
+    #[props(default)]
+    class: String,
+    /// Appended to the default fill style.
+    #[props(default)]
+    style: String,
+}
+
+/// SliderRange Component
+///
+/// The fill of a [`SliderTrack`], from the start of the track to a single thumb or between
+/// the outer thumbs.
+#[component]
+pub fn SliderRange(props: SliderRangeProps) -> Element {
+    let slider = use_context::<SliderContext>().handle();
+    let range = slider.range_props();
+    let (start, end) = slider.model.fill_percent();
+    let style = format!(
+        "{} {}",
+        custom_range_style(&slider.config.orientation, &props.color, start, end),
+        props.style
+    );
+    rsx! {
+        div { class: part_class(range.class, &props.class), style }
+    }
+}
+
+#[derive(Props, PartialEq, Clone)]
+pub struct SliderThumbProps {
+    /// Index of the thumb, in track order.
+    #[props(default = 0)]
+    index: usize,
+    #[props(default)]
+    class: String,
+    /// Appended to the thumb placement.
+    #[props(default)]
+    style: String,
+    /// Content rendered inside the thumb.
+    #[props(default)]
+    children: Element,
+}
+
+/// SliderThumb Component
+///
+/// A `role="slider"` thumb of a [`SliderTrack`], moved by the keyboard, the wheel and by
+/// dragging; its look comes from the `color` and `cursor_style` of the [`SliderRoot`].
+#[component]
+pub fn SliderThumb(props: SliderThumbProps) -> Element {
+    let slider = use_context::<SliderContext>().handle();
+    let thumb = slider.thumb_props(props.index);
+    rsx! {
+        div {
+            id: thumb.id,
+            class: part_class(thumb.class, &props.class),
+            style: "{thumb.style} {props.style}",
+            role: thumb.role,
+            tabindex: thumb.tabindex,
+            onkeydown: thumb.onkeydown,
+            onkeyup: thumb.onkeyup,
+            onwheel: thumb.onwheel,
+            onfocus: thumb.onfocus,
+            onblur: thumb.onblur,
+            aria_valuemin: thumb.aria_valuemin,
+            aria_valuemax: thumb.aria_valuemax,
+            aria_valuenow: thumb.aria_valuenow,
+            aria_valuetext: thumb.aria_valuetext,
+            aria_orientation: thumb.aria_orientation,
+            aria_disabled: thumb.aria_disabled,
+            aria_label: thumb.aria_label,
+            aria_labelledby: thumb.aria_labelledby,
+            aria_describedby: thumb.aria_describedby,
+            {props.children}
+        }
+    }
+}
+
+#[derive(Props, PartialEq, Clone)]
+pub struct SliderMarksProps {
+    marks: Vec<Mark>,
+}
+
+/// SliderMarks Component
+///
+/// Labelled marks lined up with the thumb positions of a [`SliderRoot`]; set
+/// [`SliderModel::with_snap_points`] on the configuration to make the thumbs snap to them.
+#[component]
+pub fn SliderMarks(props: SliderMarksProps) -> Element {
+    let slider = use_context::<SliderContext>().handle();
+    rsx! {
+        Marks {
+            model: slider.model.clone(),
+            marks: props.marks,
+            orientation: slider.config.orientation.clone(),
+        }
+    }
+}
+
+#[derive(Props, PartialEq, Clone)]
+pub struct SliderValueProps {
+    #[props(default = "slider-output".to_string())]
+    class: String,
+    #[props(default = "font-size: 12px; margin-top: 8px; text-align: center;".to_string())]
+    style: String,
+}
+
+/// SliderValue Component
+///
+/// An `<output>` announcing the values of a [`SliderRoot`] as they change.
+#[component]
+pub fn SliderValue(props: SliderValueProps) -> Element {
+    let slider = use_context::<SliderContext>().handle();
+    let output = slider.output_props();
+    rsx! {
+        Output {
+            id: output.id,
+            for_ids: output.for_ids,
+            value_display: output.text,
+            output_class: props.class,
+            output_style: props.style,
+        }
+    }
+}
+
 /// Props for the `Slider` component.
 ///
 /// These props configure the behavior, appearance, and accessibility of a slider input component
@@ -306,9 +1420,18 @@ fn Input(props: InputProps) -> Element {
 /// - Full accessibility support (ARIA attributes, keyboard steps).
 #[derive(PartialEq, Clone, Props)]
 pub struct SliderProps {
+    /// Id of the slider container; the ids of the label, inputs, datalist, output and
+    /// description derive from it. Defaults to an id derived from the component scope.
+    #[props(default)]
+    pub id: Option<String>,
+
     /// Label text displayed above the slider.
     #[props(default)]
-    pub label: &'static str,
+    pub label: String,
+
+    /// Text below the label that every thumb references through `aria-describedby`.
+    #[props(default)]
+    pub description: Option<String>,
 
     /// Minimum value for the slider.
     #[props(default = 0.0)]
@@ -322,18 +1445,106 @@ pub struct SliderProps {
     #[props(default = 1.0)]
     pub step: f64,
 
-    /// Current value for a single slider mode.
+    /// Controlled value for single slider mode.
+    ///
+    /// When set, the slider always renders this value and only reports user changes through
+    /// `on_change`; the parent is expected to pass the new value back.
     #[props(default)]
     pub value: Option<f64>,
 
-    /// Current range values (start, end) for double slider mode.
+    /// Initial value for an uncontrolled single slider. Defaults to `min`.
+    #[props(default)]
+    pub default_value: Option<f64>,
+
+    /// Controlled range values (start, end) for double slider mode.
+    ///
+    /// When set, the slider always renders this range and only reports user changes through
+    /// `on_change_range`; the parent is expected to pass the new range back.
     #[props(default)]
     pub range: Option<(f64, f64)>,
 
+    /// Initial range for an uncontrolled double slider. Defaults to `(min, max)`.
+    #[props(default)]
+    pub default_range: Option<(f64, f64)>,
+
     /// Whether to enable double slider mode (range selector).
     #[props(default = false)]
     pub double: bool,
 
+    /// Lets the segment between the outer thumbs drag the whole range, keeping its width.
+    ///
+    /// The segment is focusable and moves the range with the arrow, page, Home and End keys.
+    #[props(default = false)]
+    pub draggable_range: bool,
+
+    /// Controlled values for a slider with any number of thumbs.
+    ///
+    /// Takes precedence over `value`, `range` and `double`; one thumb is rendered per value.
+    #[props(default)]
+    pub values: Option<Vec<f64>>,
+
+    /// Initial values for an uncontrolled multi-thumb slider.
+    #[props(default)]
+    pub default_values: Option<Vec<f64>>,
+
+    /// Smallest allowed gap between neighbouring thumbs.
+    #[props(default = 0.0)]
+    pub min_distance: f64,
+
+    /// Largest allowed gap between neighbouring thumbs.
+    #[props(default)]
+    pub max_distance: Option<f64>,
+
+    /// How a thumb reacts when dragged into a neighbouring thumb.
+    #[props(default)]
+    pub range_behavior: RangeBehavior,
+
+    /// How values are distributed along the track.
+    #[props(default)]
+    pub scale: Scale,
+
+    /// Labelled marks drawn at their position along the track.
+    #[props(default)]
+    pub marks: Vec<Mark>,
+
+    /// Restricts values to the `marks` instead of the step grid.
+    #[props(default = false)]
+    pub snap_to_marks: bool,
+
+    /// Coloured regions drawn on the track behind the fill, e.g. thresholds of a gauge.
+    #[props(default)]
+    pub zones: Vec<Zone>,
+
+    /// Colours the fill and the thumbs with the zone holding the last thumb.
+    #[props(default = false)]
+    pub color_by_zone: bool,
+
+    /// Item counts drawn as bars behind the track, spread evenly from `min` to `max`; bars
+    /// outside the selection are dimmed.
+    #[props(default)]
+    pub histogram: Vec<f64>,
+
+    /// Value the fill of a single-thumb slider grows from, e.g. `0.0` for a balance slider
+    /// from `-50.0` to `50.0`. Defaults to `min`.
+    #[props(default)]
+    pub fill_origin: Option<f64>,
+
+    /// Distance from `fill_origin` within which a dragged thumb snaps onto it, like a centre
+    /// detent.
+    #[props(default)]
+    pub origin_detent: Option<f64>,
+
+    /// Text for each of [`SliderModel::step_values`], from `min` upwards.
+    ///
+    /// Replaces the numbers in the output, the tooltip, the step labels and `aria-valuetext`,
+    /// e.g. `["Off", "Low", "High"]` for a `0.0..=2.0` slider.
+    #[props(default)]
+    pub labels: Vec<String>,
+
+    /// How values are shown in the output, the tooltip, the step labels and `aria-valuetext`.
+    #[props(default)]
+    pub value_format: ValueFormat,
+
     /// Slider orientation: horizontal or vertical.
     #[props(default)]
     pub orientation: Orientation,
@@ -346,7 +1557,15 @@ pub struct SliderProps {
     #[props(default)]
     pub color: Color,
 
-    /// Cursor style when hovering over the slider.
+    /// Colour of the track outside the fill; defaults to the theme's track colour.
+    #[props(default)]
+    pub outside_color: Option<Color>,
+
+    /// Runs the fill from the far end of the track, for sliders laid out right to left.
+    #[props(default = false)]
+    pub rtl_fill: bool,
+
+    /// Thumb shape: `Round`, `Square` or `Custom` CSS declarations.
     #[props(default)]
     pub cursor_style: Cursor,
 
@@ -374,6 +1593,37 @@ pub struct SliderProps {
     #[props(default)]
     pub on_change_range: Callback<(f64, f64)>,
 
+    /// Callback triggered with every thumb value when any thumb moves.
+    #[props(default)]
+    pub on_change_values: Callback<Vec<f64>>,
+
+    /// Callback triggered with the final value once a thumb is released, a key is released or
+    /// the slider loses focus.
+    #[props(default)]
+    pub on_change_committed: Callback<f64>,
+
+    /// Callback triggered with the thumb, the old and new value and the source of every change,
+    /// and once more with `committed` set when the interaction ends.
+    #[props(default)]
+    pub on_change_event: Callback<SliderChange>,
+
+    /// Callback triggered with the thumb index when a pointer starts dragging a thumb.
+    #[props(default)]
+    pub on_drag_start: Callback<usize>,
+
+    /// Callback triggered with the thumb index when the pointer lets go of the thumb.
+    #[props(default)]
+    pub on_drag_end: Callback<usize>,
+
+    /// Sends at most one live change per this many milliseconds while the slider moves.
+    #[props(default)]
+    pub throttle_ms: Option<u32>,
+
+    /// Sends a live change only after the slider rested for this many milliseconds; takes
+    /// precedence over `throttle_ms`.
+    #[props(default)]
+    pub debounce_ms: Option<u32>,
+
     /// Callback triggered when the slider gains focus.
     #[props(default)]
     pub on_focus: Callback<()>,
@@ -384,57 +1634,74 @@ pub struct SliderProps {
 
     /// ARIA label for accessibility.
     #[props(default)]
-    pub aria_label: Option<&'static str>,
+    pub aria_label: Option<String>,
 
-    /// ARIA describedby attribute for accessibility.
+    /// Id of an element describing the slider; defaults to the `description`.
+    #[props(default)]
+    pub aria_describedby: Option<String>,
+
+    /// Per-thumb ARIA labels, falling back to `aria_label` for thumbs without one.
     #[props(default)]
-    pub aria_describedby: Option<&'static str>,
+    pub thumb_labels: Vec<String>,
 
     /// CSS class for the container wrapping the slider.
-    #[props(default = "slider-container")]
-    pub container_class: &'static str,
+    #[props(default = "slider-container".to_string())]
+    pub container_class: String,
 
     /// Inline style for the container wrapping the slider.
     #[props(
-        default = "display: flex; flex-direction: column; align-items: center; margin: 20px; position: relative;"
+        default = "display: flex; flex-direction: column; align-items: center; margin: 20px; position: relative;".to_string()
     )]
-    pub container_style: &'static str,
+    pub container_style: String,
+
+    /// Colours, radii and sizes emitted as `--slider-*` CSS variables on the container.
+    ///
+    /// Falls back to the theme of the nearest [`ThemeProvider`].
+    #[props(default)]
+    pub theme: Option<Theme>,
+
+    /// How the track and thumbs are drawn: native range inputs, or a `div` track with
+    /// pointer-driven `role="slider"` thumbs that hold `custom_thumb_html`.
+    #[props(default)]
+    pub renderer: Renderer,
 
     /// CSS class for the slider label.
-    #[props(default = "slider-label")]
-    pub label_class: &'static str,
+    #[props(default = "slider-label".to_string())]
+    pub label_class: String,
 
     /// Inline style for the slider label.
-    #[props(default = "font-size: 14px; margin-bottom: 8px;")]
-    pub label_style: &'static str,
+    #[props(default = "font-size: 14px; margin-bottom: 8px;".to_string())]
+    pub label_style: String,
 
     /// CSS class for the slider input element.
-    #[props(default = "slider-input")]
-    pub input_class: &'static str,
+    #[props(default = "slider-input".to_string())]
+    pub input_class: String,
 
     /// Inline style for the slider input element.
-    #[props(default = "border-radius: 8px; appearance: none; outline: none;")]
-    pub input_style: &'static str,
+    #[props(
+        default = "border-radius: var(--slider-track-radius, 8px); appearance: none; outline: none;".to_string()
+    )]
+    pub input_style: String,
 
     /// CSS class for the value/output display.
-    #[props(default = "slider-output")]
-    pub output_class: &'static str,
+    #[props(default = "slider-output".to_string())]
+    pub output_class: String,
 
     /// Inline style for the value/output display.
-    #[props(default = "font-size: 12px; margin-top: 8px;")]
-    pub output_style: &'static str,
+    #[props(default = "font-size: 12px; margin-top: 8px;".to_string())]
+    pub output_style: String,
 
     /// Inline style for the tooltip element.
     #[props(
-        default = "background-color: #333; color: #fff; padding: 4px 8px; border-radius: 4px; font-size: 12px; display: none;"
+        default = "background-color: var(--slider-tooltip-bg, #333); color: var(--slider-tooltip-text, #fff); padding: 4px 8px; border-radius: 4px; font-size: 12px; display: none;".to_string()
     )]
-    pub tooltip_style: &'static str,
+    pub tooltip_style: String,
 
     /// Inline style for the steps indicator below the slider track.
     #[props(
-        default = "width: 100%; display: flex; justify-content: space-between; margin-top: 8px; font-size: 10px;"
+        default = "width: 100%; display: flex; justify-content: space-between; margin-top: 8px; font-size: 10px;".to_string()
     )]
-    pub steps_style: &'static str,
+    pub steps_style: String,
 
     /// Custom width for the slider track.
     #[props(default)]
@@ -444,9 +1711,9 @@ pub struct SliderProps {
     #[props(default)]
     pub slider_height: Height,
 
-    /// Optional custom CSS for the slider thumb.
+    /// Extra CSS declarations for the native thumb, e.g. `"border: 2px solid #fff;"`.
     #[props(default)]
-    pub custom_thumb_css: Option<&'static str>,
+    pub custom_thumb_css: Option<String>,
 
     /// Optional custom HTML content for the slider thumb.
     #[props(default)]
@@ -456,6 +1723,14 @@ pub struct SliderProps {
     #[props(default = 1.0)]
     pub keyboard_step: f64,
 
+    /// Whether the mouse wheel over a thumb moves it by `keyboard_step`.
+    #[props(default = false)]
+    pub wheel: bool,
+
+    /// Increment for PageUp, PageDown and Shift+arrow keys; defaults to a tenth of the range.
+    #[props(default)]
+    pub page_step: Option<f64>,
+
     /// Optional icon element displayed before the slider.
     #[props(default)]
     pub icon_start: Option<Element>,
@@ -470,7 +1745,7 @@ pub struct SliderProps {
 /// A Dioxus range slider component with extensive customization and accessibility support.
 ///
 /// # Features
-/// - Single value or double range mode (TODO).
+/// - Single value, double range or any number of thumbs.
 /// - Horizontal and vertical orientations.
 /// - Customizable track, thumb, tooltip, and step indicators.
 /// - Full ARIA support for accessibility.
@@ -497,6 +1772,26 @@ pub struct SliderProps {
 /// }
 /// ```
 ///
+/// ## Controlled Slider
+/// ```rust
+/// use dioxus::prelude::*;
+/// use slider_rs::dioxus::Slider;
+///
+/// fn app() -> Element {
+///     let mut value = use_signal(|| 50.0);
+///
+///     rsx! {
+///         Slider {
+///             label: "Volume",
+///             min: 0.0,
+///             max: 100.0,
+///             value: Some(value()),
+///             on_change: move |v| value.set(v),
+///         }
+///     }
+/// }
+/// ```
+///
 /// ## Double Range Slider
 /// ```rust
 /// use dioxus::prelude::*;
@@ -509,7 +1804,7 @@ pub struct SliderProps {
 ///             min: 0.0,
 ///             max: 100.0,
 ///             step: 5.0,
-///             range: Some((20.0, 80.0)),
+///             default_range: Some((20.0, 80.0)),
 ///             double: true,
 ///             show_value: true,
 ///             on_change_range: Callback::new(move |(start, end)| log::info!("Range: {} - {}", start, end)),
@@ -518,9 +1813,52 @@ pub struct SliderProps {
 /// }
 /// ```
 ///
+/// ## Logarithmic Slider
+/// ```rust
+/// use dioxus::prelude::*;
+/// use slider_rs::dioxus::Slider;
+/// use slider_rs::Scale;
+///
+/// fn app() -> Element {
+///     rsx! {
+///         Slider {
+///             label: "Cutoff (Hz)",
+///             min: 20.0,
+///             max: 20_000.0,
+///             default_value: Some(1_000.0),
+///             scale: Scale::Log,
+///             show_steps: true,
+///         }
+///     }
+/// }
+/// ```
+///
+/// ## Multi-Thumb Slider
+/// ```rust
+/// use dioxus::prelude::*;
+/// use slider_rs::dioxus::Slider;
+///
+/// fn app() -> Element {
+///     rsx! {
+///         Slider {
+///             label: "Alert thresholds",
+///             min: 0.0,
+///             max: 100.0,
+///             default_values: Some(vec![25.0, 50.0, 75.0]),
+///             min_distance: 5.0,
+///             thumb_labels: vec!["Warning".into(), "Error".into(), "Critical".into()],
+///             on_change_values: Callback::new(move |values: Vec<f64>| log::info!("Thresholds: {:?}", values)),
+///         }
+///     }
+/// }
+/// ```
+///
 /// # Notes
 /// - Subcomponents include: `Label`, `Input`, `Ticks`, `Output`, `Steps`.
 /// - Double slider mode renders two overlapping input elements.
+/// - `values`/`default_values` render one stacked input per value on a shared track.
+/// - `value`/`range` make the slider controlled; `default_value`/`default_range` leave the
+///   state to the slider itself.
 /// - Styling is fully customizable via `*_style` and `*_class` props.
 ///
 /// # Accessibility
@@ -531,238 +1869,440 @@ pub struct SliderProps {
 /// - [MDN <input type="range"> Element](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/input/range)
 #[component]
 pub fn Slider(props: SliderProps) -> Element {
-    let mut val1 = use_signal(|| props.range.unwrap_or((props.min, props.max)).0);
-    let mut val2 = use_signal(|| props.range.unwrap_or((props.min, props.max)).1);
-
-    let input_ref1: Signal<Option<Rc<MountedData>>> = use_signal(|| None);
-    let input_ref2: Signal<Option<Rc<MountedData>>> = use_signal(|| None);
+    let multi = props.values.is_some() || props.default_values.is_some();
+    let double = props.double && !multi;
+    let initial = if let Some(default_values) = &props.default_values {
+        default_values.clone()
+    } else if props.double {
+        let (start, end) = props.default_range.unwrap_or((props.min, props.max));
+        vec![start, end]
+    } else {
+        vec![props.default_value.unwrap_or(props.min)]
+    };
+    let controlled = if multi {
+        props.values.clone()
+    } else if props.double {
+        props.range.map(|(start, end)| vec![start, end])
+    } else {
+        props.value.map(|value| vec![value])
+    };
+    let context_theme = try_use_context::<ReadOnlySignal<Theme>>();
+    let model = SliderModel::new(props.min, props.max, props.step)
+        .with_keyboard_step(props.keyboard_step)
+        .with_page_step(props.page_step)
+        .with_min_distance(props.min_distance)
+        .with_max_distance(props.max_distance)
+        .with_range_behavior(props.range_behavior)
+        .with_scale(props.scale.clone())
+        .with_snap_points(if props.snap_to_marks {
+            props.marks.iter().map(|mark| mark.value).collect()
+        } else {
+            Vec::new()
+        })
+        .with_fill_origin(props.fill_origin)
+        .with_origin_detent(props.origin_detent)
+        .with_values(initial);
+
+    let slider = use_slider(SliderConfig {
+        model,
+        values: controlled,
+        id: props.id.clone(),
+        orientation: props.orientation.clone(),
+        disabled: props.disabled,
+        wheel: props.wheel,
+        rate_limit: RateLimit {
+            throttle_ms: props.throttle_ms,
+            debounce_ms: props.debounce_ms,
+        },
+        value_format: props.value_format.clone(),
+        labels: props.labels.clone(),
+        thumb_labels: props.thumb_labels.clone(),
+        aria_label: props.aria_label.clone(),
+        labelled: !props.label.is_empty(),
+        aria_describedby: props.aria_describedby.clone(),
+        described: props.description.is_some(),
+        on_change: Callback::new(move |change: SliderChange| {
+            props.on_change.call(change.value);
+            props.on_change_event.call(change);
+        }),
+        on_values: Callback::new(move |values: Vec<f64>| {
+            if double {
+                props
+                    .on_change_range
+                    .call((values[0], values[values.len() - 1]));
+            }
+            props.on_change_values.call(values);
+        }),
+        on_commit: Callback::new(move |change: SliderChange| {
+            props.on_change_committed.call(change.value);
+            props.on_change_event.call(change);
+        }),
+        on_drag_start: props.on_drag_start,
+        on_drag_end: props.on_drag_end,
+        on_focus: Callback::new(move |_| props.on_focus.call(())),
+        on_blur: Callback::new(move |_| props.on_blur.call(())),
+    });
+    let model = &slider.model;
+    let ids = &slider.ids;
+    let thumbs = model.values().len();
+    let color = props
+        .color_by_zone
+        .then(|| Zone::at(&props.zones, model.values()[thumbs - 1]))
+        .flatten()
+        .map_or_else(|| props.color.clone(), |zone| zone.color.clone());
+    let zoned = (!props.zones.is_empty()).then(|| {
+        zoned_background(
+            &color,
+            props.outside_color.as_ref(),
+            &props.zones,
+            model,
+            &props.orientation,
+            props.rtl_fill,
+        )
+    });
 
-    let list_id = use_memo(|| format!("slider-list-{}", Uuid::new_v4()));
+    // One mounted-element signal per thumb; the list grows with the number of values.
+    let input_refs = use_hook(|| Rc::new(RefCell::new(Vec::new())));
+    let input_refs: Vec<Signal<Option<Rc<MountedData>>>> = {
+        let mut input_refs = input_refs.borrow_mut();
+        if input_refs.len() < thumbs {
+            input_refs.resize_with(thumbs, || Signal::new(None));
+        }
+        input_refs.clone()
+    };
+    let list_id = ids.list();
+    let describedby = slider.aria_describedby();
+
+    // Keeps keyboard focus on the moved thumb when `RangeBehavior::Swap` reorders the thumbs.
+    let follow_thumb = {
+        let input_refs = input_refs.clone();
+        move |thumb: usize, moved: Option<usize>| {
+            if let Some(moved) = moved.filter(|&moved| moved != thumb) {
+                if let Some(el) = input_refs[moved]() {
+                    spawn(async move {
+                        let _ = el.set_focus(true).await;
+                    });
+                }
+            }
+        }
+    };
 
-    let update_range = {
-        Callback::new(move |_| {
-            props.on_change_range.call((val1(), val2()));
-            props.on_change.call(val1());
+    let on_input = |thumb: usize| {
+        let slider = slider.clone();
+        let follow_thumb = follow_thumb.clone();
+        Callback::new(move |v: f64| {
+            follow_thumb(thumb, slider.set_value(thumb, v, ChangeSource::Pointer));
         })
     };
 
-    let on_input1 = {
-        Callback::new(move |e: FormEvent| {
-            if let Ok(input) = e.value().parse::<f64>() {
-                val1.set(input);
-                update_range.call(());
-                props.on_change.call(input);
-            }
+    let on_key = |thumb: usize| {
+        let slider = slider.clone();
+        let follow_thumb = follow_thumb.clone();
+        Callback::new(move |action: KeyAction| {
+            follow_thumb(thumb, slider.apply_key(thumb, action));
         })
     };
 
-    let on_input2 = {
-        Callback::new(move |e: FormEvent| {
-            if let Ok(input) = e.value().parse::<f64>() {
-                val2.set(input);
-                update_range.call(());
-                props.on_change.call(input);
-            }
+    let wheel = props.wheel && !props.disabled;
+    let on_wheel = |thumb: usize| {
+        let slider = slider.clone();
+        let follow_thumb = follow_thumb.clone();
+        wheel.then(|| {
+            Callback::new(move |delta_y: f64| {
+                follow_thumb(thumb, slider.apply_wheel(thumb, delta_y));
+            })
         })
     };
 
-    let on_focus_cb = { Callback::new(move |_e: FocusEvent| props.on_focus.call(())) };
-
-    let on_blur_cb = { Callback::new(move |_e: FocusEvent| props.on_blur.call(())) };
+    let commit = {
+        let slider = slider.clone();
+        Callback::new(move |_| slider.commit())
+    };
+    let drag_start = {
+        let slider = slider.clone();
+        Callback::new(move |thumb: usize| slider.drag_start(thumb))
+    };
+    let drag_end = {
+        let slider = slider.clone();
+        Callback::new(move |_| slider.drag_end())
+    };
+    let on_focus_cb = |thumb: usize| {
+        let slider = slider.clone();
+        Callback::new(move |_e: FocusEvent| slider.focus(thumb))
+    };
+    let on_blur_cb = |thumb: usize| {
+        let slider = slider.clone();
+        Callback::new(move |_e: FocusEvent| slider.blur(thumb))
+    };
 
-    let (input_style1, input_style2): (&'static str, &'static str) = if props.double {
-        let flipped_style = Box::leak(Box::new(format!(
-            "{}; transform: rotate(0deg); direction: rtl; z-index: 3; position: relative; flex: 1;",
-            props.input_style
-        )));
-        let normal_style = Box::leak(Box::new(format!(
-            "{}; z-index: 2; position: relative; flex: 1;",
-            props.input_style
-        )));
-        (flipped_style, normal_style)
+    let is_vertical = props.orientation.is_vertical();
+    let step_values = model.step_values();
+    let tick_values = if props.marks.is_empty() {
+        model.tick_values()
     } else {
-        (props.input_style, props.input_style)
+        props
+            .marks
+            .iter()
+            .map(|mark| model.to_input(mark.value))
+            .collect()
     };
-
-    let orientation_attr = if props.orientation.is_vertical() {
-        "vertical"
+    let theme = props
+        .theme
+        .clone()
+        .or_else(|| context_theme.map(|theme| theme.read().clone()));
+    // Native thumbs stop half a thumb short of the track ends; custom thumbs are centred.
+    let native_thumb_size = if props.renderer == Renderer::Native {
+        thumb_size(theme.as_ref()).to_string()
     } else {
-        "horizontal"
+        "0px".to_string()
     };
-
-    let steps_component = if props.show_steps {
-        rsx! {
-            Ticks { id: list_id().clone(), min: props.min, max: props.max, step: props.step }
-            Steps {
-                min: props.min,
-                max: props.max,
-                step: props.step,
-                steps_style: props.steps_style,
-                orientation: props.orientation.clone()
+    let marks = rsx! {
+        if props.zones.iter().any(|zone| zone.label.is_some()) {
+            ZoneLabels {
+                model: model.clone(),
+                zones: props.zones.clone(),
+                orientation: props.orientation.clone(),
+            }
+        }
+        if !props.marks.is_empty() {
+            Marks {
+                model: model.clone(),
+                marks: props.marks.clone(),
+                orientation: props.orientation.clone(),
+                thumb_size: native_thumb_size.clone(),
             }
         }
-    } else {
-        rsx! {}
     };
+    let histogram = (!props.histogram.is_empty()).then(|| {
+        rsx! {
+            Histogram {
+                model: model.clone(),
+                counts: props.histogram.clone(),
+                orientation: props.orientation.clone(),
+                color: color.clone(),
+                rtl_fill: props.rtl_fill,
+                thumb_size: native_thumb_size.clone(),
+            }
+        }
+    });
+    let step_labels = step_values
+        .iter()
+        .map(|&v| slider.value_text(v))
+        .collect::<Vec<_>>();
 
-    let double_input = if props.double {
+    let input = |thumb: usize, background: Option<String>| {
         rsx! {
             Input {
-                input_ref: input_ref2,
-                min: props.min,
-                max: props.max,
-                step: props.step,
-                value: val2(),
+                key: "{thumb}",
+                input_ref: input_refs[thumb],
+                model: model.clone(),
+                thumb: thumb,
+                rtl_fill: props.rtl_fill,
+                background: background,
                 orientation: props.orientation.clone(),
                 disabled: props.disabled,
                 size: props.size.clone(),
-                color: props.color.clone(),
-                cursor_style: props.cursor_style.clone(),
-                input_class: props.input_class,
-                input_style: input_style2,
-                on_input: on_input2,
-                on_focus: on_focus_cb,
-                on_blur: on_blur_cb,
-                datalist_id: Some(list_id().clone()),
-                aria_label: props.aria_label,
-                aria_describedby: props.aria_describedby,
+                color: color.clone(),
+                outside_color: props.outside_color.clone(),
+                input_class: props.input_class.clone(),
+                input_style: props.input_style.clone(),
+                on_input: on_input(thumb),
+                on_key: on_key(thumb),
+                on_wheel: on_wheel(thumb),
+                on_commit: commit,
+                on_drag_start: move |_| drag_start.call(thumb),
+                on_drag_end: drag_end,
+                on_focus: on_focus_cb(thumb),
+                on_blur: on_blur_cb(thumb),
+                id: ids.input(thumb),
+                datalist_id: Some(list_id.clone()),
+                aria_label: slider.aria_label(thumb),
+                aria_describedby: describedby.clone(),
+                aria_valuetext: model.thumb_value(thumb).map(|value| slider.value_text(value)),
                 width: props.slider_width.clone(),
                 height: props.slider_height.clone(),
-                custom_thumb_css: props.custom_thumb_css,
                 custom_thumb_html: props.custom_thumb_html.clone(),
-                keyboard_step: props.keyboard_step,
             }
         }
+    };
+
+    let group_style = if is_vertical {
+        "display: flex; flex-direction: row; align-items: flex-start;"
+    } else if props.double || multi {
+        "position: relative; width: 100%; display: flex; align-items: center;"
     } else {
-        rsx! {}
+        "display: flex; align-items: center; width: 100%;"
     };
 
-    let value_display = if props.show_value {
+    let range_drag = if props.draggable_range && thumbs > 1 {
+        let segment = slider.range_drag_props();
         rsx! {
-            Output {
-                value_display: format!("{:.1}", val1()),
-                output_class: props.output_class,
-                output_style: props.output_style,
-                tooltip_style: props.tooltip_style,
-                show_tooltip: props.show_tooltip,
-                tooltip_left: format!("{:.2}%", ((val1() - props.min) / (props.max - props.min)) * 100.0),
+            style { {RANGE_DRAG_CSS} }
+            div {
+                class: segment.class,
+                style: segment.style,
+                role: segment.role,
+                tabindex: segment.tabindex,
+                onpointerdown: segment.onpointerdown,
+                onpointermove: segment.onpointermove,
+                onpointerup: segment.onpointerup,
+                onpointercancel: segment.onpointerup,
+                onkeydown: segment.onkeydown,
+                onkeyup: segment.onkeyup,
+                onblur: segment.onblur,
+                aria_valuemin: segment.aria_valuemin,
+                aria_valuemax: segment.aria_valuemax,
+                aria_valuenow: segment.aria_valuenow,
+                aria_valuetext: segment.aria_valuetext,
+                aria_orientation: segment.aria_orientation,
+                aria_disabled: segment.aria_disabled,
+                aria_label: segment.aria_label,
+                aria_labelledby: segment.aria_labelledby,
             }
         }
     } else {
         rsx! {}
     };
 
-    let input_group = if props.orientation.is_vertical() {
+    // Range and multi-thumb sliders stack one input per thumb on a shared track: the bottom
+    // input paints the fill between the outer thumbs and the inputs above it stay transparent.
+    let inputs = if props.renderer == Renderer::Custom {
+        let track = slider.track_props();
+        let (start, end) = model.fill_percent();
+        let mut track_style = custom_track_style(
+            &props.orientation,
+            &props.slider_width,
+            &props.slider_height,
+            &props.size,
+            props.disabled,
+        );
+        if let Some(outside) = &props.outside_color {
+            track_style.push_str(&format!(" background: {};", outside.to_color_code()));
+        }
+        if !props.zones.is_empty() {
+            track_style.push(' ');
+            track_style.push_str(&zones_track_background(
+                props.outside_color.as_ref(),
+                &props.zones,
+                model,
+                &props.orientation,
+            ));
+        }
         rsx! {
             div {
-                style: "display: flex; flex-direction: row; align-items: flex-start;",
-                {props.icon_start.unwrap_or(rsx!{})}
-                Input {
-                    input_ref: input_ref1,
-                    min: props.min,
-                    max: props.max,
-                    step: props.step,
-                    value: val1(),
-                    orientation: props.orientation.clone(),
-                    disabled: props.disabled,
-                    size: props.size,
-                    color: props.color,
-                    cursor_style: props.cursor_style,
-                    input_class: props.input_class,
-                    input_style: input_style1,
-                    on_input: on_input1,
-                    on_focus: on_focus_cb,
-                    on_blur: on_blur_cb,
-                    datalist_id: Some(list_id()),
-                    aria_label: props.aria_label,
-                    aria_describedby: props.aria_describedby,
-                    width: props.slider_width,
-                    height: props.slider_height,
-                    custom_thumb_css: props.custom_thumb_css,
-                    custom_thumb_html: props.custom_thumb_html,
-                    keyboard_step: props.keyboard_step,
+                class: track.class,
+                style: track_style,
+                onpointerdown: track.onpointerdown,
+                onpointermove: track.onpointermove,
+                onpointerup: track.onpointerup,
+                onpointercancel: track.onpointerup,
+                div {
+                    class: RANGE_CLASS,
+                    style: custom_range_style(&props.orientation, &color, start, end),
+                }
+                {range_drag}
+                for thumb_props in (0..thumbs).map(|thumb| slider.thumb_props(thumb)) {
+                    div {
+                        key: "{thumb_props.id}",
+                        id: thumb_props.id,
+                        class: thumb_props.class,
+                        style: thumb_props.style,
+                        role: thumb_props.role,
+                        tabindex: thumb_props.tabindex,
+                        onkeydown: thumb_props.onkeydown,
+                        onkeyup: thumb_props.onkeyup,
+                        onwheel: thumb_props.onwheel,
+                        onfocus: thumb_props.onfocus,
+                        onblur: thumb_props.onblur,
+                        aria_valuemin: thumb_props.aria_valuemin,
+                        aria_valuemax: thumb_props.aria_valuemax,
+                        aria_valuenow: thumb_props.aria_valuenow,
+                        aria_valuetext: thumb_props.aria_valuetext,
+                        aria_orientation: thumb_props.aria_orientation,
+                        aria_disabled: thumb_props.aria_disabled,
+                        aria_label: thumb_props.aria_label,
+                        aria_labelledby: thumb_props.aria_labelledby,
+                        aria_describedby: thumb_props.aria_describedby,
+                        {props.custom_thumb_html.clone().unwrap_or(rsx! {})}
+                    }
                 }
-                {double_input}
-                {props.icon_end.unwrap_or(rsx!{})}
-                {steps_component}
             }
         }
-    } else if props.double {
+    } else if thumbs > 1 {
+        let (start, end) = model.fill_percent();
+        let fill = zoned.clone().unwrap_or_else(|| {
+            range_background(
+                &color,
+                props.outside_color.as_ref(),
+                &props.orientation,
+                props.rtl_fill,
+                true,
+                start,
+                end,
+            )
+        });
+        let stack_style = if is_vertical {
+            "display: grid; height: 100%;"
+        } else {
+            "display: grid; width: 100%;"
+        };
         rsx! {
             div {
-                style: "position: relative; width: 100%; display: flex; align-items: center;",
-                {props.icon_start.unwrap_or(rsx!{})}
-                Input {
-                    input_ref: input_ref1,
-                    min: props.min,
-                    max: props.max,
-                    step: props.step,
-                    value: val1(),
-                    orientation: props.orientation.clone(),
-                    disabled: props.disabled,
-                    size: props.size,
-                    color: props.color.clone(),
-                    cursor_style: props.cursor_style.clone(),
-                    input_class: props.input_class,
-                    input_style: input_style1,
-                    on_input: on_input1,
-                    on_focus: on_focus_cb,
-                    on_blur: on_blur_cb,
-                    datalist_id: Some(list_id().clone()),
-                    aria_label: props.aria_label,
-                    aria_describedby: props.aria_describedby,
-                    width: props.slider_width.clone(),
-                    height: props.slider_height.clone(),
-                    custom_thumb_css: props.custom_thumb_css,
-                    custom_thumb_html: props.custom_thumb_html.clone(),
-                    keyboard_step: props.keyboard_step,
+                class: STACKED_THUMBS_CLASS,
+                style: stack_style,
+                style { {STACKED_THUMBS_CSS} }
+                {range_drag}
+                for thumb in 0..thumbs {
+                    {input(
+                        thumb,
+                        Some(if thumb == 0 {
+                            fill.clone()
+                        } else {
+                            "background: transparent;".to_string()
+                        }),
+                    )}
                 }
-                {double_input}
-                {props.icon_end.clone().unwrap_or(rsx!{})}
             }
         }
     } else {
+        input(0, zoned)
+    };
+
+    let vertical_steps = if props.show_steps && is_vertical {
+        rsx! {
+            Ticks { id: list_id.clone(), values: tick_values.clone() }
+            Steps {
+                labels: step_labels.clone(),
+                steps_style: props.steps_style.clone(),
+                orientation: props.orientation.clone()
+            }
+        }
+    } else {
+        rsx! {}
+    };
+
+    let value_display = if props.show_value {
         rsx! {
-            div {
-                style: "display: flex; align-items: center; width: 100%;",
-                {props.icon_start.clone().unwrap_or(rsx!{})}
-                Input {
-                    input_ref: input_ref1,
-                    min: props.min,
-                    max: props.max,
-                    step: props.step,
-                    value: val1(),
-                    orientation: props.orientation.clone(),
-                    disabled: props.disabled,
-                    size: props.size.clone(),
-                    color: props.color.clone(),
-                    cursor_style: props.cursor_style.clone(),
-                    input_class: props.input_class,
-                    input_style: input_style1,
-                    on_input: on_input1,
-                    on_focus: on_focus_cb,
-                    on_blur: on_blur_cb,
-                    datalist_id: Some(list_id().clone()),
-                    aria_label: props.aria_label,
-                    aria_describedby: props.aria_describedby,
-                    width: props.slider_width.clone(),
-                    height: props.slider_height.clone(),
-                    custom_thumb_css: props.custom_thumb_css,
-                    custom_thumb_html: props.custom_thumb_html.clone(),
-                    keyboard_step: props.keyboard_step,
-                }
-                {props.icon_end.clone().unwrap_or(rsx!{})}
+            Output {
+                id: ids.output(),
+                for_ids: ids.inputs(thumbs),
+                value_display: slider.output_text(),
+                output_class: props.output_class.clone(),
+                output_style: props.output_style.clone(),
+                tooltip_style: props.tooltip_style.clone(),
+                show_tooltip: props.show_tooltip,
+                tooltip_left: format!("{:.2}%", model.percent(model.value())),
             }
         }
+    } else {
+        rsx! {}
     };
 
-    let horizontal_steps = if props.show_steps && !props.orientation.is_vertical() {
+    let horizontal_steps = if props.show_steps && !is_vertical {
         rsx! {
             Steps {
-                min: props.min,
-                max: props.max,
-                step: props.step,
-                steps_style: props.steps_style,
+                labels: step_labels.clone(),
+                steps_style: props.steps_style.clone(),
                 orientation: props.orientation.clone()
             }
         }
@@ -770,22 +2310,278 @@ pub fn Slider(props: SliderProps) -> Element {
         rsx! {}
     };
 
+    // The bars share a wrapper with the track, so they span the track alone.
+    let inputs = match histogram {
+        Some(histogram) => rsx! {
+            div {
+                class: "slider-histogram-track",
+                style: histogram_track_style(&props.orientation),
+                {histogram}
+                {inputs}
+            }
+        },
+        None => inputs,
+    };
+    let (container_class, container_style) = themed_container(
+        theme.as_ref(),
+        &props.container_class,
+        &props.container_style,
+    );
+
     rsx! {
         div {
-            class: "{props.container_class}",
-            style: "{props.container_style}",
+            id: ids.root(),
+            class: container_class,
+            style: container_style,
             role: "group",
-            aria_orientation: "{orientation_attr}",
+            aria_labelledby: (!props.label.is_empty()).then(|| ids.label()),
+            aria_orientation: props.orientation.to_aria(),
             aria_disabled: "{props.disabled}",
+            style {
+                {thumb_css(ids.root(), &props.cursor_style, &color, props.disabled, props.custom_thumb_css.as_deref())}
+            }
+            if theme.is_some() {
+                style { {THEME_CSS} }
+            }
             Label {
-                label: props.label,
-                label_class: props.label_class,
-                label_style: props.label_style
+                id: ids.label(),
+                for_id: (props.renderer == Renderer::Native).then(|| ids.input(0)),
+                label: props.label.clone(),
+                label_class: props.label_class.clone(),
+                label_style: props.label_style.clone()
+            }
+            if let Some(description) = props.description {
+                p {
+                    id: ids.description(),
+                    class: "slider-description",
+                    style: "font-size: 12px; margin: 0 0 8px;",
+                    "{description}"
+                }
+            }
+            div {
+                style: group_style,
+                {props.icon_start.clone().unwrap_or(rsx!{})}
+                {inputs}
+                {props.icon_end.clone().unwrap_or(rsx!{})}
+                if is_vertical {
+                    {marks.clone()}
+                }
+                {vertical_steps}
+            }
+            if !is_vertical {
+                {marks}
             }
-            {input_group}
-            Ticks { id: list_id().clone(), min: props.min, max: props.max, step: props.step }
+            Ticks { id: list_id.clone(), values: tick_values }
             {value_display}
             {horizontal_steps}
         }
     }
 }
+
+/// ThemeProvider Component
+///
+/// Shares a [`Theme`] with every [`Slider`] below it; passing a new theme restyles them all.
+///
+/// # Examples
+/// ```rust
+/// use dioxus::prelude::*;
+/// use slider_rs::dioxus::{Slider, ThemeProvider};
+/// use slider_rs::common::Theme;
+///
+/// fn app() -> Element {
+///     let mut dark = use_signal(|| false);
+///     let theme = if dark() { Theme::dark() } else { Theme::light() };
+///
+///     rsx! {
+///         ThemeProvider {
+///             theme: theme,
+///             button { onclick: move |_| dark.toggle(), "Toggle theme" }
+///             Slider { label: "Volume" }
+///             Slider { label: "Balance", min: -1.0, max: 1.0, step: 0.1 }
+///         }
+///     }
+/// }
+/// ```
+#[component]
+pub fn ThemeProvider(
+    /// Theme used by every slider inside, unless a slider sets its own `theme`.
+    theme: ReadOnlySignal<Theme>,
+    children: Element,
+) -> Element {
+    use_context_provider(|| theme);
+    rsx! {
+        {children}
+    }
+}
+
+/// Props for the `OptionSlider` component.
+#[derive(Props, PartialEq, Clone)]
+pub struct OptionSliderProps<T: Clone + PartialEq + Display + 'static> {
+    /// Id of the slider container, see [`SliderProps::id`].
+    #[props(default)]
+    pub id: Option<String>,
+
+    /// Label text displayed above the slider.
+    #[props(default)]
+    pub label: String,
+
+    /// The options to pick from, in track order.
+    pub options: Vec<T>,
+
+    /// Controlled index of the selected option.
+    #[props(default)]
+    pub index: Option<usize>,
+
+    /// Initial index for an uncontrolled slider. Defaults to the first option.
+    #[props(default)]
+    pub default_index: Option<usize>,
+
+    /// Text for an option, used instead of its `Display` output.
+    #[props(default)]
+    pub format: Option<Callback<T, String>>,
+
+    /// Callback triggered with the selected option.
+    #[props(default)]
+    pub on_change: Callback<T>,
+
+    /// Callback triggered with the index of the selected option.
+    #[props(default)]
+    pub on_change_index: Callback<usize>,
+
+    /// Slider orientation: horizontal or vertical.
+    #[props(default)]
+    pub orientation: Orientation,
+
+    /// Size variant for styling the slider track and thumb.
+    #[props(default)]
+    pub size: Size,
+
+    /// Color variant for styling the slider.
+    #[props(default)]
+    pub color: Color,
+
+    /// Thumb shape: `Round`, `Square` or `Custom` CSS declarations.
+    #[props(default)]
+    pub cursor_style: Cursor,
+
+    /// Whether to show the selected option as an output.
+    #[props(default = false)]
+    pub show_value: bool,
+
+    /// Whether to show the option labels along the slider track.
+    #[props(default = true)]
+    pub show_steps: bool,
+
+    /// Whether to show tooltip above the thumb on hover.
+    #[props(default = false)]
+    pub show_tooltip: bool,
+
+    /// Whether to disable interaction with the slider.
+    #[props(default = false)]
+    pub disabled: bool,
+
+    /// ARIA label for accessibility.
+    #[props(default)]
+    pub aria_label: Option<String>,
+
+    /// ARIA describedby attribute for accessibility.
+    #[props(default)]
+    pub aria_describedby: Option<String>,
+
+    /// Custom width for the slider track.
+    #[props(default)]
+    pub slider_width: Width,
+
+    /// Custom height for the slider track.
+    #[props(default)]
+    pub slider_height: Height,
+
+    /// CSS class for the container wrapping the slider.
+    #[props(default = "slider-container".to_string())]
+    pub container_class: String,
+
+    /// Inline style for the container wrapping the slider.
+    #[props(
+        default = "display: flex; flex-direction: column; align-items: center; margin: 20px; position: relative;".to_string()
+    )]
+    pub container_style: String,
+
+    /// Colours, radii and sizes emitted as `--slider-*` CSS variables on the container.
+    ///
+    /// Falls back to the theme of the nearest [`ThemeProvider`].
+    #[props(default)]
+    pub theme: Option<Theme>,
+}
+
+/// OptionSlider Component
+///
+/// A [`Slider`] over a list of options: the thumb snaps to each option and the step labels,
+/// the output, the tooltip and `aria-valuetext` show the option instead of its index.
+///
+/// # Examples
+/// ```rust
+/// use dioxus::prelude::*;
+/// use slider_rs::dioxus::OptionSlider;
+///
+/// fn app() -> Element {
+///     rsx! {
+///         OptionSlider {
+///             label: "Size",
+///             options: vec!["XS", "S", "M", "L", "XL"],
+///             default_index: Some(2),
+///             on_change: Callback::new(move |size| log::info!("Size: {}", size)),
+///         }
+///     }
+/// }
+/// ```
+#[component]
+pub fn OptionSlider<T: Clone + PartialEq + Display + 'static>(
+    props: OptionSliderProps<T>,
+) -> Element {
+    let labels = props
+        .options
+        .iter()
+        .map(|option| match props.format {
+            Some(format) => format.call(option.clone()),
+            None => option.to_string(),
+        })
+        .collect::<Vec<_>>();
+
+    let options = props.options.clone();
+    let on_change = Callback::new(move |value: f64| {
+        let index = value.round() as usize;
+        if let Some(option) = options.get(index) {
+            props.on_change_index.call(index);
+            props.on_change.call(option.clone());
+        }
+    });
+
+    rsx! {
+        Slider {
+            label: props.label.clone(),
+            min: 0.0,
+            max: props.options.len().saturating_sub(1) as f64,
+            step: 1.0,
+            value: props.index.map(|index| index as f64),
+            default_value: props.default_index.map(|index| index as f64),
+            labels: labels,
+            on_change: on_change,
+            orientation: props.orientation.clone(),
+            size: props.size.clone(),
+            color: props.color.clone(),
+            cursor_style: props.cursor_style.clone(),
+            show_value: props.show_value,
+            show_steps: props.show_steps,
+            show_tooltip: props.show_tooltip,
+            disabled: props.disabled,
+            id: props.id.clone(),
+            aria_label: props.aria_label.clone(),
+            aria_describedby: props.aria_describedby.clone(),
+            slider_width: props.slider_width.clone(),
+            slider_height: props.slider_height.clone(),
+            container_class: props.container_class.clone(),
+            container_style: props.container_style.clone(),
+            theme: props.theme.clone(),
+        }
+    }
+}
@@ -1 +1,2331 @@
+#![doc = include_str!("../LEPTOS.md")]
 
+use crate::common::{
+    ChangePacer, ChangeSource, Color, Cursor, HISTOGRAM_VIEW_BOX, Height, KeyAction, Mark,
+    Orientation, RANGE_CLASS, RangeBehavior, RateLimit, Renderer, STACKED_THUMBS_CLASS,
+    STACKED_THUMBS_CSS, Scale, Size, SliderChange, SliderIds, SliderModel, THEME_CSS, THUMB_CLASS,
+    TRACK_CLASS, TRACK_LAYOUT, Theme, ValueFormat, Width, Zone, custom_range_style,
+    custom_thumb_style, custom_track_style, focus_thumb, histogram_bars, histogram_style,
+    histogram_track_style, is_rtl, mark_style, mark_tick_style, marks_container_style,
+    pointer_on_track, range_background, range_layout, themed_container, thumb_css, thumb_size,
+    track_background, value_text, zone_label_style, zoned_background, zones_track_background,
+};
+use leptos::tachys::html::attribute::custom::custom_attribute;
+use leptos::{ev, html, prelude::*};
+use std::fmt::Display;
+use web_sys::HtmlInputElement;
+
+#[component]
+fn Label(
+    id: String,
+    /// Id of the input the label names; custom thumbs point back with `aria-labelledby`.
+    for_id: Option<String>,
+    #[prop(into, default = "".to_string())] label: String,
+    #[prop(into, default = "font-size: 14px; margin-bottom: 8px; text-align: center;".to_string())]
+    label_style: String,
+    #[prop(into, default = "slider-label".to_string())] label_class: String,
+) -> impl IntoView {
+    view! {
+        <label id=id for=for_id class=label_class style=label_style>
+            {label}
+        </label>
+    }
+}
+
+#[component]
+fn Steps(
+    labels: Vec<String>,
+    #[prop(
+        into, default = "width: 100%; display: flex; justify-content: space-between; margin-top: 8px; font-size: 10px;".to_string()
+    )]
+    steps_style: String,
+    #[prop(optional)] orientation: Orientation,
+) -> impl IntoView {
+    let span_style = if orientation.is_vertical() {
+        "margin: 4px 0; writing-mode: vertical-rl; text-align: center;"
+    } else {
+        "text-align: center;"
+    };
+
+    let steps = labels
+        .into_iter()
+        .map(|label| view! { <span style=span_style>{label}</span> })
+        .collect_view();
+
+    let style = if orientation.is_vertical() {
+        "display: flex; flex-direction: column; align-items: center; height: 100%; font-size: 10px;"
+    } else {
+        &steps_style
+    };
+
+    view! { <div style=style.to_string()>{steps}</div> }
+}
+
+#[component]
+fn Output(
+    id: String,
+    /// Ids of the inputs the output shows the value of.
+    for_ids: String,
+    #[prop(into)] value_display: Signal<String>,
+    #[prop(into, default = "font-size: 12px; margin-top: 8px; text-align: center;".to_string())]
+    output_style: String,
+    #[prop(into, default = "slider-output".to_string())] output_class: String,
+    #[prop(
+        into, default = "background-color: var(--slider-tooltip-bg, #333); color: var(--slider-tooltip-text, #fff); padding: 4px 8px; border-radius: 4px; font-size: 12px; position: absolute; transform: translate(-50%, -120%); display: block; pointer-events: none;".to_string()
+    )]
+    tooltip_style: String,
+    #[prop(default = false)] show_tooltip: bool,
+    #[prop(into)] tooltip_left: Signal<String>,
+) -> impl IntoView {
+    view! {
+        <output id=id for=for_ids class=output_class.clone() style=output_style aria-live="polite">
+            {move || value_display.get()}
+        </output>
+        <Show when=move || show_tooltip>
+            <div
+                class=output_class.clone()
+                style={
+                    let tooltip_style = tooltip_style.clone();
+                    move || format!("{} left: {};", tooltip_style, tooltip_left.get())
+                }
+            >
+                {move || value_display.get()}
+            </div>
+        </Show>
+    }
+}
+
+#[component]
+fn Ticks(#[prop(into)] id: String, values: Vec<f64>) -> impl IntoView {
+    let options = values
+        .into_iter()
+        .map(|v| view! { <option value=v.to_string() /> })
+        .collect_view();
+
+    view! { <datalist id=id>{options}</datalist> }
+}
+
+#[component]
+fn Histogram(
+    #[prop(into)] model: Signal<SliderModel>,
+    counts: Vec<f64>,
+    #[prop(optional)] orientation: Orientation,
+    #[prop(into)] color: Signal<Color>,
+    #[prop(default = false)] rtl_fill: bool,
+    /// Thumb size the bars are inset by, see [`histogram_style`].
+    #[prop(into)]
+    thumb_size: Signal<String>,
+) -> impl IntoView {
+    let style = {
+        let orientation = orientation.clone();
+        move || thumb_size.with(|size| histogram_style(&orientation, size))
+    };
+    let bars = move || {
+        let fill = color.with(|color| color.to_color_code().to_string());
+        model
+            .with(|m| histogram_bars(&counts, m, &orientation, rtl_fill))
+            .into_iter()
+            .map(|bar| {
+                view! {
+                    <rect
+                        class="slider-histogram-bar"
+                        x=format!("{:.2}", bar.x)
+                        y=format!("{:.2}", bar.y)
+                        width=format!("{:.2}", bar.width)
+                        height=format!("{:.2}", bar.height)
+                        fill=fill.clone()
+                        opacity=bar.opacity()
+                    />
+                }
+            })
+            .collect_view()
+    };
+
+    view! {
+        <svg
+            class="slider-histogram"
+            viewBox=HISTOGRAM_VIEW_BOX
+            preserveAspectRatio="none"
+            aria-hidden="true"
+            style=style
+        >
+            {bars}
+        </svg>
+    }
+}
+
+#[component]
+fn ZoneLabels(
+    #[prop(into)] model: Signal<SliderModel>,
+    zones: Vec<Zone>,
+    #[prop(optional)] orientation: Orientation,
+) -> impl IntoView {
+    let items = zones
+        .into_iter()
+        .filter(|zone| zone.label.is_some())
+        .map(|zone| {
+            let style = model.with_untracked(|m| zone_label_style(m, &zone, &orientation));
+            view! {
+                <span class="slider-zone-label" style=style>
+                    {zone.label.clone().unwrap_or_default()}
+                </span>
+            }
+        })
+        .collect_view();
+
+    view! {
+        <div class="slider-zones" style=marks_container_style(&orientation)>
+            {items}
+        </div>
+    }
+}
+
+#[component]
+fn Marks(
+    #[prop(into)] model: Signal<SliderModel>,
+    marks: Vec<Mark>,
+    #[prop(optional)] orientation: Orientation,
+    /// Thumb size the marks are inset by, see [`mark_style`].
+    #[prop(into, default = Signal::stored("0px".to_string()))]
+    thumb_size: Signal<String>,
+) -> impl IntoView {
+    let items = marks
+        .into_iter()
+        .map(|mark| {
+            let percent = model.with_untracked(|m| m.percent(mark.value));
+            let style = {
+                let orientation = orientation.clone();
+                move || thumb_size.with(|size| mark_style(percent, &orientation, size))
+            };
+            view! {
+                <span class=mark.class_name() style=style>
+                    <span class="slider-mark-tick" style=mark_tick_style(&orientation)></span>
+                    {mark.label.clone().unwrap_or_default()}
+                </span>
+            }
+        })
+        .collect_view();
+
+    view! {
+        <div class="slider-marks" style=marks_container_style(&orientation)>
+            {items}
+        </div>
+    }
+}
+
+#[component]
+fn Input(
+    #[prop(optional)] input_ref: NodeRef<html::Input>,
+    #[prop(into)] model: Signal<SliderModel>,
+    #[prop(default = 0)] thumb: usize,
+    #[prop(optional)] orientation: Orientation,
+    #[prop(optional)] size: Size,
+    #[prop(optional)] width: Width,
+    #[prop(optional)] height: Height,
+    #[prop(optional)] color: Color,
+    outside_color: Option<Color>,
+    #[prop(default = false)] disabled: bool,
+    on_input: Callback<f64>,
+    on_key: Callback<KeyAction>,
+    /// Receives the vertical wheel delta; without it the wheel scrolls the page.
+    on_wheel: Option<Callback<f64>>,
+    /// Fires when a key is released or the browser reports the final value.
+    on_commit: Callback<()>,
+    on_drag_start: Callback<()>,
+    on_drag_end: Callback<()>,
+    on_focus: Callback<ev::FocusEvent>,
+    on_blur: Callback<ev::FocusEvent>,
+    id: String,
+    aria_label: Option<String>,
+    aria_describedby: Option<String>,
+    #[prop(into)] aria_valuetext: Signal<Option<String>>,
+    datalist_id: Option<String>,
+    #[prop(into, default = "slider-input".to_string())] input_class: String,
+    #[prop(into)] input_style: String,
+    #[prop(default = true)] use_gradient: bool,
+    custom_thumb_html: Option<ViewFn>,
+    #[prop(default = false)] rtl_fill: bool,
+    /// Overrides the computed track `background` declaration.
+    background: Option<Signal<String>>,
+) -> impl IntoView {
+    let value = move || model.with(|m| m.thumb_value(thumb).unwrap_or(m.min()));
+    let (min, max) = model.with_untracked(|m| (m.min(), m.max()));
+    let (input_min, input_max, step) = model.with_untracked(|m| {
+        let (input_min, input_max) = m.input_bounds();
+        (input_min, input_max, m.input_step())
+    });
+    let input_value = move || model.with(|m| m.to_input(value()));
+    let static_style = format!(
+        "{} {} {} {} {}",
+        input_style,
+        width.to_style(),
+        height.to_style(),
+        orientation.to_style(),
+        size.to_style(),
+    );
+    let aria_orientation = orientation.to_aria();
+    let orient = orientation.to_orient();
+
+    let style = move || {
+        let background = background
+            .map(|background| background.get())
+            .unwrap_or_else(|| {
+                if model.with(|m| m.fill_origin().is_some()) {
+                    let (start, end) = model.with(SliderModel::fill_percent);
+                    return range_background(
+                        &color,
+                        outside_color.as_ref(),
+                        &orientation,
+                        rtl_fill,
+                        use_gradient,
+                        start,
+                        end,
+                    );
+                }
+                let percent = model.with(|m| m.percent(value()));
+                track_background(
+                    &color,
+                    outside_color.as_ref(),
+                    &orientation,
+                    rtl_fill,
+                    use_gradient,
+                    percent,
+                )
+            });
+        format!(
+            "cursor: pointer; transition: background 0.3s; {} {}",
+            static_style, background
+        )
+    };
+
+    let on_key_down = move |e: ev::KeyboardEvent| {
+        let rtl = e.target().is_some_and(|target| is_rtl(&target));
+        if let Some(action) = KeyAction::from_key_event(&e.key(), e.shift_key(), rtl) {
+            e.prevent_default();
+            on_key.run(action);
+        }
+    };
+
+    let on_pointer_down = move |e: ev::PointerEvent| {
+        if disabled || e.button() != 0 {
+            return;
+        }
+        // Keeps the release on this input when the pointer leaves it mid-drag.
+        let _ = event_target::<HtmlInputElement>(&e).set_pointer_capture(e.pointer_id());
+        on_drag_start.run(());
+    };
+
+    let input = view! {
+        <input
+            node_ref=input_ref
+            id=id
+            type="range"
+            class=input_class
+            min=input_min.to_string()
+            max=input_max.to_string()
+            step=step
+            prop:value=move || input_value().to_string()
+            list=datalist_id
+            on:input=move |e| {
+                if let Ok(v) = event_target_value(&e).parse::<f64>() {
+                    on_input.run(model.with_untracked(|m| m.from_input(v)));
+                    // Mirror the accepted value, which stays unchanged when the move is rejected.
+                    event_target::<HtmlInputElement>(&e)
+                        .set_value(&untrack(input_value).to_string());
+                }
+            }
+            on:change=move |_| on_commit.run(())
+            on:focus=move |e| on_focus.run(e)
+            on:blur=move |e| on_blur.run(e)
+            on:keydown=on_key_down
+            on:keyup=move |_| on_commit.run(())
+            on:pointerdown=on_pointer_down
+            on:pointerup=move |_| on_drag_end.run(())
+            on:pointercancel=move |_| on_drag_end.run(())
+            on:wheel=move |e: ev::WheelEvent| {
+                if let Some(on_wheel) = on_wheel {
+                    e.prevent_default();
+                    on_wheel.run(e.delta_y());
+                }
+            }
+            style=style
+            disabled=disabled
+        />
+    }
+    // An element takes at most 26 attributes, so the ARIA ones are added as one group.
+    .add_any_attr((
+        // `prop:value` is not rendered on the server, so the markup carries the initial value.
+        custom_attribute("value", untrack(input_value).to_string()),
+        custom_attribute("orient", orient),
+        custom_attribute("aria-valuemin", min.to_string()),
+        custom_attribute("aria-valuemax", max.to_string()),
+        custom_attribute("aria-valuenow", move || value().to_string()),
+        custom_attribute("aria-orientation", aria_orientation),
+        custom_attribute("aria-disabled", disabled.to_string()),
+        custom_attribute("aria-label", aria_label.clone()),
+        custom_attribute("aria-describedby", aria_describedby),
+        custom_attribute("aria-valuetext", move || aria_valuetext.get()),
+    ));
+
+    view! {
+        {input}
+        {custom_thumb_html.map(|thumb| thumb.run())}
+    }
+}
+
+/// Ids for a slider without an `id`.
+///
+/// While rendering on the server or hydrating, the request's shared context numbers the
+/// sliders in render order on both sides, so the ids match; otherwise a counter is used.
+fn generated_ids() -> SliderIds {
+    #[cfg(any(feature = "ssr", feature = "hydrate"))]
+    if let Some(context) = Owner::current_shared_context() {
+        if !context.is_browser() || context.during_hydration() {
+            return SliderIds::new(format!("slider-h{}", context.next_id().into_inner()));
+        }
+    }
+    SliderIds::generated()
+}
+
+/// Configuration of [`use_slider`].
+///
+/// Start from a [`SliderModel`] and override the fields you need.
+///
+/// # Examples
+/// ```rust
+/// use slider_rs::common::SliderModel;
+/// use slider_rs::leptos::SliderConfig;
+///
+/// let config = SliderConfig {
+///     wheel: true,
+///     ..SliderConfig::new(SliderModel::new(0.0, 100.0, 5.0).with_value(40.0))
+/// };
+/// assert_eq!(config.model.value(), 40.0);
+/// ```
+#[derive(Clone)]
+pub struct SliderConfig {
+    /// Bounds, steps and thumb rules; its values are the initial values.
+    pub model: SliderModel,
+    /// Values the thumbs follow whenever the signal changes, one per thumb.
+    pub values: Option<Signal<Vec<f64>>>,
+    /// Root of the element ids, see [`SliderIds`]; generated when unset.
+    pub id: Option<String>,
+    pub orientation: Orientation,
+    pub disabled: bool,
+    /// Lets the mouse wheel move a focused thumb.
+    pub wheel: bool,
+    /// Paces `on_change` and `on_values`.
+    pub rate_limit: RateLimit,
+    /// Text of the values in `aria-valuetext` and the output.
+    pub value_format: ValueFormat,
+    /// Text for each of [`SliderModel::step_values`], replacing the formatted value.
+    pub labels: Vec<String>,
+    /// Accessible name of each thumb, falling back to `aria_label`.
+    pub thumb_labels: Vec<String>,
+    pub aria_label: Option<String>,
+    /// Whether an element with the id [`SliderIds::label`] names thumbs without an
+    /// `aria_label`.
+    pub labelled: bool,
+    /// Id of an element describing the slider.
+    pub aria_describedby: Option<String>,
+    /// Whether an element with the id [`SliderIds::description`] describes the slider when
+    /// `aria_describedby` is unset.
+    pub described: bool,
+    /// Every live change.
+    pub on_change: Option<Callback<SliderChange>>,
+    /// All values after a live change, sent right before `on_change`.
+    pub on_values: Option<Callback<Vec<f64>>>,
+    /// The last change of an interaction once it ends, marked as committed.
+    pub on_commit: Option<Callback<SliderChange>>,
+    pub on_drag_start: Option<Callback<usize>>,
+    pub on_drag_end: Option<Callback<usize>>,
+    pub on_focus: Option<Callback<usize>>,
+    pub on_blur: Option<Callback<usize>>,
+}
+
+impl SliderConfig {
+    pub fn new(model: SliderModel) -> Self {
+        Self {
+            model,
+            values: None,
+            id: None,
+            orientation: Orientation::default(),
+            disabled: false,
+            wheel: false,
+            rate_limit: RateLimit::default(),
+            value_format: ValueFormat::default(),
+            labels: Vec::new(),
+            thumb_labels: Vec::new(),
+            aria_label: None,
+            labelled: false,
+            aria_describedby: None,
+            described: false,
+            on_change: None,
+            on_values: None,
+            on_commit: None,
+            on_drag_start: None,
+            on_drag_end: None,
+            on_focus: None,
+            on_blur: None,
+        }
+    }
+}
+
+/// An event handler returned by the prop getters of [`SliderHandle`].
+pub type Handler<E> = Box<dyn FnMut(E)>;
+
+/// Attributes and handlers of the track, which moves the closest thumb to a pressed point and
+/// drags it until the pointer is released.
+pub struct TrackAttributes {
+    pub class: &'static str,
+    pub style: &'static str,
+    pub on_pointerdown: Handler<ev::PointerEvent>,
+    pub on_pointermove: Handler<ev::PointerEvent>,
+    pub on_pointerup: Handler<ev::PointerEvent>,
+    pub on_pointercancel: Handler<ev::PointerEvent>,
+}
+
+/// Attributes of the fill inside the track.
+pub struct RangeAttributes {
+    pub class: &'static str,
+    pub style: Signal<String>,
+}
+
+/// Attributes and handlers of a `role="slider"` thumb inside the track.
+pub struct ThumbAttributes {
+    pub id: String,
+    pub class: &'static str,
+    pub style: Signal<String>,
+    pub role: &'static str,
+    pub tabindex: &'static str,
+    pub aria_valuemin: String,
+    pub aria_valuemax: String,
+    pub aria_valuenow: Signal<String>,
+    pub aria_valuetext: Signal<String>,
+    pub aria_orientation: &'static str,
+    pub aria_disabled: &'static str,
+    pub aria_label: Option<String>,
+    pub aria_labelledby: Option<String>,
+    pub aria_describedby: Option<String>,
+    pub on_keydown: Handler<ev::KeyboardEvent>,
+    pub on_keyup: Handler<ev::KeyboardEvent>,
+    /// Does nothing unless the wheel is enabled, letting the page scroll.
+    pub on_wheel: Handler<ev::WheelEvent>,
+    pub on_focus: Handler<ev::FocusEvent>,
+    pub on_blur: Handler<ev::FocusEvent>,
+}
+
+/// Attributes and text of an `<output>` showing the values.
+pub struct OutputAttributes {
+    pub id: String,
+    pub for_ids: Signal<String>,
+    pub aria_live: &'static str,
+    pub text: Signal<String>,
+}
+
+/// State and handlers of a headless slider, see [`use_slider`].
+#[derive(Clone, Copy)]
+pub struct SliderHandle {
+    /// The slider with its current values.
+    pub model: RwSignal<SliderModel>,
+    ids: StoredValue<SliderIds>,
+    config: StoredValue<SliderConfig>,
+    pacer: StoredValue<ChangePacer, LocalStorage>,
+    dragging: StoredValue<Option<usize>>,
+}
+
+impl SliderHandle {
+    pub fn ids(&self) -> SliderIds {
+        self.ids.get_value()
+    }
+
+    /// The current values, tracked.
+    pub fn values(&self) -> Vec<f64> {
+        self.model.with(|m| m.values().to_vec())
+    }
+
+    /// Text of `value`, see [`value_text`].
+    pub fn value_text(&self, value: f64) -> String {
+        self.model.with_untracked(|m| {
+            self.config
+                .with_value(|config| value_text(m, &config.labels, &config.value_format, value))
+        })
+    }
+
+    /// Text of all values, as shown by the output; tracked.
+    pub fn output_text(&self) -> String {
+        self.values()
+            .into_iter()
+            .map(|value| self.value_text(value))
+            .collect::<Vec<_>>()
+            .join(" – ")
+    }
+
+    pub fn is_dragging(&self) -> bool {
+        self.dragging.get_value().is_some()
+    }
+
+    pub fn aria_label(&self, thumb: usize) -> Option<String> {
+        self.config.with_value(|config| {
+            config
+                .thumb_labels
+                .get(thumb)
+                .or(config.aria_label.as_ref())
+                .cloned()
+        })
+    }
+
+    pub fn aria_describedby(&self) -> Option<String> {
+        self.config.with_value(|config| {
+            config
+                .aria_describedby
+                .clone()
+                .filter(|id| !id.is_empty())
+                .or_else(|| config.described.then(|| self.ids().description()))
+        })
+    }
+
+    /// Moves `thumb` to `value` and returns the index the thumb ended up at, which differs
+    /// from `thumb` when [`RangeBehavior::Swap`] reorders the thumbs.
+    ///
+    /// Pointer input snaps onto the fill origin within its detent, see [`SliderModel::detent`].
+    pub fn set_value(&self, thumb: usize, value: f64, source: ChangeSource) -> Option<usize> {
+        let mut next = self.model.get_untracked();
+        let value = if source == ChangeSource::Pointer {
+            next.detent(value)
+        } else {
+            value
+        };
+        let change = next.set_value(thumb, value)?.with_source(source);
+        Some(self.send(next, change))
+    }
+
+    /// Applies a key to `thumb`, see [`SliderHandle::set_value`].
+    pub fn apply_key(&self, thumb: usize, action: KeyAction) -> Option<usize> {
+        let mut next = self.model.get_untracked();
+        let change = next.apply_key(thumb, action)?;
+        Some(self.send(next, change))
+    }
+
+    /// Moves `thumb` for a wheel delta and commits, see [`SliderHandle::set_value`].
+    pub fn apply_wheel(&self, thumb: usize, delta_y: f64) -> Option<usize> {
+        let mut next = self.model.get_untracked();
+        let change = next.apply_wheel(thumb, delta_y)?;
+        let moved = self.send(next, change);
+        self.commit();
+        Some(moved)
+    }
+
+    /// Sends any change held back and reports the last change as committed.
+    pub fn commit(&self) {
+        if let Some(change) = self.pacer.with_value(ChangePacer::commit) {
+            if let Some(cb) = self.config.with_value(|config| config.on_commit) {
+                cb.run(change.committed());
+            }
+        }
+    }
+
+    pub fn drag_start(&self, thumb: usize) {
+        if self.dragging.get_value().is_none() {
+            self.dragging.set_value(Some(thumb));
+            if let Some(cb) = self.config.with_value(|config| config.on_drag_start) {
+                cb.run(thumb);
+            }
+        }
+    }
+
+    /// Ends a drag and commits it.
+    pub fn drag_end(&self) {
+        if let Some(thumb) = self.dragging.try_update_value(Option::take).flatten() {
+            if let Some(cb) = self.config.with_value(|config| config.on_drag_end) {
+                cb.run(thumb);
+            }
+            self.commit();
+        }
+    }
+
+    pub fn focus(&self, thumb: usize) {
+        if let Some(cb) = self.config.with_value(|config| config.on_focus) {
+            cb.run(thumb);
+        }
+    }
+
+    /// Commits unless a drag is running; swapping thumbs mid-drag moves the focus, and the
+    /// drag commits on release instead.
+    pub fn blur(&self, thumb: usize) {
+        if !self.is_dragging() {
+            self.commit();
+        }
+        if let Some(cb) = self.config.with_value(|config| config.on_blur) {
+            cb.run(thumb);
+        }
+    }
+
+    pub fn track_props(&self) -> TrackAttributes {
+        let handle = *self;
+        let (disabled, orientation) = self
+            .config
+            .with_value(|config| (config.disabled, config.orientation.clone()));
+        let on_pointer_down = {
+            let orientation = orientation.clone();
+            move |e: ev::PointerEvent| {
+                if disabled || e.button() != 0 {
+                    return;
+                }
+                if let Some((track, percent)) = pointer_on_track(&e, &orientation) {
+                    e.prevent_default();
+                    let _ = track.set_pointer_capture(e.pointer_id());
+                    let (thumb, value) = handle.model.with_untracked(|m| {
+                        let value = m.value_from_percent(percent);
+                        (m.closest_thumb(value), value)
+                    });
+                    let thumb = handle
+                        .set_value(thumb, value, ChangeSource::Pointer)
+                        .unwrap_or(thumb);
+                    handle.drag_start(thumb);
+                    focus_thumb(&track, thumb);
+                }
+            }
+        };
+        let on_pointer_move = move |e: ev::PointerEvent| {
+            let Some(thumb) = handle.dragging.get_value() else {
+                return;
+            };
+            if let Some((track, percent)) = pointer_on_track(&e, &orientation) {
+                let value = handle
+                    .model
+                    .with_untracked(|m| m.value_from_percent(percent));
+                let moved = handle
+                    .set_value(thumb, value, ChangeSource::Pointer)
+                    .unwrap_or(thumb);
+                if moved != thumb {
+                    focus_thumb(&track, moved);
+                }
+                handle.dragging.set_value(Some(moved));
+            }
+        };
+        TrackAttributes {
+            class: TRACK_CLASS,
+            style: TRACK_LAYOUT,
+            on_pointerdown: Box::new(on_pointer_down),
+            on_pointermove: Box::new(on_pointer_move),
+            on_pointerup: Box::new(move |_| handle.drag_end()),
+            on_pointercancel: Box::new(move |_| handle.drag_end()),
+        }
+    }
+
+    pub fn range_props(&self) -> RangeAttributes {
+        let model = self.model;
+        let orientation = self.config.with_value(|config| config.orientation.clone());
+        RangeAttributes {
+            class: RANGE_CLASS,
+            style: Signal::derive(move || {
+                let (start, end) = model.with(SliderModel::fill_percent);
+                range_layout(&orientation, start, end)
+            }),
+        }
+    }
+
+    pub fn thumb_props(&self, thumb: usize) -> ThumbAttributes {
+        let handle = *self;
+        let model = self.model;
+        let (disabled, wheel, orientation, labelled) = self.config.with_value(|config| {
+            (
+                config.disabled,
+                config.wheel,
+                config.orientation.clone(),
+                config.labelled,
+            )
+        });
+        let value = move || model.with(|m| m.thumb_value(thumb).unwrap_or(m.min()));
+        let aria_label = self.aria_label(thumb);
+        let on_key_down = move |e: ev::KeyboardEvent| {
+            let rtl = e.target().is_some_and(|target| is_rtl(&target));
+            if let Some(action) = KeyAction::from_key_event(&e.key(), e.shift_key(), rtl) {
+                e.prevent_default();
+                let moved = handle.apply_key(thumb, action).unwrap_or(thumb);
+                if let (true, Some(target)) = (moved != thumb, e.target()) {
+                    focus_thumb(&target, moved);
+                }
+            }
+        };
+        let on_wheel = move |e: ev::WheelEvent| {
+            if !wheel || disabled {
+                return;
+            }
+            e.prevent_default();
+            let moved = handle.apply_wheel(thumb, e.delta_y()).unwrap_or(thumb);
+            if let (true, Some(target)) = (moved != thumb, e.target()) {
+                focus_thumb(&target, moved);
+            }
+        };
+        ThumbAttributes {
+            id: self.ids.with_value(|ids| ids.input(thumb)),
+            class: THUMB_CLASS,
+            style: {
+                let orientation = orientation.clone();
+                Signal::derive(move || {
+                    custom_thumb_style(&orientation, model.with(|m| m.percent(value())))
+                })
+            },
+            role: "slider",
+            tabindex: if disabled { "-1" } else { "0" },
+            aria_valuemin: model.with_untracked(|m| m.min().to_string()),
+            aria_valuemax: model.with_untracked(|m| m.max().to_string()),
+            aria_valuenow: Signal::derive(move || value().to_string()),
+            aria_valuetext: Signal::derive(move || handle.value_text(value())),
+            aria_orientation: orientation.to_aria(),
+            aria_disabled: if disabled { "true" } else { "false" },
+            aria_labelledby: (aria_label.is_none() && labelled)
+                .then(|| self.ids.with_value(SliderIds::label)),
+            aria_label,
+            aria_describedby: self.aria_describedby(),
+            on_keydown: Box::new(on_key_down),
+            on_keyup: Box::new(move |_| handle.commit()),
+            on_wheel: Box::new(on_wheel),
+            on_focus: Box::new(move |_| handle.focus(thumb)),
+            on_blur: Box::new(move |_| handle.blur(thumb)),
+        }
+    }
+
+    pub fn output_props(&self) -> OutputAttributes {
+        let handle = *self;
+        OutputAttributes {
+            id: self.ids.with_value(SliderIds::output),
+            for_ids: Signal::derive(move || {
+                handle
+                    .ids
+                    .with_value(|ids| ids.inputs(handle.model.with(|m| m.values().len())))
+            }),
+            aria_live: "polite",
+            text: Signal::derive(move || handle.output_text()),
+        }
+    }
+
+    /// Records the change and sends it under the rate limit, returning the moved thumb.
+    fn send(&self, next: SliderModel, change: SliderChange) -> usize {
+        let values = next.values().to_vec();
+        self.model.set(next);
+        let (on_values, on_change) = self
+            .config
+            .with_value(|config| (config.on_values, config.on_change));
+        let send = move || {
+            if let Some(cb) = on_values {
+                cb.run(values);
+            }
+            if let Some(cb) = on_change {
+                cb.run(change);
+            }
+        };
+        self.pacer.with_value(|pacer| pacer.send(change, send));
+        change.thumb
+    }
+}
+
+/// Headless slider: the values, ids, pacing and keyboard, wheel and pointer handling of
+/// [`Slider`], with prop getters to render it in your own markup.
+///
+/// The getters target a `div` track holding the fill and one `role="slider"` element per
+/// thumb; keep their `class` on the elements, as the pointer and focus handling look them up.
+///
+/// # Examples
+/// ```rust
+/// use leptos::prelude::*;
+/// use slider_rs::common::SliderModel;
+/// use slider_rs::leptos::{SliderConfig, use_slider};
+///
+/// #[component]
+/// fn Volume() -> impl IntoView {
+///     let slider = use_slider(SliderConfig {
+///         labelled: true,
+///         ..SliderConfig::new(SliderModel::new(0.0, 100.0, 1.0).with_value(40.0))
+///     });
+///     let track = slider.track_props();
+///     let range = slider.range_props();
+///     let thumb = slider.thumb_props(0);
+///     let output = slider.output_props();
+///
+///     view! {
+///         <div class="volume">
+///             <span id=slider.ids().label()>"Volume"</span>
+///             <div
+///                 class=format!("{} volume-track", track.class)
+///                 style=track.style
+///                 on:pointerdown=track.on_pointerdown
+///                 on:pointermove=track.on_pointermove
+///                 on:pointerup=track.on_pointerup
+///                 on:pointercancel=track.on_pointercancel
+///             >
+///                 <div class=format!("{} volume-fill", range.class) style=range.style></div>
+///                 <div
+///                     id=thumb.id
+///                     class=format!("{} volume-knob", thumb.class)
+///                     style=thumb.style
+///                     role=thumb.role
+///                     tabindex=thumb.tabindex
+///                     aria-valuemin=thumb.aria_valuemin
+///                     aria-valuemax=thumb.aria_valuemax
+///                     aria-valuenow=thumb.aria_valuenow
+///                     aria-valuetext=thumb.aria_valuetext
+///                     aria-labelledby=thumb.aria_labelledby
+///                     on:keydown=thumb.on_keydown
+///                     on:keyup=thumb.on_keyup
+///                     on:blur=thumb.on_blur
+///                 ></div>
+///             </div>
+///             <output id=output.id for=output.for_ids aria-live=output.aria_live>
+///                 {output.text}
+///             </output>
+///         </div>
+///     }
+/// }
+/// ```
+pub fn use_slider(config: SliderConfig) -> SliderHandle {
+    let model = RwSignal::new(match config.values {
+        Some(values) => config.model.clone().with_values(values.get_untracked()),
+        None => config.model.clone(),
+    });
+    if let Some(values) = config.values {
+        Effect::new(move |_| {
+            let values = values.get();
+            if model.with_untracked(|m| m.values() != values.as_slice()) {
+                model.update(|m| *m = m.clone().with_values(values));
+            }
+        });
+    }
+
+    let ids = config
+        .id
+        .clone()
+        .filter(|id| !id.is_empty())
+        .map(SliderIds::new)
+        .unwrap_or_else(generated_ids);
+    let pacer = StoredValue::new_local(ChangePacer::default());
+    pacer.with_value(|pacer| pacer.set_limit(config.rate_limit));
+    SliderHandle {
+        model,
+        ids: StoredValue::new(ids),
+        config: StoredValue::new(config),
+        pacer,
+        dragging: StoredValue::new(None::<usize>),
+    }
+}
+
+/// SliderRoot Component
+///
+/// Holds the state of a slider assembled from parts: [`SliderTrack`], [`SliderRange`],
+/// [`SliderThumb`], [`SliderMarks`] and [`SliderValue`] read it through context and can be
+/// reordered, wrapped or replaced. Your own parts get the shared [`SliderHandle`] with
+/// `expect_context::<SliderHandle>()`.
+///
+/// # Examples
+/// ```rust
+/// use leptos::prelude::*;
+/// use slider_rs::common::{Mark, SliderModel};
+/// use slider_rs::leptos::{
+///     SliderConfig, SliderMarks, SliderRange, SliderRoot, SliderThumb, SliderTrack, SliderValue,
+/// };
+///
+/// #[component]
+/// fn Budget() -> impl IntoView {
+///     let config = SliderConfig {
+///         aria_label: Some("Budget".to_string()),
+///         ..SliderConfig::new(SliderModel::new(0.0, 1000.0, 50.0).with_values(vec![200.0, 600.0]))
+///     };
+///
+///     view! {
+///         <SliderRoot config=config class="budget">
+///             <SliderTrack>
+///                 <SliderRange />
+///                 <SliderThumb index=0 />
+///                 <SliderThumb index=1 class="upper" />
+///             </SliderTrack>
+///             <SliderMarks marks=vec![Mark::new(0.0).with_label("0"), Mark::new(1000.0).with_label("1k")] />
+///             <SliderValue />
+///         </SliderRoot>
+///     }
+/// }
+/// ```
+#[component]
+pub fn SliderRoot(
+    /// Configuration of the state shared by the parts, see [`use_slider`].
+    config: SliderConfig,
+    /// Colour of the thumbs.
+    #[prop(optional)]
+    color: Color,
+    #[prop(optional)] cursor_style: Cursor,
+    #[prop(into, optional)] class: String,
+    #[prop(into, optional)] style: String,
+    children: Children,
+) -> impl IntoView {
+    let (labelled, orientation, disabled) =
+        (config.labelled, config.orientation.clone(), config.disabled);
+    let slider = use_slider(config);
+    provide_context(slider);
+    let ids = slider.ids();
+    view! {
+        <div
+            id=ids.root().to_string()
+            class=format!("slider-root {}", class)
+            style=style
+            role="group"
+            aria-labelledby=labelled.then(|| ids.label())
+            aria-orientation=orientation.to_aria()
+            aria-disabled=disabled.to_string()
+        >
+            <style>{thumb_css(ids.root(), &cursor_style, &color, disabled, None)}</style>
+            {children()}
+        </div>
+    }
+}
+
+/// SliderTrack Component
+///
+/// The track of a [`SliderRoot`]; a press moves the closest thumb there. Place the
+/// [`SliderRange`] and the [`SliderThumb`]s inside it.
+#[component]
+pub fn SliderTrack(
+    #[prop(into, optional)] class: String,
+    /// Appended to the default track style.
+    #[prop(into, optional)]
+    style: String,
+    children: Children,
+) -> impl IntoView {
+    let slider = expect_context::<SliderHandle>();
+    let track = slider.track_props();
+    let (orientation, disabled) = slider
+        .config
+        .with_value(|config| (config.orientation.clone(), config.disabled));
+    let style = format!(
+        "{} {}",
+        custom_track_style(
+            &orientation,
+            &Width::default(),
+            &Height::default(),
+            &Size::default(),
+            disabled,
+        ),
+        style
+    );
+    view! {
+        <div
+            class=format!("{} {}", track.class, class)
+            style=style
+            on:pointerdown=track.on_pointerdown
+            on:pointermove=track.on_pointermove
+            on:pointerup=track.on_pointerup
+            on:pointercancel=track.on_pointercancel
+        >
+            {children()}
+        </div>
+    }
+}
+
+/// SliderRange Component
+///
+/// The fill of a [`SliderTrack`], from the start of the track to a single thumb or between
+/// the outer thumbs.
+#[component]
+pub fn SliderRange(
+    #[prop(optional)] color: Color,
+    #[prop(into, optional)] class: String,
+    /// Appended to the default fill style.
+    #[prop(into, optional)]
+    style: String,
+) -> impl IntoView {
+    let slider = expect_context::<SliderHandle>();
+    let range = slider.range_props();
+    let orientation = slider
+        .config
+        .with_value(|config| config.orientation.clone());
+    let range_style = move || {
+        let (start, end) = slider.model.with(SliderModel::fill_percent);
+        format!(
+            "{} {}",
+            custom_range_style(&orientation, &color, start, end),
+            style
+        )
+    };
+    view! { <div class=format!("{} {}", range.class, class) style=range_style></div> }
+}
+
+/// SliderThumb Component
+///
+/// A `role="slider"` thumb of a [`SliderTrack`], moved by the keyboard, the wheel and by
+/// dragging; its look comes from the `color` and `cursor_style` of the [`SliderRoot`].
+#[component]
+pub fn SliderThumb(
+    /// Index of the thumb, in track order.
+    #[prop(optional)]
+    index: usize,
+    #[prop(into, optional)] class: String,
+    /// Appended to the thumb placement.
+    #[prop(into, optional)]
+    style: String,
+    /// Content rendered inside the thumb.
+    #[prop(optional)]
+    children: Option<Children>,
+) -> impl IntoView {
+    let slider = expect_context::<SliderHandle>();
+    let thumb = slider.thumb_props(index);
+    let thumb_style = thumb.style;
+    view! {
+        <div
+            id=thumb.id
+            class=format!("{} {}", thumb.class, class)
+            style=move || format!("{} {}", thumb_style.get(), style)
+            role=thumb.role
+            tabindex=thumb.tabindex
+            on:keydown=thumb.on_keydown
+            on:keyup=thumb.on_keyup
+            on:wheel=thumb.on_wheel
+            on:focus=thumb.on_focus
+            on:blur=thumb.on_blur
+            aria-valuemin=thumb.aria_valuemin
+            aria-valuemax=thumb.aria_valuemax
+            aria-valuenow=thumb.aria_valuenow
+            aria-valuetext=thumb.aria_valuetext
+            aria-orientation=thumb.aria_orientation
+            aria-disabled=thumb.aria_disabled
+            aria-label=thumb.aria_label
+            aria-labelledby=thumb.aria_labelledby
+            aria-describedby=thumb.aria_describedby
+        >
+            {children.map(|children| children())}
+        </div>
+    }
+}
+
+/// SliderMarks Component
+///
+/// Labelled marks lined up with the thumb positions of a [`SliderRoot`]; set
+/// [`SliderModel::with_snap_points`] on the configuration to make the thumbs snap to them.
+#[component]
+pub fn SliderMarks(marks: Vec<Mark>) -> impl IntoView {
+    let slider = expect_context::<SliderHandle>();
+    let orientation = slider
+        .config
+        .with_value(|config| config.orientation.clone());
+    view! { <Marks model=slider.model marks=marks orientation=orientation /> }
+}
+
+/// SliderValue Component
+///
+/// An `<output>` announcing the values of a [`SliderRoot`] as they change.
+#[component]
+pub fn SliderValue(
+    #[prop(into, default = "slider-output".to_string())] class: String,
+    #[prop(into, default = "font-size: 12px; margin-top: 8px; text-align: center;".to_string())]
+    style: String,
+) -> impl IntoView {
+    let slider = expect_context::<SliderHandle>();
+    let output = slider.output_props();
+    view! {
+        <output
+            id=output.id
+            for=output.for_ids
+            class=class
+            style=style
+            aria-live=output.aria_live
+        >
+            {output.text}
+        </output>
+    }
+}
+
+/// Slider Component
+///
+/// A Leptos slider (range input) component, supporting both single and double handle sliders.
+///
+/// # Features
+/// - Single value, double range or any number of thumbs.
+/// - Two-way binding through `RwSignal`s for `value`, `range` and `values`.
+/// - Horizontal and vertical orientations.
+/// - Customizable track, thumb, tooltip, and step indicators.
+/// - Full ARIA support for accessibility.
+/// - Keyboard controls with adjustable increments.
+///
+/// # Examples
+///
+/// ## Single Value Slider
+/// ```rust
+/// use leptos::prelude::*;
+/// use slider_rs::leptos::Slider;
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     let volume = RwSignal::new(50.0);
+///
+///     view! {
+///         <Slider
+///             label="Volume"
+///             min=0.0
+///             max=100.0
+///             step=1.0
+///             value=volume
+///             show_value=true
+///             on_change=Callback::new(|v: f64| leptos::logging::log!("Value: {}", v))
+///         />
+///     }
+/// }
+/// ```
+///
+/// ## Double Range Slider
+/// ```rust
+/// use leptos::prelude::*;
+/// use slider_rs::leptos::Slider;
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     let range = RwSignal::new((20.0, 80.0));
+///
+///     view! {
+///         <Slider
+///             label="Range"
+///             min=0.0
+///             max=100.0
+///             step=1.0
+///             range=range
+///             double=true
+///             show_value=true
+///             on_change_range=Callback::new(|(start, end): (f64, f64)| {
+///                 leptos::logging::log!("Range: {} - {}", start, end)
+///             })
+///         />
+///     }
+/// }
+/// ```
+///
+/// ## Logarithmic Slider
+/// ```rust
+/// use leptos::prelude::*;
+/// use slider_rs::leptos::Slider;
+/// use slider_rs::Scale;
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     view! {
+///         <Slider
+///             label="Cutoff (Hz)"
+///             min=20.0
+///             max=20_000.0
+///             default_value=1_000.0
+///             scale=Scale::Log
+///             show_steps=true
+///         />
+///     }
+/// }
+/// ```
+///
+/// ## Multi-Thumb Slider
+/// ```rust
+/// use leptos::prelude::*;
+/// use slider_rs::leptos::Slider;
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     let thresholds = RwSignal::new(vec![25.0, 50.0, 75.0]);
+///
+///     view! {
+///         <Slider
+///             label="Alert thresholds"
+///             min=0.0
+///             max=100.0
+///             values=thresholds
+///             min_distance=5.0
+///             thumb_labels=vec!["Warning".into(), "Error".into(), "Critical".into()]
+///             on_change_values=Callback::new(|values: Vec<f64>| {
+///                 leptos::logging::log!("Thresholds: {:?}", values)
+///             })
+///         />
+///     }
+/// }
+/// ```
+///
+/// # Notes
+/// - Subcomponents include: `Label`, `Input`, `Ticks`, `Output`, `Steps`.
+/// - Double slider mode renders two overlapping input elements.
+/// - `values`/`default_values` render one stacked input per value on a shared track.
+/// - Styling is fully customizable via `*_style` and `*_class` props.
+///
+/// # Accessibility
+/// - Supports ARIA roles, orientation, and description attributes.
+/// - Keyboard navigation with configurable step increments.
+///
+/// # See Also
+/// - [MDN <input type="range"> Element](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/input/range)
+#[component]
+pub fn Slider(
+    /// Id of the slider container; the ids of the label, inputs, datalist, output and
+    /// description derive from it. Generated once per slider when unset.
+    #[prop(optional, into)]
+    id: Option<String>,
+
+    /// Label text displayed above the slider.
+    #[prop(into, default = "".to_string())]
+    label: String,
+
+    /// Text below the label that every thumb references through `aria-describedby`.
+    #[prop(optional, into)]
+    description: Option<String>,
+
+    /// Minimum value for the slider.
+    #[prop(default = 0.0)]
+    min: f64,
+
+    /// Maximum value for the slider.
+    #[prop(default = 10.0)]
+    max: f64,
+
+    /// Increment step size.
+    #[prop(default = 1.0)]
+    step: f64,
+
+    /// Current value for a single slider mode, kept in sync in both directions.
+    #[prop(optional, into)]
+    value: Option<RwSignal<f64>>,
+
+    /// Initial value when no `value` signal is bound. Defaults to `min`.
+    #[prop(optional, into)]
+    default_value: Option<f64>,
+
+    /// Current range values (start, end) for double slider mode, kept in sync in both directions.
+    #[prop(optional, into)]
+    range: Option<RwSignal<(f64, f64)>>,
+
+    /// Initial range when no `range` signal is bound. Defaults to `(min, max)`.
+    #[prop(optional, into)]
+    default_range: Option<(f64, f64)>,
+
+    /// Whether to enable double slider mode (range selector).
+    #[prop(default = false)]
+    double: bool,
+
+    /// Values for a slider with any number of thumbs, kept in sync in both directions.
+    ///
+    /// Takes precedence over `value`, `range` and `double`; one thumb is rendered per value.
+    #[prop(optional, into)]
+    values: Option<RwSignal<Vec<f64>>>,
+
+    /// Initial values when no `values` signal is bound.
+    #[prop(optional, into)]
+    default_values: Option<Vec<f64>>,
+
+    /// Smallest allowed gap between neighbouring thumbs.
+    #[prop(default = 0.0)]
+    min_distance: f64,
+
+    /// Largest allowed gap between neighbouring thumbs.
+    #[prop(optional, into)]
+    max_distance: Option<f64>,
+
+    /// How a thumb reacts when dragged into a neighbouring thumb.
+    #[prop(optional)]
+    range_behavior: RangeBehavior,
+
+    /// How values are distributed along the track.
+    #[prop(optional)]
+    scale: Scale,
+
+    /// Labelled marks drawn at their position along the track.
+    #[prop(optional)]
+    marks: Vec<Mark>,
+
+    /// Restricts values to the `marks` instead of the step grid.
+    #[prop(default = false)]
+    snap_to_marks: bool,
+
+    /// Coloured regions drawn on the track behind the fill, e.g. thresholds of a gauge.
+    #[prop(optional)]
+    zones: Vec<Zone>,
+
+    /// Colours the fill and the thumbs with the zone holding the last thumb.
+    #[prop(default = false)]
+    color_by_zone: bool,
+
+    /// Item counts drawn as bars behind the track, spread evenly from `min` to `max`; bars
+    /// outside the selection are dimmed.
+    #[prop(optional)]
+    histogram: Vec<f64>,
+
+    /// Value the fill of a single-thumb slider grows from, e.g. `0.0` for a balance slider
+    /// from `-50.0` to `50.0`. Defaults to `min`.
+    #[prop(optional, into)]
+    fill_origin: Option<f64>,
+
+    /// Distance from `fill_origin` within which a dragged thumb snaps onto it, like a centre
+    /// detent.
+    #[prop(optional, into)]
+    origin_detent: Option<f64>,
+
+    /// Text for each of [`SliderModel::step_values`], from `min` upwards.
+    ///
+    /// Replaces the numbers in the output, the tooltip, the step labels and `aria-valuetext`,
+    /// e.g. `["Off", "Low", "High"]` for a `0.0..=2.0` slider.
+    #[prop(optional)]
+    labels: Vec<String>,
+
+    /// How values are shown in the output, the tooltip, the step labels and `aria-valuetext`.
+    #[prop(optional)]
+    value_format: ValueFormat,
+
+    /// Slider orientation: horizontal or vertical.
+    #[prop(optional)]
+    orientation: Orientation,
+
+    /// Size variant for styling the slider track and thumb.
+    #[prop(optional)]
+    size: Size,
+
+    /// Color variant for styling the slider.
+    #[prop(optional)]
+    color: Color,
+
+    /// Colour of the track outside the fill; defaults to the theme's track colour.
+    #[prop(optional, into)]
+    outside_color: Option<Color>,
+
+    /// Runs the fill from the far end of the track, for sliders laid out right to left.
+    #[prop(default = false)]
+    rtl_fill: bool,
+
+    /// Thumb shape: `Round`, `Square` or `Custom` CSS declarations.
+    #[prop(optional)]
+    cursor_style: Cursor,
+
+    /// Whether to show the current value as an output.
+    #[prop(default = false)]
+    show_value: bool,
+
+    /// Whether to show step ticks along the slider track.
+    #[prop(default = false)]
+    show_steps: bool,
+
+    /// Whether to show tooltip above the thumb on hover.
+    #[prop(default = false)]
+    show_tooltip: bool,
+
+    /// Whether to disable interaction with the slider.
+    #[prop(default = false)]
+    disabled: bool,
+
+    /// Callback triggered when the slider value changes.
+    #[prop(optional, into)]
+    on_change: Option<Callback<f64>>,
+
+    /// Callback triggered when the slider range changes (double mode).
+    #[prop(optional, into)]
+    on_change_range: Option<Callback<(f64, f64)>>,
+
+    /// Callback triggered with every thumb value when any thumb moves.
+    #[prop(optional, into)]
+    on_change_values: Option<Callback<Vec<f64>>>,
+
+    /// Callback triggered with the final value once a thumb is released, a key is released or
+    /// the slider loses focus.
+    #[prop(optional, into)]
+    on_change_committed: Option<Callback<f64>>,
+
+    /// Callback triggered with the thumb, the old and new value and the source of every change,
+    /// and once more with `committed` set when the interaction ends.
+    #[prop(optional, into)]
+    on_change_event: Option<Callback<SliderChange>>,
+
+    /// Callback triggered with the thumb index when a pointer starts dragging a thumb.
+    #[prop(optional, into)]
+    on_drag_start: Option<Callback<usize>>,
+
+    /// Callback triggered with the thumb index when the pointer lets go of the thumb.
+    #[prop(optional, into)]
+    on_drag_end: Option<Callback<usize>>,
+
+    /// Sends at most one live change per this many milliseconds while the slider moves.
+    #[prop(optional, into)]
+    throttle_ms: Option<u32>,
+
+    /// Sends a live change only after the slider rested for this many milliseconds; takes
+    /// precedence over `throttle_ms`.
+    #[prop(optional, into)]
+    debounce_ms: Option<u32>,
+
+    /// Callback triggered when the slider gains focus.
+    #[prop(optional, into)]
+    on_focus: Option<Callback<()>>,
+
+    /// Callback triggered when the slider loses focus.
+    #[prop(optional, into)]
+    on_blur: Option<Callback<()>>,
+
+    /// ARIA label for accessibility.
+    #[prop(optional, into)]
+    aria_label: Option<String>,
+
+    /// Id of an element describing the slider; defaults to the `description`.
+    #[prop(optional, into)]
+    aria_describedby: Option<String>,
+
+    /// Per-thumb ARIA labels, falling back to `aria_label` for thumbs without one.
+    #[prop(optional)]
+    thumb_labels: Vec<String>,
+
+    /// CSS class for the container wrapping the slider.
+    #[prop(into, default = "slider-container".to_string())]
+    container_class: String,
+
+    /// Inline style for the container wrapping the slider.
+    #[prop(
+        into, default = "display: flex; flex-direction: column; align-items: center; margin: 20px; position: relative;".to_string()
+    )]
+    container_style: String,
+
+    /// Colours, radii and sizes emitted as `--slider-*` CSS variables on the container.
+    ///
+    /// Falls back to the theme of the nearest [`ThemeProvider`].
+    #[prop(optional, into)]
+    theme: MaybeProp<Theme>,
+
+    /// How the track and thumbs are drawn: native range inputs, or a `div` track with
+    /// pointer-driven `role="slider"` thumbs that hold `custom_thumb_html`.
+    #[prop(optional)]
+    renderer: Renderer,
+
+    /// CSS class for the slider label.
+    #[prop(into, default = "slider-label".to_string())]
+    label_class: String,
+
+    /// Inline style for the slider label.
+    #[prop(into, default = "font-size: 14px; margin-bottom: 8px;".to_string())]
+    label_style: String,
+
+    /// CSS class for the slider input element.
+    #[prop(into, default = "slider-input".to_string())]
+    input_class: String,
+
+    /// Inline style for the slider input element.
+    #[prop(
+        into, default = "border-radius: var(--slider-track-radius, 8px); appearance: none; outline: none;".to_string()
+    )]
+    input_style: String,
+
+    /// CSS class for the value/output display.
+    #[prop(into, default = "slider-output".to_string())]
+    output_class: String,
+
+    /// Inline style for the value/output display.
+    #[prop(into, default = "font-size: 12px; margin-top: 8px;".to_string())]
+    output_style: String,
+
+    /// Inline style for the tooltip element.
+    #[prop(
+        into, default = "background-color: var(--slider-tooltip-bg, #333); color: var(--slider-tooltip-text, #fff); padding: 4px 8px; border-radius: 4px; font-size: 12px; display: none;".to_string()
+    )]
+    tooltip_style: String,
+
+    /// Inline style for the steps indicator below the slider track.
+    #[prop(
+        into, default = "width: 100%; display: flex; justify-content: space-between; margin-top: 8px; font-size: 10px;".to_string()
+    )]
+    steps_style: String,
+
+    /// Custom width for the slider track.
+    #[prop(optional)]
+    slider_width: Width,
+
+    /// Custom height for the slider track.
+    #[prop(optional)]
+    slider_height: Height,
+
+    /// Extra CSS declarations for the native thumb, e.g. `"border: 2px solid #fff;"`.
+    #[prop(optional, into)]
+    custom_thumb_css: Option<String>,
+
+    /// Optional custom HTML content for the slider thumb.
+    #[prop(optional, into)]
+    custom_thumb_html: Option<ViewFn>,
+
+    /// Keyboard step increment for arrow key adjustments.
+    #[prop(default = 1.0)]
+    keyboard_step: f64,
+
+    /// Whether the mouse wheel over a thumb moves it by `keyboard_step`.
+    #[prop(default = false)]
+    wheel: bool,
+
+    /// Increment for PageUp, PageDown and Shift+arrow keys; defaults to a tenth of the range.
+    #[prop(optional, into)]
+    page_step: Option<f64>,
+
+    /// Optional icon element displayed before the slider.
+    #[prop(optional, into)]
+    icon_start: Option<ViewFn>,
+
+    /// Optional icon element displayed after the slider.
+    #[prop(optional, into)]
+    icon_end: Option<ViewFn>,
+) -> impl IntoView {
+    let multi = values.is_some() || default_values.is_some();
+    let double = double && !multi;
+    let value = value.unwrap_or_else(|| RwSignal::new(default_value.unwrap_or(min)));
+    let range = range.unwrap_or_else(|| RwSignal::new(default_range.unwrap_or((min, max))));
+    let values = values.unwrap_or_else(|| RwSignal::new(default_values.unwrap_or_default()));
+    // One node ref per thumb; the list grows with the number of values.
+    let input_refs = StoredValue::new(Vec::<NodeRef<html::Input>>::new());
+    let input_ref = move |thumb: usize| {
+        input_refs.update_value(|refs| {
+            if refs.len() <= thumb {
+                refs.resize_with(thumb + 1, NodeRef::new);
+            }
+        });
+        input_refs.with_value(|refs| refs[thumb])
+    };
+
+    let bound_values = Signal::derive(move || {
+        if multi {
+            values.get()
+        } else if double {
+            let (start, end) = range.get();
+            vec![start, end]
+        } else {
+            vec![value.get()]
+        }
+    });
+    let model = SliderModel::new(min, max, step)
+        .with_keyboard_step(keyboard_step)
+        .with_page_step(page_step)
+        .with_min_distance(min_distance)
+        .with_max_distance(max_distance)
+        .with_range_behavior(range_behavior)
+        .with_scale(scale)
+        .with_snap_points(if snap_to_marks {
+            marks.iter().map(|mark| mark.value).collect()
+        } else {
+            Vec::new()
+        })
+        .with_fill_origin(fill_origin)
+        .with_origin_detent(origin_detent);
+
+    let slider = use_slider(SliderConfig {
+        values: Some(bound_values),
+        id,
+        orientation: orientation.clone(),
+        disabled,
+        wheel,
+        rate_limit: RateLimit {
+            throttle_ms,
+            debounce_ms,
+        },
+        value_format,
+        labels,
+        thumb_labels,
+        aria_label,
+        labelled: !label.is_empty(),
+        aria_describedby,
+        described: description.is_some(),
+        on_change: Some(Callback::new(move |change: SliderChange| {
+            if let Some(cb) = on_change {
+                cb.run(change.value);
+            }
+            if let Some(cb) = on_change_event {
+                cb.run(change);
+            }
+        })),
+        on_values: Some(Callback::new(move |next: Vec<f64>| {
+            if double {
+                if let Some(cb) = on_change_range {
+                    cb.run((next[0], next[next.len() - 1]));
+                }
+            }
+            if let Some(cb) = on_change_values {
+                cb.run(next);
+            }
+        })),
+        on_commit: Some(Callback::new(move |change: SliderChange| {
+            if let Some(cb) = on_change_committed {
+                cb.run(change.value);
+            }
+            if let Some(cb) = on_change_event {
+                cb.run(change);
+            }
+        })),
+        on_drag_start,
+        on_drag_end,
+        on_focus: on_focus.map(|cb| Callback::new(move |_| cb.run(()))),
+        on_blur: on_blur.map(|cb| Callback::new(move |_| cb.run(()))),
+        ..SliderConfig::new(model)
+    });
+    let model = slider.model;
+
+    // Writes the slider's own changes back to the bound signals.
+    Effect::new(move |_| {
+        let next = model.with(|m| m.values().to_vec());
+        if bound_values.with_untracked(|bound| *bound == next) {
+            return;
+        }
+        if multi {
+            values.set(next);
+        } else if double {
+            range.set((next[0], next[next.len() - 1]));
+        } else {
+            value.set(next[0]);
+        }
+    });
+
+    let ids = slider.ids();
+    let list_id = ids.list();
+    let describedby = slider.aria_describedby();
+
+    // Keeps keyboard focus on the moved thumb when `RangeBehavior::Swap` reorders the thumbs.
+    let follow_thumb = move |thumb: usize, moved: Option<usize>| {
+        if let Some(moved) = moved.filter(|&moved| moved != thumb) {
+            if let Some(input) = input_ref(moved).get_untracked() {
+                let _ = input.focus();
+            }
+        }
+    };
+
+    let on_input = move |thumb: usize| {
+        Callback::new(move |v: f64| {
+            follow_thumb(thumb, slider.set_value(thumb, v, ChangeSource::Pointer));
+        })
+    };
+
+    let on_key = move |thumb: usize| {
+        Callback::new(move |action: KeyAction| {
+            follow_thumb(thumb, slider.apply_key(thumb, action));
+        })
+    };
+
+    let wheel = wheel && !disabled;
+    let on_wheel = move |thumb: usize| {
+        wheel.then(|| {
+            Callback::new(move |delta_y: f64| {
+                follow_thumb(thumb, slider.apply_wheel(thumb, delta_y));
+            })
+        })
+    };
+
+    let commit = Callback::new(move |_: ()| slider.commit());
+    let drag_end = Callback::new(move |_: ()| slider.drag_end());
+    let on_focus_cb =
+        move |thumb: usize| Callback::new(move |_: ev::FocusEvent| slider.focus(thumb));
+    let on_blur_cb = move |thumb: usize| Callback::new(move |_: ev::FocusEvent| slider.blur(thumb));
+
+    let fill_color = {
+        let color = color.clone();
+        let zones = zones.clone();
+        Signal::derive(move || {
+            if !color_by_zone {
+                return color.clone();
+            }
+            let last = model.with(|m| m.values()[m.values().len() - 1]);
+            Zone::at(&zones, last).map_or_else(|| color.clone(), |zone| zone.color.clone())
+        })
+    };
+    let zoned = (!zones.is_empty()).then(|| {
+        let zones = zones.clone();
+        let outside_color = outside_color.clone();
+        let orientation = orientation.clone();
+        Signal::derive(move || {
+            model.with(|m| {
+                zoned_background(
+                    &fill_color.get(),
+                    outside_color.as_ref(),
+                    &zones,
+                    m,
+                    &orientation,
+                    rtl_fill,
+                )
+            })
+        })
+    });
+
+    let input = {
+        let orientation = orientation.clone();
+        let color = color.clone();
+        let outside_color = outside_color.clone();
+        let list_id = list_id.clone();
+        let ids = ids.clone();
+        let describedby = describedby.clone();
+        let size = size.clone();
+        let slider_width = slider_width.clone();
+        let slider_height = slider_height.clone();
+        let custom_thumb_html = custom_thumb_html.clone();
+        move |thumb: usize, background: Option<Signal<String>>| {
+            view! {
+                <Input
+                    input_ref=input_ref(thumb)
+                    model=model
+                    thumb=thumb
+                    rtl_fill=rtl_fill
+                    background=background
+                    orientation=orientation.clone()
+                    disabled=disabled
+                    size=size.clone()
+                    color=color.clone()
+                    outside_color=outside_color.clone()
+                    input_class=input_class.clone()
+                    on_input=on_input(thumb)
+                    on_key=on_key(thumb)
+                    on_wheel=on_wheel(thumb)
+                    on_commit=commit
+                    on_drag_start=Callback::new(move |_| slider.drag_start(thumb))
+                    on_drag_end=drag_end
+                    on_focus=on_focus_cb(thumb)
+                    on_blur=on_blur_cb(thumb)
+                    id=ids.input(thumb)
+                    datalist_id=Some(list_id.clone())
+                    aria_label=slider.aria_label(thumb)
+                    aria_describedby=describedby.clone()
+                    aria_valuetext=Signal::derive(move || {
+                        model.with(|m| m.thumb_value(thumb)).map(|v| slider.value_text(v))
+                    })
+                    width=slider_width.clone()
+                    height=slider_height.clone()
+                    input_style=input_style.clone()
+                    custom_thumb_html=custom_thumb_html.clone()
+                />
+            }
+        }
+    };
+
+    let group_style = if orientation.is_vertical() {
+        "display: flex; flex-direction: row; align-items: flex-start;"
+    } else if double || multi {
+        "position: relative; width: 100%; display: flex; align-items: center;"
+    } else {
+        "display: flex; align-items: center; width: 100%;"
+    };
+
+    let step_labels = model.with_untracked(|m| {
+        m.step_values()
+            .into_iter()
+            .map(|v| slider.value_text(v))
+            .collect::<Vec<_>>()
+    });
+    let tick_values = model.with_untracked(|m| {
+        if marks.is_empty() {
+            m.tick_values()
+        } else {
+            marks.iter().map(|mark| m.to_input(mark.value)).collect()
+        }
+    });
+    let context_theme = use_context::<Signal<Theme>>();
+    let theme = Signal::derive(move || {
+        theme
+            .get()
+            .or_else(|| context_theme.map(|theme| theme.get()))
+    });
+    // Native thumbs stop half a thumb short of the track ends; custom thumbs are centred.
+    let native_thumb_size = Signal::derive(move || {
+        if renderer == Renderer::Native {
+            theme.with(|theme| thumb_size(theme.as_ref()).to_string())
+        } else {
+            "0px".to_string()
+        }
+    });
+    let zone_labels = zones.iter().any(|zone| zone.label.is_some()).then(|| {
+        view! { <ZoneLabels model=model zones=zones.clone() orientation=orientation.clone() /> }
+    });
+    let marks_view = match (zone_labels, marks.is_empty()) {
+        (None, true) => None,
+        (None, false) => Some(
+            view! {
+                <Marks
+                    model=model
+                    marks=marks
+                    orientation=orientation.clone()
+                    thumb_size=native_thumb_size
+                />
+            }
+            .into_any(),
+        ),
+        (zone_labels, marks_empty) => Some(
+            view! {
+                {zone_labels}
+                {(!marks_empty)
+                    .then(|| {
+                        view! {
+                            <Marks
+                                model=model
+                                marks=marks
+                                orientation=orientation.clone()
+                                thumb_size=native_thumb_size
+                            />
+                        }
+                    })}
+            }
+            .into_any(),
+        ),
+    };
+    let (vertical_marks, horizontal_marks) = if orientation.is_vertical() {
+        (marks_view, None)
+    } else {
+        (None, marks_view)
+    };
+
+    // Range and multi-thumb sliders stack one input per thumb on a shared track: the bottom
+    // input paints the fill between the outer thumbs and the inputs above it stay transparent.
+    let inputs = if renderer == Renderer::Custom {
+        let track = slider.track_props();
+        let range_style = {
+            let orientation = orientation.clone();
+            move || {
+                let (start, end) = model.with(SliderModel::fill_percent);
+                custom_range_style(&orientation, &fill_color.get(), start, end)
+            }
+        };
+        let custom_thumb_html = custom_thumb_html.clone();
+        let thumb = move |thumb: usize| {
+            let thumb = slider.thumb_props(thumb);
+            view! {
+                <div
+                    id=thumb.id
+                    class=thumb.class
+                    style=thumb.style
+                    role=thumb.role
+                    tabindex=thumb.tabindex
+                    on:keydown=thumb.on_keydown
+                    on:keyup=thumb.on_keyup
+                    on:wheel=thumb.on_wheel
+                    on:focus=thumb.on_focus
+                    on:blur=thumb.on_blur
+                    aria-valuemin=thumb.aria_valuemin
+                    aria-valuemax=thumb.aria_valuemax
+                    aria-valuenow=thumb.aria_valuenow
+                    aria-valuetext=thumb.aria_valuetext
+                    aria-orientation=thumb.aria_orientation
+                    aria-disabled=thumb.aria_disabled
+                    aria-label=thumb.aria_label
+                    aria-labelledby=thumb.aria_labelledby
+                    aria-describedby=thumb.aria_describedby
+                >
+                    {custom_thumb_html.clone().map(|html| html.run())}
+                </div>
+            }
+        };
+        let mut track_style =
+            custom_track_style(&orientation, &slider_width, &slider_height, &size, disabled);
+        if let Some(outside) = &outside_color {
+            track_style.push_str(&format!(" background: {};", outside.to_color_code()));
+        }
+        if !zones.is_empty() {
+            track_style.push(' ');
+            track_style.push_str(&model.with_untracked(|m| {
+                zones_track_background(outside_color.as_ref(), &zones, m, &orientation)
+            }));
+        }
+        view! {
+            <div
+                class=track.class
+                style=track_style
+                on:pointerdown=track.on_pointerdown
+                on:pointermove=track.on_pointermove
+                on:pointerup=track.on_pointerup
+                on:pointercancel=track.on_pointercancel
+            >
+                <div class=RANGE_CLASS style=range_style></div>
+                <For
+                    each=move || 0..model.with(|m| m.values().len())
+                    key=|thumb| *thumb
+                    children=thumb
+                />
+            </div>
+        }
+        .into_any()
+    } else if multi || double {
+        let fill = zoned.unwrap_or_else(|| {
+            let outside_color = outside_color.clone();
+            let orientation = orientation.clone();
+            Signal::derive(move || {
+                let (start, end) = model.with(SliderModel::fill_percent);
+                range_background(
+                    &fill_color.get(),
+                    outside_color.as_ref(),
+                    &orientation,
+                    rtl_fill,
+                    true,
+                    start,
+                    end,
+                )
+            })
+        });
+        let transparent = Signal::stored("background: transparent;".to_string());
+        let stack_style = if orientation.is_vertical() {
+            "display: grid; height: 100%;"
+        } else {
+            "display: grid; width: 100%;"
+        };
+        view! {
+            <div class=STACKED_THUMBS_CLASS style=stack_style>
+                <style>{STACKED_THUMBS_CSS}</style>
+                <For
+                    each=move || 0..model.with(|m| m.values().len())
+                    key=|thumb| *thumb
+                    children=move |thumb| {
+                        let background = if thumb == 0 { fill } else { transparent };
+                        input(thumb, Some(background))
+                    }
+                />
+            </div>
+        }
+        .into_any()
+    } else {
+        input(0, zoned).into_any()
+    };
+
+    let histogram_view = (!histogram.is_empty()).then(|| {
+        view! {
+            <Histogram
+                model=model
+                counts=histogram
+                orientation=orientation.clone()
+                color=fill_color
+                rtl_fill=rtl_fill
+                thumb_size=native_thumb_size
+            />
+        }
+    });
+    // The bars share a wrapper with the track, so they span the track alone.
+    let inputs = match histogram_view {
+        Some(histogram) => view! {
+            <div class="slider-histogram-track" style=histogram_track_style(&orientation)>
+                {histogram}
+                {inputs}
+            </div>
+        }
+        .into_any(),
+        None => inputs,
+    };
+
+    let input_group = view! {
+        <div style=group_style>
+            {icon_start.map(|icon| icon.run())}
+            {inputs}
+            {icon_end.map(|icon| icon.run())}
+            {vertical_marks}
+            {(show_steps && orientation.is_vertical())
+                .then(|| {
+                    view! {
+                        <Ticks id=list_id.clone() values=tick_values.clone() />
+                        <Steps
+                            labels=step_labels.clone()
+                            steps_style=steps_style.clone()
+                            orientation=orientation.clone()
+                        />
+                    }
+                })}
+        </div>
+    };
+
+    let value_display = show_value.then(|| {
+        view! {
+            <Output
+                id=ids.output()
+                for_ids=ids.inputs(model.with_untracked(|m| m.values().len()))
+                value_display=Signal::derive(move || slider.output_text())
+                output_class=output_class
+                output_style=output_style
+                tooltip_style=tooltip_style
+                show_tooltip=show_tooltip
+                tooltip_left=Signal::derive(move || {
+                    model.with(|m| format!("{:.2}%", m.percent(m.value())))
+                })
+            />
+        }
+    });
+
+    let horizontal_steps = (show_steps && !orientation.is_vertical()).then(|| {
+        view! {
+            <Steps
+                labels=step_labels.clone()
+                steps_style=steps_style
+                orientation=orientation.clone()
+            />
+        }
+    });
+
+    let container = Memo::new(move |_| {
+        theme.with(|theme| themed_container(theme.as_ref(), &container_class, &container_style))
+    });
+    let thumb_style = if color_by_zone {
+        let root = ids.root().to_string();
+        (move || {
+            thumb_css(
+                &root,
+                &cursor_style,
+                &fill_color.get(),
+                disabled,
+                custom_thumb_css.as_deref(),
+            )
+        })
+        .into_any()
+    } else {
+        thumb_css(
+            ids.root(),
+            &cursor_style,
+            &color,
+            disabled,
+            custom_thumb_css.as_deref(),
+        )
+        .into_any()
+    };
+
+    view! {
+        <div
+            id=ids.root().to_string()
+            class=move || container.with(|(class, _)| class.clone())
+            style=move || container.with(|(_, style)| style.clone())
+            role="group"
+            aria-labelledby=(!label.is_empty()).then(|| ids.label())
+            aria-orientation=orientation.to_aria()
+            aria-disabled=disabled.to_string()
+        >
+            <style>
+                {thumb_style}
+            </style>
+            <Show when=move || theme.with(Option::is_some)>
+                <style>{THEME_CSS}</style>
+            </Show>
+            <Label
+                id=ids.label()
+                for_id=(renderer == Renderer::Native).then(|| ids.input(0))
+                label=label.clone()
+                label_class=label_class
+                label_style=label_style
+            />
+            {description
+                .map(|description| {
+                    view! {
+                        <p
+                            id=ids.description()
+                            class="slider-description"
+                            style="font-size: 12px; margin: 0 0 8px;"
+                        >
+                            {description}
+                        </p>
+                    }
+                })}
+            {input_group}
+            {horizontal_marks}
+            <Ticks id=list_id.clone() values=tick_values.clone() />
+            {value_display}
+            {horizontal_steps}
+        </div>
+    }
+}
+
+/// ThemeProvider Component
+///
+/// Shares a [`Theme`] with every [`Slider`] below it; when `theme` is a signal, updating it
+/// restyles them all.
+///
+/// # Examples
+/// ```rust
+/// use leptos::prelude::*;
+/// use slider_rs::leptos::{Slider, ThemeProvider};
+/// use slider_rs::common::Theme;
+///
+/// #[component]
+/// pub fn App() -> impl IntoView {
+///     let dark = RwSignal::new(false);
+///     let theme = Signal::derive(move || if dark.get() { Theme::dark() } else { Theme::light() });
+///
+///     view! {
+///         <ThemeProvider theme=theme>
+///             <button on:click=move |_| dark.update(|dark| *dark = !*dark)>"Toggle theme"</button>
+///             <Slider label="Volume" />
+///             <Slider label="Balance" min=-1.0 max=1.0 step=0.1 />
+///         </ThemeProvider>
+///     }
+/// }
+/// ```
+#[component]
+pub fn ThemeProvider(
+    /// Theme used by every slider inside, unless a slider sets its own `theme`.
+    #[prop(into)]
+    theme: Signal<Theme>,
+    children: Children,
+) -> impl IntoView {
+    provide_context(theme);
+    children()
+}
+
+/// OptionSlider Component
+///
+/// A [`Slider`] over a list of options: the thumb snaps to each option and the step labels,
+/// the output, the tooltip and `aria-valuetext` show the option instead of its index.
+///
+/// # Examples
+/// ```rust
+/// use leptos::prelude::*;
+/// use slider_rs::leptos::OptionSlider;
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     let size = RwSignal::new(2);
+///
+///     view! {
+///         <OptionSlider
+///             label="Size"
+///             options=vec!["XS", "S", "M", "L", "XL"]
+///             index=size
+///             on_change=Callback::new(|size: &'static str| leptos::logging::log!("Size: {}", size))
+///         />
+///     }
+/// }
+/// ```
+#[component]
+pub fn OptionSlider<T>(
+    /// Id of the slider container, see [`Slider`].
+    #[prop(optional, into)]
+    id: Option<String>,
+
+    /// Label text displayed above the slider.
+    #[prop(into, default = "".to_string())]
+    label: String,
+
+    /// The options to pick from, in track order.
+    options: Vec<T>,
+
+    /// Index of the selected option, kept in sync in both directions.
+    #[prop(optional, into)]
+    index: Option<RwSignal<usize>>,
+
+    /// Initial index when no `index` signal is bound. Defaults to the first option.
+    #[prop(optional, into)]
+    default_index: Option<usize>,
+
+    /// Text for an option, used instead of its `Display` output.
+    #[prop(optional, into)]
+    format: Option<Callback<T, String>>,
+
+    /// Callback triggered with the selected option.
+    #[prop(optional, into)]
+    on_change: Option<Callback<T>>,
+
+    /// Callback triggered with the index of the selected option.
+    #[prop(optional, into)]
+    on_change_index: Option<Callback<usize>>,
+
+    /// Slider orientation: horizontal or vertical.
+    #[prop(optional)]
+    orientation: Orientation,
+
+    /// Size variant for styling the slider track and thumb.
+    #[prop(optional)]
+    size: Size,
+
+    /// Color variant for styling the slider.
+    #[prop(optional)]
+    color: Color,
+
+    /// Thumb shape: `Round`, `Square` or `Custom` CSS declarations.
+    #[prop(optional)]
+    cursor_style: Cursor,
+
+    /// Whether to show the selected option as an output.
+    #[prop(default = false)]
+    show_value: bool,
+
+    /// Whether to show the option labels along the slider track.
+    #[prop(default = true)]
+    show_steps: bool,
+
+    /// Whether to show tooltip above the thumb on hover.
+    #[prop(default = false)]
+    show_tooltip: bool,
+
+    /// Whether to disable interaction with the slider.
+    #[prop(default = false)]
+    disabled: bool,
+
+    /// ARIA label for accessibility.
+    #[prop(optional, into)]
+    aria_label: Option<String>,
+
+    /// ARIA describedby attribute for accessibility.
+    #[prop(optional, into)]
+    aria_describedby: Option<String>,
+
+    /// Custom width for the slider track.
+    #[prop(optional)]
+    slider_width: Width,
+
+    /// Custom height for the slider track.
+    #[prop(optional)]
+    slider_height: Height,
+
+    /// CSS class for the container wrapping the slider.
+    #[prop(into, default = "slider-container".to_string())]
+    container_class: String,
+
+    /// Inline style for the container wrapping the slider.
+    #[prop(
+        into, default = "display: flex; flex-direction: column; align-items: center; margin: 20px; position: relative;".to_string()
+    )]
+    container_style: String,
+
+    /// Colours, radii and sizes emitted as `--slider-*` CSS variables on the container.
+    ///
+    /// Falls back to the theme of the nearest [`ThemeProvider`].
+    #[prop(optional, into)]
+    theme: MaybeProp<Theme>,
+) -> impl IntoView
+where
+    T: Clone + PartialEq + Display + Send + Sync + 'static,
+{
+    let labels = options
+        .iter()
+        .map(|option| match format {
+            Some(format) => format.run(option.clone()),
+            None => option.to_string(),
+        })
+        .collect::<Vec<_>>();
+    let max = options.len().saturating_sub(1) as f64;
+
+    let index = index.unwrap_or_else(|| RwSignal::new(default_index.unwrap_or(0)));
+    let value = RwSignal::new(index.get_untracked() as f64);
+    Effect::new(move |_| value.set(index.get() as f64));
+
+    let options = StoredValue::new(options);
+    let on_value = Callback::new(move |value: f64| {
+        let selected = value.round() as usize;
+        if let Some(option) = options.with_value(|options| options.get(selected).cloned()) {
+            index.set(selected);
+            if let Some(cb) = on_change_index {
+                cb.run(selected);
+            }
+            if let Some(cb) = on_change {
+                cb.run(option);
+            }
+        }
+    });
+
+    view! {
+        <Slider
+            id=id.unwrap_or_default()
+            label=label
+            min=0.0
+            max=max
+            step=1.0
+            value=value
+            labels=labels
+            on_change=on_value
+            orientation=orientation
+            size=size
+            color=color
+            cursor_style=cursor_style
+            show_value=show_value
+            show_steps=show_steps
+            show_tooltip=show_tooltip
+            disabled=disabled
+            aria_label=aria_label.unwrap_or_default()
+            aria_describedby=aria_describedby.unwrap_or_default()
+            slider_width=slider_width
+            slider_height=slider_height
+            container_class=container_class
+            container_style=container_style
+            theme=theme
+        />
+    }
+}
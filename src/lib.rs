@@ -16,4 +16,7 @@ pub mod dioxus;
 #[cfg(feature = "lep")]
 pub mod leptos;
 
-pub use common::{Color, Cursor, Height, Orientation, Size, Width};
+pub use common::{
+    ChangeSource, Color, Cursor, Height, Mark, Orientation, RangeBehavior, Renderer, Scale, Size,
+    SliderChange, SliderModel, Theme, ValueFormat, Width,
+};
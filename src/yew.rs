@@ -1,48 +1,72 @@
 #![doc = include_str!("../YEW.md")]
 
-use crate::common::{Color, Cursor, Height, Orientation, Size, Width};
-use uuid::Uuid;
-use web_sys::{FocusEvent, HtmlInputElement, InputEvent, KeyboardEvent};
+use crate::common::{
+    ChangePacer, ChangeSource, Color, Cursor, HISTOGRAM_VIEW_BOX, Height, KeyAction, Mark,
+    Orientation, RANGE_CLASS, RANGE_DRAG_CLASS, RANGE_DRAG_CSS, RangeBehavior, RateLimit, Renderer,
+    STACKED_THUMBS_CLASS, STACKED_THUMBS_CSS, Scale, Size, SliderChange, SliderIds, SliderModel,
+    THEME_CSS, THUMB_CLASS, TRACK_CLASS, TRACK_LAYOUT, Theme, ValueFormat, Width, Zone,
+    custom_range_style, custom_thumb_style, custom_track_style, focus_thumb, histogram_bars,
+    histogram_style, histogram_track_style, is_rtl, mark_style, mark_tick_style,
+    marks_container_style, pointer_on_range, pointer_on_track, range_background, range_drag_style,
+    range_layout, themed_container, thumb_css, thumb_size, track_background, value_text,
+    zone_label_style, zoned_background, zones_track_background,
+};
+use std::cell::{Cell, RefCell};
+use std::fmt::Display;
+use std::rc::Rc;
+use web_sys::wasm_bindgen::JsCast;
+use web_sys::{
+    FocusEvent, HtmlElement, HtmlInputElement, InputEvent, KeyboardEvent, PointerEvent, WheelEvent,
+};
 use yew::prelude::*;
 
 #[derive(Properties, PartialEq)]
 pub struct LabelProps {
     #[prop_or_default]
-    pub label: &'static str,
-    #[prop_or("font-size: 14px; margin-bottom: 8px; text-align: center;")]
-    pub label_style: &'static str,
-    #[prop_or("slider-label")]
-    pub label_class: &'static str,
+    pub id: Option<String>,
+    /// Id of the input the label names; custom thumbs point back with `aria-labelledby`.
+    #[prop_or_default]
+    pub for_id: Option<String>,
+    #[prop_or_default]
+    pub label: AttrValue,
+    #[prop_or(AttrValue::Static("font-size: 14px; margin-bottom: 8px; text-align: center;"))]
+    pub label_style: AttrValue,
+    #[prop_or(AttrValue::Static("slider-label"))]
+    pub label_class: AttrValue,
 }
 
 #[function_component(Label)]
 fn slider_label(props: &LabelProps) -> Html {
-    html! { <label class={props.label_class} style={props.label_style}>{ props.label }</label> }
+    html! {
+        <label
+            id={props.id.clone()}
+            for={props.for_id.clone()}
+            class={props.label_class.clone()}
+            style={props.label_style.clone()}
+        >
+            { props.label.clone() }
+        </label>
+    }
 }
 
 #[derive(Properties, PartialEq)]
 pub struct StepsProps {
-    #[prop_or(0.0)]
-    pub min: f64,
-    #[prop_or(10.0)]
-    pub max: f64,
-    #[prop_or(1.0)]
-    pub step: f64,
-    #[prop_or(
+    #[prop_or_default]
+    pub labels: Vec<String>,
+    #[prop_or(AttrValue::Static(
         "width: 100%; display: flex; justify-content: space-between; margin-top: 8px; font-size: 10px;"
-    )]
-    pub steps_style: &'static str,
+    ))]
+    pub steps_style: AttrValue,
     #[prop_or_default]
     pub orientation: Orientation,
 }
 
 #[function_component(Steps)]
 fn slider_steps(props: &StepsProps) -> Html {
-    let count = ((props.max - props.min) / props.step).floor() as usize;
-
-    let steps = (0..=count)
-        .map(|i| {
-            let val = props.min + (i as f64 * props.step);
+    let steps = props
+        .labels
+        .iter()
+        .map(|label| {
             html! {
                 <span
                     style={if props.orientation.is_vertical() {
@@ -51,16 +75,18 @@ fn slider_steps(props: &StepsProps) -> Html {
                     "text-align: center;"
                 }}
                 >
-                    { format!("{:.0}", val) }
+                    { label }
                 </span>
             }
         })
         .collect::<Html>();
 
     let style = if props.orientation.is_vertical() {
-        "display: flex; flex-direction: column; align-items: center; height: 100%; font-size: 10px;"
+        AttrValue::Static(
+            "display: flex; flex-direction: column; align-items: center; height: 100%; font-size: 10px;",
+        )
     } else {
-        props.steps_style
+        props.steps_style.clone()
     };
 
     html! { <div style={style}>{ steps }</div> }
@@ -68,16 +94,21 @@ fn slider_steps(props: &StepsProps) -> Html {
 
 #[derive(Properties, PartialEq)]
 pub struct OutputProps {
+    #[prop_or_default]
+    pub id: Option<String>,
+    /// Ids of the inputs the output shows the value of.
+    #[prop_or_default]
+    pub for_ids: Option<String>,
     #[prop_or_default]
     pub value_display: String,
-    #[prop_or("font-size: 12px; margin-top: 8px; text-align: center;")]
-    pub output_style: &'static str,
-    #[prop_or("slider-output")]
-    pub output_class: &'static str,
-    #[prop_or(
-        "background-color: #333; color: #fff; padding: 4px 8px; border-radius: 4px; font-size: 12px; position: absolute; transform: translate(-50%, -120%); display: block; pointer-events: none;"
-    )]
-    pub tooltip_style: &'static str,
+    #[prop_or(AttrValue::Static("font-size: 12px; margin-top: 8px; text-align: center;"))]
+    pub output_style: AttrValue,
+    #[prop_or(AttrValue::Static("slider-output"))]
+    pub output_class: AttrValue,
+    #[prop_or(AttrValue::Static(
+        "background-color: var(--slider-tooltip-bg, #333); color: var(--slider-tooltip-text, #fff); padding: 4px 8px; border-radius: 4px; font-size: 12px; position: absolute; transform: translate(-50%, -120%); display: block; pointer-events: none;"
+    ))]
+    pub tooltip_style: AttrValue,
     #[prop_or(false)]
     pub show_tooltip: bool,
     #[prop_or_default]
@@ -88,14 +119,20 @@ pub struct OutputProps {
 fn slider_output(props: &OutputProps) -> Html {
     html! {
         <>
-            <output class={props.output_class} style={props.output_style} aria-live="polite">
+            <output
+                id={props.id.clone()}
+                for={props.for_ids.clone()}
+                class={props.output_class.clone()}
+                style={props.output_style.clone()}
+                aria-live="polite"
+            >
                 { &props.value_display }
             </output>
             { if props.show_tooltip {
                     let mut style = props.tooltip_style.to_string();
                     style.push_str(&format!(" left: {}; ", props.tooltip_left));
                     html! {
-                        <div class={props.output_class} style={style}>
+                        <div class={props.output_class.clone()} style={style}>
                             { &props.value_display }
                         </div>
                     }
@@ -110,39 +147,132 @@ fn slider_output(props: &OutputProps) -> Html {
 pub struct TicksProps {
     #[prop_or_default]
     pub id: String,
-    #[prop_or(0.0)]
-    pub min: f64,
-    #[prop_or(10.0)]
-    pub max: f64,
-    #[prop_or(1.0)]
-    pub step: f64,
+    #[prop_or_default]
+    pub values: Vec<f64>,
 }
 
 #[function_component(Ticks)]
 fn slider_ticks(props: &TicksProps) -> Html {
-    let mut current = props.min;
-    let mut children = vec![];
+    html! {
+        <datalist id={props.id.clone()}>
+            { for props.values.iter().map(|v| html! { <option value={v.to_string()} /> }) }
+        </datalist>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct MarksProps {
+    pub model: SliderModel,
+    #[prop_or_default]
+    pub marks: Vec<Mark>,
+    #[prop_or_default]
+    pub orientation: Orientation,
+    /// Thumb size the marks are inset by, see [`mark_style`].
+    #[prop_or(AttrValue::Static("0px"))]
+    pub thumb_size: AttrValue,
+}
+
+#[function_component(Marks)]
+fn slider_marks(props: &MarksProps) -> Html {
+    html! {
+        <div class="slider-marks" style={marks_container_style(&props.orientation)}>
+            { for props.marks.iter().map(|mark| html! {
+                <span
+                    class={mark.class_name()}
+                    style={mark_style(
+                        props.model.percent(mark.value),
+                        &props.orientation,
+                        &props.thumb_size,
+                    )}
+                >
+                    <span class="slider-mark-tick" style={mark_tick_style(&props.orientation)} />
+                    { mark.label.clone().unwrap_or_default() }
+                </span>
+            }) }
+        </div>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct ZoneLabelsProps {
+    pub model: SliderModel,
+    #[prop_or_default]
+    pub zones: Vec<Zone>,
+    #[prop_or_default]
+    pub orientation: Orientation,
+}
 
-    while current <= props.max {
-        children.push(html! { <option value={current.to_string()} /> });
-        current += props.step;
+#[function_component(ZoneLabels)]
+fn slider_zone_labels(props: &ZoneLabelsProps) -> Html {
+    html! {
+        <div class="slider-zones" style={marks_container_style(&props.orientation)}>
+            { for props.zones.iter().filter(|zone| zone.label.is_some()).map(|zone| html! {
+                <span
+                    class="slider-zone-label"
+                    style={zone_label_style(&props.model, zone, &props.orientation)}
+                >
+                    { zone.label.clone().unwrap_or_default() }
+                </span>
+            }) }
+        </div>
     }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct HistogramProps {
+    pub model: SliderModel,
+    #[prop_or_default]
+    pub counts: Vec<f64>,
+    #[prop_or_default]
+    pub orientation: Orientation,
+    #[prop_or_default]
+    pub color: Color,
+    #[prop_or(false)]
+    pub rtl_fill: bool,
+    /// Thumb size the bars are inset by, see [`histogram_style`].
+    #[prop_or(AttrValue::Static("0px"))]
+    pub thumb_size: AttrValue,
+}
 
-    html! { <datalist id={props.id.clone()}>{ for children }</datalist> }
+#[function_component(Histogram)]
+fn slider_histogram(props: &HistogramProps) -> Html {
+    let bars = histogram_bars(
+        &props.counts,
+        &props.model,
+        &props.orientation,
+        props.rtl_fill,
+    );
+    let fill = props.color.to_color_code().to_string();
+    html! {
+        <svg
+            class="slider-histogram"
+            viewBox={HISTOGRAM_VIEW_BOX}
+            preserveAspectRatio="none"
+            aria-hidden="true"
+            style={histogram_style(&props.orientation, &props.thumb_size)}
+        >
+            { for bars.iter().map(|bar| html! {
+                <rect
+                    class="slider-histogram-bar"
+                    x={format!("{:.2}", bar.x)}
+                    y={format!("{:.2}", bar.y)}
+                    width={format!("{:.2}", bar.width)}
+                    height={format!("{:.2}", bar.height)}
+                    fill={fill.clone()}
+                    opacity={bar.opacity()}
+                />
+            }) }
+        </svg>
+    }
 }
 
 #[derive(Properties, PartialEq)]
 pub struct InputProps {
     #[prop_or_default]
     pub input_ref: NodeRef,
-    #[prop_or(0.0)]
-    pub min: f64,
-    #[prop_or(10.0)]
-    pub max: f64,
-    #[prop_or(1.0)]
-    pub step: f64,
-    #[prop_or(0.0)]
-    pub value: f64,
+    pub model: SliderModel,
+    #[prop_or(0)]
+    pub thumb: usize,
     #[prop_or_default]
     pub orientation: Orientation,
     #[prop_or_default]
@@ -154,276 +284,1387 @@ pub struct InputProps {
     #[prop_or_default]
     pub color: Color,
     #[prop_or_default]
-    pub cursor_style: Cursor,
+    pub outside_color: Option<Color>,
     #[prop_or(false)]
     pub disabled: bool,
     #[prop_or_default]
-    pub on_input: Callback<InputEvent>,
+    pub on_input: Callback<f64>,
+    #[prop_or_default]
+    pub on_key: Callback<KeyAction>,
+    /// Receives the vertical wheel delta; without it the wheel scrolls the page.
+    #[prop_or_default]
+    pub on_wheel: Option<Callback<f64>>,
+    /// Fires when a key is released or the browser reports the final value.
+    #[prop_or_default]
+    pub on_commit: Callback<()>,
+    #[prop_or_default]
+    pub on_drag_start: Callback<()>,
+    #[prop_or_default]
+    pub on_drag_end: Callback<()>,
     #[prop_or_default]
     pub on_focus: Callback<FocusEvent>,
     #[prop_or_default]
     pub on_blur: Callback<FocusEvent>,
     #[prop_or_default]
-    pub aria_label: Option<&'static str>,
+    pub id: Option<String>,
+    #[prop_or_default]
+    pub aria_label: Option<String>,
+    #[prop_or_default]
+    pub aria_describedby: Option<String>,
     #[prop_or_default]
-    pub aria_describedby: Option<&'static str>,
+    pub aria_valuetext: Option<String>,
     #[prop_or_default]
     pub datalist_id: Option<String>,
-    #[prop_or("slider-input")]
-    pub input_class: &'static str,
-    #[prop_or("border-radius: 8px; appearance: none; outline: none;")]
-    pub input_style: &'static str,
+    #[prop_or(AttrValue::Static("slider-input"))]
+    pub input_class: AttrValue,
+    #[prop_or(AttrValue::Static(
+        "border-radius: var(--slider-track-radius, 8px); appearance: none; outline: none;"
+    ))]
+    pub input_style: AttrValue,
     #[prop_or(true)]
     pub use_gradient: bool,
     #[prop_or_default]
-    pub custom_thumb_css: Option<&'static str>,
-    #[prop_or_default]
     pub custom_thumb_html: Option<Html>,
-    #[prop_or(1.0)]
-    pub keyboard_step: f64,
     #[prop_or(false)]
     pub rtl_fill: bool,
+    /// Overrides the computed track `background` declaration.
+    #[prop_or_default]
+    pub background: Option<String>,
 }
 
 #[function_component(Input)]
 fn slider_input(props: &InputProps) -> Html {
-    let value_percent = ((props.value - props.min) / (props.max - props.min)) * 100.0;
-    let fill_color = props.color.to_color_code();
-    let gradient = if props.use_gradient {
-        if props.orientation.is_vertical() {
-            if props.rtl_fill {
-                format!(
-                    "background: linear-gradient(to top, {} 0%, {} {:.2}%, #ccc {:.2}%, #ccc 100%);",
-                    fill_color, fill_color, value_percent, value_percent
-                )
-            } else {
-                format!(
-                    "background: linear-gradient(to bottom, {} 0%, {} {:.2}%, #ccc {:.2}%, #ccc 100%);",
-                    fill_color, fill_color, value_percent, value_percent
-                )
-            }
-        } else if props.rtl_fill {
-            format!(
-                "background: linear-gradient(to left, {} 0%, {} {:.2}%, #ccc {:.2}%, #ccc 100%);",
-                fill_color, fill_color, value_percent, value_percent
-            )
-        } else {
-            format!(
-                "background: linear-gradient(to right, {} 0%, {} {:.2}%, #ccc {:.2}%, #ccc 100%);",
-                fill_color, fill_color, value_percent, value_percent
-            )
-        }
-    } else {
-        format!("background: {};", fill_color)
-    };
+    let model = &props.model;
+    let value = model.thumb_value(props.thumb).unwrap_or(model.min());
 
     let base_style = format!(
         "cursor: pointer; transition: background 0.3s; {} {} {} {} {} {}",
         props.input_style,
         props.width.to_style(),
         props.height.to_style(),
-        gradient,
+        props.background.clone().unwrap_or_else(|| {
+            if model.fill_origin().is_some() {
+                let (start, end) = model.fill_percent();
+                range_background(
+                    &props.color,
+                    props.outside_color.as_ref(),
+                    &props.orientation,
+                    props.rtl_fill,
+                    props.use_gradient,
+                    start,
+                    end,
+                )
+            } else {
+                track_background(
+                    &props.color,
+                    props.outside_color.as_ref(),
+                    &props.orientation,
+                    props.rtl_fill,
+                    props.use_gradient,
+                    model.percent(value),
+                )
+            }
+        }),
         props.orientation.to_style(),
         props.size.to_style(),
     );
 
-    let thumb_style = props.custom_thumb_css.unwrap_or("");
+    let (input_min, input_max) = model.input_bounds();
+    let input_value = model.to_input(value);
 
-    let on_key_down = {
+    let on_input = {
         let on_input = props.on_input.clone();
-        let input_ref = props.input_ref.clone();
-        let keyboard_step = props.keyboard_step;
-        let min = props.min;
-        let max = props.max;
-        Callback::from(move |e: KeyboardEvent| {
-            if let Some(input) = input_ref.cast::<HtmlInputElement>() {
-                let current = input.value().parse::<f64>().unwrap_or(0.0);
-                let new_val = match e.key().as_str() {
-                    "ArrowLeft" | "ArrowDown" => current - keyboard_step,
-                    "ArrowRight" | "ArrowUp" => current + keyboard_step,
-                    _ => current,
+        let model = model.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
+                if let Ok(v) = input.value().parse::<f64>() {
+                    // Snap the DOM back to the rendered value; the next render moves the thumb
+                    // once the new value is accepted, which keeps controlled sliders in sync.
+                    input.set_value(&input_value.to_string());
+                    on_input.emit(model.from_input(v));
                 }
-                .clamp(min, max);
-                input.set_value(&new_val.to_string());
+            }
+        })
+    };
+
+    let on_key_down = {
+        let on_key = props.on_key.clone();
+        Callback::from(move |e: KeyboardEvent| {
+            let rtl = e.target().is_some_and(|target| is_rtl(&target));
+            if let Some(action) = KeyAction::from_key_event(&e.key(), e.shift_key(), rtl) {
+                e.prevent_default();
+                on_key.emit(action);
+            }
+        })
+    };
 
-                on_input.emit(InputEvent::new("input").unwrap());
+    let on_pointer_down = {
+        let on_drag_start = props.on_drag_start.clone();
+        let disabled = props.disabled;
+        Callback::from(move |e: PointerEvent| {
+            if disabled || e.button() != 0 {
+                return;
             }
+            // Keeps the release on this input when the pointer leaves it mid-drag.
+            if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
+                let _ = input.set_pointer_capture(e.pointer_id());
+            }
+            on_drag_start.emit(());
         })
     };
 
+    let on_pointer_up = props.on_drag_end.reform(|_: PointerEvent| ());
+
+    let on_wheel = props.on_wheel.clone().map(|on_wheel| {
+        Callback::from(move |e: WheelEvent| {
+            e.prevent_default();
+            on_wheel.emit(e.delta_y());
+        })
+    });
+
     html! {
         <>
             <input
                 ref={props.input_ref.clone()}
+                id={props.id.clone()}
                 type="range"
-                class={props.input_class}
-                min={props.min.to_string()}
-                max={props.max.to_string()}
-                step={if props.step == 0.0 { "any".to_string() } else { props.step.to_string() }}
-                value={props.value.to_string()}
+                class={props.input_class.clone()}
+                min={input_min.to_string()}
+                max={input_max.to_string()}
+                step={model.input_step()}
+                value={input_value.to_string()}
                 list={props.datalist_id.clone()}
-                oninput={props.on_input.clone()}
+                oninput={on_input}
+                onchange={props.on_commit.reform(|_: Event| ())}
                 onfocus={props.on_focus.clone()}
                 onblur={props.on_blur.clone()}
                 onkeydown={on_key_down}
-                style={format!("{} {}", base_style, thumb_style)}
+                onkeyup={props.on_commit.reform(|_: KeyboardEvent| ())}
+                onpointerdown={on_pointer_down}
+                onpointerup={on_pointer_up.clone()}
+                onpointercancel={on_pointer_up}
+                onwheel={on_wheel}
+                style={base_style}
                 orient={props.orientation.to_orient()}
                 disabled={props.disabled}
-                aria-valuemin={props.min.to_string()}
-                aria-valuemax={props.max.to_string()}
-                aria-valuenow={props.value.to_string()}
-                aria-orientation={if props.orientation.is_vertical() { "vertical" } else { "horizontal" }}
+                aria-valuemin={model.min().to_string()}
+                aria-valuemax={model.max().to_string()}
+                aria-valuenow={value.to_string()}
+                aria-orientation={props.orientation.to_aria()}
                 aria-disabled={props.disabled.to_string()}
-                aria-label={props.aria_label.unwrap_or_default()}
-                aria-describedby={props.aria_describedby.unwrap_or_default()}
+                aria-label={props.aria_label.clone()}
+                aria-describedby={props.aria_describedby.clone()}
+                aria-valuetext={props.aria_valuetext.clone()}
             />
             { props.custom_thumb_html.clone().unwrap_or(html! {}) }
         </>
     }
 }
 
-/// Props for the `Slider` component.
+/// Configuration of [`use_slider`].
 ///
-/// These props configure the behavior, appearance, and accessibility of a slider input component
-/// supporting both single and double (range) sliders.
+/// Start from a [`SliderModel`] and override the fields you need.
 ///
-/// # Features
-/// - Single or double slider modes.
-/// - Vertical or horizontal orientation.
-/// - Customizable appearance with CSS styles and classes.
-/// - Tooltip, value display, and step indicators.
-/// - Full accessibility support (ARIA attributes, keyboard steps).
-#[derive(Properties, PartialEq, Clone)]
-pub struct Props {
-    /// Label text displayed above the slider.
-    #[prop_or_default]
-    pub label: &'static str,
-
-    /// Minimum value for the slider.
-    #[prop_or(0.0)]
-    pub min: f64,
-
-    /// Maximum value for the slider.
-    #[prop_or(10.0)]
-    pub max: f64,
+/// # Examples
+/// ```rust
+/// use slider_rs::common::SliderModel;
+/// use slider_rs::yew::SliderConfig;
+///
+/// let config = SliderConfig {
+///     wheel: true,
+///     ..SliderConfig::new(SliderModel::new(0.0, 100.0, 5.0).with_value(40.0))
+/// };
+/// assert_eq!(config.model.value(), 40.0);
+/// ```
+#[derive(Clone, PartialEq)]
+pub struct SliderConfig {
+    /// Bounds, steps and thumb rules; its values are the initial values of an uncontrolled
+    /// slider.
+    pub model: SliderModel,
+    /// Values of a controlled slider, one per thumb.
+    pub values: Option<Vec<f64>>,
+    /// Root of the element ids, see [`SliderIds`]; generated when unset.
+    pub id: Option<AttrValue>,
+    pub orientation: Orientation,
+    pub disabled: bool,
+    /// Lets the mouse wheel move a focused thumb.
+    pub wheel: bool,
+    /// Paces `on_change` and `on_values`.
+    pub rate_limit: RateLimit,
+    /// Text of the values in `aria-valuetext` and the output.
+    pub value_format: ValueFormat,
+    /// Text for each of [`SliderModel::step_values`], replacing the formatted value.
+    pub labels: Vec<String>,
+    /// Accessible name of each thumb, falling back to `aria_label`.
+    pub thumb_labels: Vec<AttrValue>,
+    pub aria_label: Option<AttrValue>,
+    /// Whether an element with the id [`SliderIds::label`] names thumbs without an
+    /// `aria_label`.
+    pub labelled: bool,
+    /// Id of an element describing the slider.
+    pub aria_describedby: Option<AttrValue>,
+    /// Whether an element with the id [`SliderIds::description`] describes the slider when
+    /// `aria_describedby` is unset.
+    pub described: bool,
+    /// Every live change.
+    pub on_change: Callback<SliderChange>,
+    /// All values after a live change, sent right before `on_change`.
+    pub on_values: Callback<Vec<f64>>,
+    /// The last change of an interaction once it ends, marked as committed.
+    pub on_commit: Callback<SliderChange>,
+    pub on_drag_start: Callback<usize>,
+    pub on_drag_end: Callback<usize>,
+    pub on_focus: Callback<usize>,
+    pub on_blur: Callback<usize>,
+}
 
-    /// Increment step size.
-    #[prop_or(1.0)]
-    pub step: f64,
+impl SliderConfig {
+    pub fn new(model: SliderModel) -> Self {
+        Self {
+            model,
+            values: None,
+            id: None,
+            orientation: Orientation::default(),
+            disabled: false,
+            wheel: false,
+            rate_limit: RateLimit::default(),
+            value_format: ValueFormat::default(),
+            labels: Vec::new(),
+            thumb_labels: Vec::new(),
+            aria_label: None,
+            labelled: false,
+            aria_describedby: None,
+            described: false,
+            on_change: Callback::default(),
+            on_values: Callback::default(),
+            on_commit: Callback::default(),
+            on_drag_start: Callback::default(),
+            on_drag_end: Callback::default(),
+            on_focus: Callback::default(),
+            on_blur: Callback::default(),
+        }
+    }
+}
 
-    /// Current value for a single slider mode.
-    #[prop_or_default]
-    pub value: Option<f64>,
+/// Attributes and handlers of the track, which moves the closest thumb to a pressed point and
+/// drags it until the pointer is released.
+pub struct TrackAttributes {
+    pub class: &'static str,
+    pub style: &'static str,
+    pub onpointerdown: Callback<PointerEvent>,
+    pub onpointermove: Callback<PointerEvent>,
+    /// Ends the drag; also use it for `onpointercancel`.
+    pub onpointerup: Callback<PointerEvent>,
+}
 
-    /// Current range values (start, end) for double slider mode.
-    #[prop_or_default]
-    pub range: Option<(f64, f64)>,
+/// Attributes of the fill inside the track.
+pub struct RangeAttributes {
+    pub class: &'static str,
+    pub style: String,
+}
 
-    /// Whether to enable double slider mode (range selector).
-    #[prop_or(false)]
-    pub double: bool,
+/// Attributes and handlers of a `role="slider"` thumb inside the track.
+pub struct ThumbAttributes {
+    pub id: String,
+    pub class: &'static str,
+    pub style: String,
+    pub role: &'static str,
+    pub tabindex: &'static str,
+    pub aria_valuemin: String,
+    pub aria_valuemax: String,
+    pub aria_valuenow: String,
+    pub aria_valuetext: String,
+    pub aria_orientation: &'static str,
+    pub aria_disabled: &'static str,
+    pub aria_label: Option<String>,
+    pub aria_labelledby: Option<String>,
+    pub aria_describedby: Option<String>,
+    pub onkeydown: Callback<KeyboardEvent>,
+    pub onkeyup: Callback<KeyboardEvent>,
+    pub onwheel: Option<Callback<WheelEvent>>,
+    pub onfocus: Callback<FocusEvent>,
+    pub onblur: Callback<FocusEvent>,
+}
 
-    /// Slider orientation: horizontal or vertical.
-    #[prop_or_default]
-    pub orientation: Orientation,
+/// Attributes and handlers of a focusable segment between the outer thumbs, which moves the
+/// whole range when dragged or with the arrow, page, Home and End keys.
+pub struct RangeDragAttributes {
+    pub class: &'static str,
+    pub style: String,
+    pub role: &'static str,
+    pub tabindex: &'static str,
+    pub aria_valuemin: String,
+    pub aria_valuemax: String,
+    pub aria_valuenow: String,
+    pub aria_valuetext: String,
+    pub aria_orientation: &'static str,
+    pub aria_disabled: &'static str,
+    pub aria_label: Option<String>,
+    pub aria_labelledby: Option<String>,
+    pub onpointerdown: Callback<PointerEvent>,
+    pub onpointermove: Callback<PointerEvent>,
+    /// Ends the drag; also use it for `onpointercancel`.
+    pub onpointerup: Callback<PointerEvent>,
+    pub onkeydown: Callback<KeyboardEvent>,
+    pub onkeyup: Callback<KeyboardEvent>,
+    pub onblur: Callback<FocusEvent>,
+}
 
-    /// Size variant for styling the slider track and thumb.
-    #[prop_or_default]
-    pub size: Size,
+/// Attributes and text of an `<output>` showing the values.
+pub struct OutputAttributes {
+    pub id: String,
+    pub for_ids: String,
+    pub aria_live: &'static str,
+    pub text: String,
+}
 
-    /// Color variant for styling the slider.
-    #[prop_or_default]
-    pub color: Color,
+/// State and handlers of a headless slider, see [`use_slider`].
+///
+/// Handles compare equal while they come from the same render, which lets the parts of a
+/// [`SliderRoot`] re-render with it.
+#[derive(Clone)]
+pub struct SliderHandle {
+    /// The slider with its current values.
+    pub model: SliderModel,
+    pub ids: SliderIds,
+    config: Rc<SliderConfig>,
+    values: UseStateHandle<Vec<f64>>,
+    pacer: ChangePacer,
+    dragging: Rc<RefCell<Option<usize>>>,
+    /// Value under the pointer and start of the range when a range drag began.
+    range_drag: Rc<RefCell<Option<(f64, f64)>>>,
+}
 
-    /// Cursor style when hovering over the slider.
-    #[prop_or_default]
-    pub cursor_style: Cursor,
+impl PartialEq for SliderHandle {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.config, &other.config) && self.model == other.model
+    }
+}
 
-    /// Whether to show the current value as an output.
-    #[prop_or(false)]
-    pub show_value: bool,
+impl SliderHandle {
+    pub fn values(&self) -> &[f64] {
+        self.model.values()
+    }
 
-    /// Whether to show step ticks along the slider track.
-    #[prop_or(false)]
-    pub show_steps: bool,
+    /// Text of `value`, see [`value_text`].
+    pub fn value_text(&self, value: f64) -> String {
+        value_text(
+            &self.model,
+            &self.config.labels,
+            &self.config.value_format,
+            value,
+        )
+    }
 
-    /// Whether to show tooltip above the thumb on hover.
-    #[prop_or(false)]
-    pub show_tooltip: bool,
+    /// Text of all values, as shown by the output.
+    pub fn output_text(&self) -> String {
+        self.values()
+            .iter()
+            .map(|&value| self.value_text(value))
+            .collect::<Vec<_>>()
+            .join(" – ")
+    }
 
-    /// Whether to disable interaction with the slider.
-    #[prop_or(false)]
-    pub disabled: bool,
+    pub fn is_dragging(&self) -> bool {
+        self.dragging.borrow().is_some() || self.range_drag.borrow().is_some()
+    }
 
-    /// Callback triggered when the slider value changes.
-    #[prop_or_default]
-    pub on_change: Callback<f64>,
+    pub fn aria_label(&self, thumb: usize) -> Option<String> {
+        self.config
+            .thumb_labels
+            .get(thumb)
+            .or(self.config.aria_label.as_ref())
+            .map(|label| label.to_string())
+    }
 
-    /// Callback triggered when the slider range changes (double mode).
-    #[prop_or_default]
-    pub on_change_range: Callback<(f64, f64)>,
+    pub fn aria_describedby(&self) -> Option<String> {
+        self.config
+            .aria_describedby
+            .as_deref()
+            .map(str::to_string)
+            .or_else(|| self.config.described.then(|| self.ids.description()))
+    }
 
-    /// Callback triggered when the slider gains focus.
-    #[prop_or_default]
-    pub on_focus: Callback<()>,
+    /// Moves `thumb` to `value` and returns the index the thumb ended up at, which differs
+    /// from `thumb` when [`RangeBehavior::Swap`] reorders the thumbs.
+    ///
+    /// Pointer input snaps onto the fill origin within its detent, see [`SliderModel::detent`].
+    pub fn set_value(&self, thumb: usize, value: f64, source: ChangeSource) -> Option<usize> {
+        let mut model = self.model.clone();
+        let value = if source == ChangeSource::Pointer {
+            model.detent(value)
+        } else {
+            value
+        };
+        let change = model.set_value(thumb, value)?.with_source(source);
+        Some(self.send(model, change))
+    }
 
-    /// Callback triggered when the slider loses focus.
-    #[prop_or_default]
-    pub on_blur: Callback<()>,
+    /// Applies a key to `thumb`, see [`SliderHandle::set_value`].
+    pub fn apply_key(&self, thumb: usize, action: KeyAction) -> Option<usize> {
+        let mut model = self.model.clone();
+        let change = model.apply_key(thumb, action)?;
+        Some(self.send(model, change))
+    }
 
-    /// ARIA label for accessibility.
-    #[prop_or_default]
-    pub aria_label: Option<&'static str>,
+    /// Moves `thumb` for a wheel delta and commits, see [`SliderHandle::set_value`].
+    pub fn apply_wheel(&self, thumb: usize, delta_y: f64) -> Option<usize> {
+        let mut model = self.model.clone();
+        let change = model.apply_wheel(thumb, delta_y)?;
+        let moved = self.send(model, change);
+        self.commit();
+        Some(moved)
+    }
 
-    /// ARIA describedby attribute for accessibility.
-    #[prop_or_default]
-    pub aria_describedby: Option<&'static str>,
+    /// Moves every thumb by `delta`, see [`SliderModel::shift_range`].
+    pub fn shift_range(&self, delta: f64, source: ChangeSource) {
+        let mut model = self.model.clone();
+        if let Some(change) = model.shift_range(delta) {
+            self.send(model, change.with_source(source));
+        }
+    }
 
-    /// CSS class for the container wrapping the slider.
-    #[prop_or("slider-container")]
-    pub container_class: &'static str,
+    /// Applies a key to the whole range, see [`SliderModel::apply_range_key`].
+    pub fn apply_range_key(&self, action: KeyAction) {
+        let mut model = self.model.clone();
+        if let Some(change) = model.apply_range_key(action) {
+            self.send(model, change);
+        }
+    }
 
-    /// Inline style for the container wrapping the slider.
-    #[prop_or(
-        "display: flex; flex-direction: column; align-items: center; margin: 20px; position: relative;"
-    )]
-    pub container_style: &'static str,
+    /// Sends any change held back and reports the last change as committed.
+    pub fn commit(&self) {
+        if let Some(change) = self.pacer.commit() {
+            self.config.on_commit.emit(change.committed());
+        }
+    }
 
-    /// CSS class for the slider label.
-    #[prop_or("slider-label")]
-    pub label_class: &'static str,
+    pub fn drag_start(&self, thumb: usize) {
+        if self.dragging.borrow().is_none() {
+            *self.dragging.borrow_mut() = Some(thumb);
+            self.config.on_drag_start.emit(thumb);
+        }
+    }
 
-    /// Inline style for the slider label.
-    #[prop_or("font-size: 14px; margin-bottom: 8px;")]
-    pub label_style: &'static str,
+    /// Ends a drag and commits it.
+    pub fn drag_end(&self) {
+        let thumb = self.dragging.borrow_mut().take();
+        if let Some(thumb) = thumb {
+            self.config.on_drag_end.emit(thumb);
+            self.commit();
+        }
+    }
 
-    /// CSS class for the slider input element.
-    #[prop_or("slider-input")]
-    pub input_class: &'static str,
+    pub fn focus(&self, thumb: usize) {
+        self.config.on_focus.emit(thumb);
+    }
 
-    /// Inline style for the slider input element.
-    #[prop_or("border-radius: 8px; appearance: none; outline: none;")]
-    pub input_style: &'static str,
+    /// Commits unless a drag is running; swapping thumbs mid-drag moves the focus, and the
+    /// drag commits on release instead.
+    pub fn blur(&self, thumb: usize) {
+        if !self.is_dragging() {
+            self.commit();
+        }
+        self.config.on_blur.emit(thumb);
+    }
 
-    /// CSS class for the value/output display.
-    #[prop_or("slider-output")]
-    pub output_class: &'static str,
+    pub fn track_props(&self) -> TrackAttributes {
+        let on_pointer_down = {
+            let handle = self.clone();
+            Callback::from(move |e: PointerEvent| {
+                if handle.config.disabled || e.button() != 0 {
+                    return;
+                }
+                if let Some((track, percent)) = pointer_on_track(&e, &handle.config.orientation) {
+                    e.prevent_default();
+                    let _ = track.set_pointer_capture(e.pointer_id());
+                    let value = handle.model.value_from_percent(percent);
+                    let thumb = handle.model.closest_thumb(value);
+                    let thumb = handle
+                        .set_value(thumb, value, ChangeSource::Pointer)
+                        .unwrap_or(thumb);
+                    handle.drag_start(thumb);
+                    focus_thumb(&track, thumb);
+                }
+            })
+        };
+        let on_pointer_move = {
+            let handle = self.clone();
+            Callback::from(move |e: PointerEvent| {
+                let Some(thumb) = *handle.dragging.borrow() else {
+                    return;
+                };
+                if let Some((track, percent)) = pointer_on_track(&e, &handle.config.orientation) {
+                    let value = handle.model.value_from_percent(percent);
+                    let moved = handle
+                        .set_value(thumb, value, ChangeSource::Pointer)
+                        .unwrap_or(thumb);
+                    if moved != thumb {
+                        focus_thumb(&track, moved);
+                    }
+                    *handle.dragging.borrow_mut() = Some(moved);
+                }
+            })
+        };
+        let on_pointer_up = {
+            let handle = self.clone();
+            Callback::from(move |_: PointerEvent| handle.drag_end())
+        };
+        TrackAttributes {
+            class: TRACK_CLASS,
+            style: TRACK_LAYOUT,
+            onpointerdown: on_pointer_down,
+            onpointermove: on_pointer_move,
+            onpointerup: on_pointer_up,
+        }
+    }
 
-    /// Inline style for the value/output display.
-    #[prop_or("font-size: 12px; margin-top: 8px;")]
-    pub output_style: &'static str,
+    pub fn range_props(&self) -> RangeAttributes {
+        let (start, end) = self.model.fill_percent();
+        RangeAttributes {
+            class: RANGE_CLASS,
+            style: range_layout(&self.config.orientation, start, end),
+        }
+    }
 
-    /// Inline style for the tooltip element.
-    #[prop_or(
-        "background-color: #333; color: #fff; padding: 4px 8px; border-radius: 4px; font-size: 12px; display: none;"
-    )]
-    pub tooltip_style: &'static str,
+    pub fn thumb_props(&self, thumb: usize) -> ThumbAttributes {
+        let value = self.model.thumb_value(thumb).unwrap_or(self.model.min());
+        let disabled = self.config.disabled;
+        let aria_label = self.aria_label(thumb);
+        let on_key_down = {
+            let handle = self.clone();
+            Callback::from(move |e: KeyboardEvent| {
+                let rtl = e.target().is_some_and(|target| is_rtl(&target));
+                if let Some(action) = KeyAction::from_key_event(&e.key(), e.shift_key(), rtl) {
+                    e.prevent_default();
+                    let moved = handle.apply_key(thumb, action).unwrap_or(thumb);
+                    if let (true, Some(target)) = (moved != thumb, e.target()) {
+                        focus_thumb(&target, moved);
+                    }
+                }
+            })
+        };
+        let on_wheel = (self.config.wheel && !disabled).then(|| {
+            let handle = self.clone();
+            Callback::from(move |e: WheelEvent| {
+                e.prevent_default();
+                let moved = handle.apply_wheel(thumb, e.delta_y()).unwrap_or(thumb);
+                if let (true, Some(target)) = (moved != thumb, e.target()) {
+                    focus_thumb(&target, moved);
+                }
+            })
+        });
+        ThumbAttributes {
+            id: self.ids.input(thumb),
+            class: THUMB_CLASS,
+            style: custom_thumb_style(&self.config.orientation, self.model.percent(value)),
+            role: "slider",
+            tabindex: if disabled { "-1" } else { "0" },
+            aria_valuemin: self.model.min().to_string(),
+            aria_valuemax: self.model.max().to_string(),
+            aria_valuenow: value.to_string(),
+            aria_valuetext: self.value_text(value),
+            aria_orientation: self.config.orientation.to_aria(),
+            aria_disabled: if disabled { "true" } else { "false" },
+            aria_labelledby: (aria_label.is_none() && self.config.labelled)
+                .then(|| self.ids.label()),
+            aria_label,
+            aria_describedby: self.aria_describedby(),
+            onkeydown: on_key_down,
+            onkeyup: {
+                let handle = self.clone();
+                Callback::from(move |_: KeyboardEvent| handle.commit())
+            },
+            onwheel: on_wheel,
+            onfocus: {
+                let handle = self.clone();
+                Callback::from(move |_: FocusEvent| handle.focus(thumb))
+            },
+            onblur: {
+                let handle = self.clone();
+                Callback::from(move |_: FocusEvent| handle.blur(thumb))
+            },
+        }
+    }
 
-    /// Inline style for the steps indicator below the slider track.
-    #[prop_or(
-        "width: 100%; display: flex; justify-content: space-between; margin-top: 8px; font-size: 10px;"
-    )]
-    pub steps_style: &'static str,
+    /// Getters for the range drag segment; range drags report the first thumb to
+    /// `on_drag_start` and `on_drag_end`.
+    pub fn range_drag_props(&self) -> RangeDragAttributes {
+        let disabled = self.config.disabled;
+        let (start, end) = self.model.range();
+        let (start_percent, end_percent) = self.model.fill_percent();
+        let on_pointer_down = {
+            let handle = self.clone();
+            Callback::from(move |e: PointerEvent| {
+                if handle.config.disabled || e.button() != 0 {
+                    return;
+                }
+                if let Some((segment, percent)) = pointer_on_range(&e, &handle.config.orientation) {
+                    e.prevent_default();
+                    e.stop_propagation();
+                    let _ = segment.set_pointer_capture(e.pointer_id());
+                    if let Some(segment) = segment.dyn_ref::<HtmlElement>() {
+                        let _ = segment.focus();
+                    }
+                    let grab = handle.model.value_from_percent(percent);
+                    *handle.range_drag.borrow_mut() = Some((grab, handle.model.range().0));
+                    handle.config.on_drag_start.emit(0);
+                }
+            })
+        };
+        let on_pointer_move = {
+            let handle = self.clone();
+            Callback::from(move |e: PointerEvent| {
+                let Some((grab, grab_start)) = *handle.range_drag.borrow() else {
+                    return;
+                };
+                if let Some((_, percent)) = pointer_on_range(&e, &handle.config.orientation) {
+                    let value = handle.model.value_from_percent(percent);
+                    let delta = grab_start + value - grab - handle.model.range().0;
+                    handle.shift_range(delta, ChangeSource::Pointer);
+                }
+            })
+        };
+        let on_pointer_up = {
+            let handle = self.clone();
+            Callback::from(move |_: PointerEvent| {
+                let grabbed = handle.range_drag.borrow_mut().take();
+                if grabbed.is_some() {
+                    handle.config.on_drag_end.emit(0);
+                    handle.commit();
+                }
+            })
+        };
+        let on_key_down = {
+            let handle = self.clone();
+            Callback::from(move |e: KeyboardEvent| {
+                let rtl = e.target().is_some_and(|target| is_rtl(&target));
+                if let Some(action) = KeyAction::from_key_event(&e.key(), e.shift_key(), rtl) {
+                    e.prevent_default();
+                    handle.apply_range_key(action);
+                }
+            })
+        };
+        RangeDragAttributes {
+            class: RANGE_DRAG_CLASS,
+            style: range_drag_style(&self.config.orientation, start_percent, end_percent),
+            role: "slider",
+            tabindex: if disabled { "-1" } else { "0" },
+            aria_valuemin: self.model.min().to_string(),
+            aria_valuemax: (self.model.max() - (end - start)).to_string(),
+            aria_valuenow: start.to_string(),
+            aria_valuetext: self.output_text(),
+            aria_orientation: self.config.orientation.to_aria(),
+            aria_disabled: if disabled { "true" } else { "false" },
+            aria_label: self
+                .config
+                .aria_label
+                .as_ref()
+                .map(|label| label.to_string()),
+            aria_labelledby: (self.config.aria_label.is_none() && self.config.labelled)
+                .then(|| self.ids.label()),
+            onpointerdown: on_pointer_down,
+            onpointermove: on_pointer_move,
+            onpointerup: on_pointer_up,
+            onkeydown: on_key_down,
+            onkeyup: {
+                let handle = self.clone();
+                Callback::from(move |_: KeyboardEvent| handle.commit())
+            },
+            onblur: {
+                let handle = self.clone();
+                Callback::from(move |_: FocusEvent| {
+                    if !handle.is_dragging() {
+                        handle.commit();
+                    }
+                })
+            },
+        }
+    }
+
+    pub fn output_props(&self) -> OutputAttributes {
+        OutputAttributes {
+            id: self.ids.output(),
+            for_ids: self.ids.inputs(self.values().len()),
+            aria_live: "polite",
+            text: self.output_text(),
+        }
+    }
+
+    /// Records the change and sends it under the rate limit, returning the moved thumb.
+    fn send(&self, model: SliderModel, change: SliderChange) -> usize {
+        self.values.set(model.values().to_vec());
+        let on_values = self.config.on_values.clone();
+        let on_change = self.config.on_change.clone();
+        let values = model.values().to_vec();
+        // A controlled slider renders its own values only while the change is held back, so it
+        // renders again once the change goes out, in case the parent keeps the old value.
+        let rerender = self.config.values.is_some().then(|| self.values.clone());
+        self.pacer.send(change, move || {
+            on_values.emit(values.clone());
+            on_change.emit(change);
+            if let Some(handle) = rerender {
+                handle.set(values);
+            }
+        });
+        change.thumb
+    }
+}
+
+/// Headless slider: the values, ids, pacing and keyboard, wheel and pointer handling of
+/// [`Slider`], with prop getters to render it in your own markup.
+///
+/// The getters target a `div` track holding the fill and one `role="slider"` element per
+/// thumb; keep their `class` on the elements, as the pointer and focus handling look them up.
+///
+/// # Examples
+/// ```rust
+/// use yew::prelude::*;
+/// use slider_rs::common::SliderModel;
+/// use slider_rs::yew::{SliderConfig, use_slider};
+///
+/// #[function_component]
+/// fn Volume() -> Html {
+///     let slider = use_slider(SliderConfig {
+///         labelled: true,
+///         ..SliderConfig::new(SliderModel::new(0.0, 100.0, 1.0).with_value(40.0))
+///     });
+///     let track = slider.track_props();
+///     let range = slider.range_props();
+///     let thumb = slider.thumb_props(0);
+///     let output = slider.output_props();
+///
+///     html! {
+///         <div class="volume">
+///             <span id={slider.ids.label()}>{ "Volume" }</span>
+///             <div
+///                 class={classes!(track.class, "volume-track")}
+///                 style={track.style}
+///                 onpointerdown={track.onpointerdown}
+///                 onpointermove={track.onpointermove}
+///                 onpointerup={track.onpointerup.clone()}
+///                 onpointercancel={track.onpointerup}
+///             >
+///                 <div class={classes!(range.class, "volume-fill")} style={range.style} />
+///                 <div
+///                     id={thumb.id}
+///                     class={classes!(thumb.class, "volume-knob")}
+///                     style={thumb.style}
+///                     role={thumb.role}
+///                     tabindex={thumb.tabindex}
+///                     aria-valuemin={thumb.aria_valuemin}
+///                     aria-valuemax={thumb.aria_valuemax}
+///                     aria-valuenow={thumb.aria_valuenow}
+///                     aria-valuetext={thumb.aria_valuetext}
+///                     aria-labelledby={thumb.aria_labelledby}
+///                     onkeydown={thumb.onkeydown}
+///                     onkeyup={thumb.onkeyup}
+///                     onblur={thumb.onblur}
+///                 />
+///             </div>
+///             <output id={output.id} for={output.for_ids} aria-live={output.aria_live}>
+///                 { output.text }
+///             </output>
+///         </div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_slider(config: SliderConfig) -> SliderHandle {
+    let values = {
+        let initial = config.model.values().to_vec();
+        use_state(move || initial)
+    };
+    let id_counter = use_context::<SliderIdCounter>();
+    #[cfg(any(feature = "ssr", feature = "hydrate"))]
+    let named = config.id.is_some();
+    let generated_ids = use_state(move || match id_counter {
+        Some(counter) => counter.next_ids(),
+        None => {
+            #[cfg(any(feature = "ssr", feature = "hydrate"))]
+            if !named {
+                warn_unnumbered_slider();
+            }
+            SliderIds::generated()
+        }
+    });
+    let pacer = use_state(ChangePacer::default);
+    let dragging = use_mut_ref(|| None::<usize>);
+    let range_drag = use_mut_ref(|| None::<(f64, f64)>);
+    pacer.set_limit(config.rate_limit);
+
+    let current = pacer.displayed(config.values.as_deref(), &values);
+    let ids = config
+        .id
+        .as_deref()
+        .map(SliderIds::new)
+        .unwrap_or_else(|| (*generated_ids).clone());
+    SliderHandle {
+        model: config.model.clone().with_values(current),
+        ids,
+        config: Rc::new(config),
+        values,
+        pacer: (*pacer).clone(),
+        dragging,
+        range_drag,
+    }
+}
+
+/// Props for the `SliderRoot` component.
+#[derive(Properties, PartialEq)]
+pub struct SliderRootProps {
+    /// Configuration of the state shared by the parts, see [`use_slider`].
+    pub config: SliderConfig,
+    /// Colour of the thumbs.
+    #[prop_or_default]
+    pub color: Color,
+    #[prop_or_default]
+    pub cursor_style: Cursor,
+    #[prop_or_default]
+    pub class: AttrValue,
+    #[prop_or_default]
+    pub style: AttrValue,
+    #[prop_or_default]
+    pub children: Html,
+}
+
+/// SliderRoot Component
+///
+/// Holds the state of a slider assembled from parts: [`SliderTrack`], [`SliderRange`],
+/// [`SliderThumb`], [`SliderMarks`] and [`SliderValue`] read it through context and can be
+/// reordered, wrapped or replaced. Your own parts get the shared [`SliderHandle`] with
+/// `use_context::<SliderHandle>()`.
+///
+/// # Examples
+/// ```rust
+/// use yew::prelude::*;
+/// use slider_rs::common::{Mark, SliderModel};
+/// use slider_rs::yew::{
+///     SliderConfig, SliderMarks, SliderRange, SliderRoot, SliderThumb, SliderTrack, SliderValue,
+/// };
+///
+/// #[function_component]
+/// fn Budget() -> Html {
+///     let config = SliderConfig {
+///         aria_label: Some("Budget".into()),
+///         ..SliderConfig::new(SliderModel::new(0.0, 1000.0, 50.0).with_values(vec![200.0, 600.0]))
+///     };
+///
+///     html! {
+///         <SliderRoot config={config} class="budget">
+///             <SliderTrack>
+///                 <SliderRange />
+///                 <SliderThumb index={0} />
+///                 <SliderThumb index={1} class="upper" />
+///             </SliderTrack>
+///             <SliderMarks marks={vec![Mark::new(0.0).with_label("0"), Mark::new(1000.0).with_label("1k")]} />
+///             <SliderValue />
+///         </SliderRoot>
+///     }
+/// }
+/// ```
+#[function_component(SliderRoot)]
+pub fn slider_root(props: &SliderRootProps) -> Html {
+    let slider = use_slider(props.config.clone());
+    let disabled = props.config.disabled;
+    html! {
+        <ContextProvider<SliderHandle> context={slider.clone()}>
+            <div
+                id={slider.ids.root().to_string()}
+                class={classes!("slider-root", props.class.clone())}
+                style={props.style.clone()}
+                role="group"
+                aria-labelledby={props.config.labelled.then(|| slider.ids.label())}
+                aria-orientation={props.config.orientation.to_aria()}
+                aria-disabled={disabled.to_string()}
+            >
+                <style>
+                    { thumb_css(slider.ids.root(), &props.cursor_style, &props.color, disabled, None) }
+                </style>
+                { props.children.clone() }
+            </div>
+        </ContextProvider<SliderHandle>>
+    }
+}
+
+/// The [`SliderHandle`] shared by the enclosing [`SliderRoot`].
+#[hook]
+fn use_slider_handle(part: &'static str) -> SliderHandle {
+    use_context::<SliderHandle>()
+        .unwrap_or_else(|| panic!("`{}` must be placed inside a `SliderRoot`", part))
+}
+
+/// Props for the `SliderTrack` component.
+#[derive(Properties, PartialEq)]
+pub struct SliderTrackProps {
+    #[prop_or_default]
+    pub class: AttrValue,
+    /// Appended to the default track style.
+    #[prop_or_default]
+    pub style: AttrValue,
+    #[prop_or_default]
+    pub children: Html,
+}
+
+/// SliderTrack Component
+///
+/// The track of a [`SliderRoot`]; a press moves the closest thumb there. Place the
+/// [`SliderRange`] and the [`SliderThumb`]s inside it.
+#[function_component(SliderTrack)]
+pub fn slider_track(props: &SliderTrackProps) -> Html {
+    let slider = use_slider_handle("SliderTrack");
+    let track = slider.track_props();
+    let style = format!(
+        "{} {}",
+        custom_track_style(
+            &slider.config.orientation,
+            &Width::default(),
+            &Height::default(),
+            &Size::default(),
+            slider.config.disabled,
+        ),
+        props.style
+    );
+    html! {
+        <div
+            class={classes!(track.class, props.class.clone())}
+            style={style}
+            onpointerdown={track.onpointerdown}
+            onpointermove={track.onpointermove}
+            onpointerup={track.onpointerup.clone()}
+            onpointercancel={track.onpointerup}
+        >
+            { props.children.clone() }
+        </div>
+    }
+}
+
+/// Props for the `SliderRange` component.
+#[derive(Properties, PartialEq)]
+pub struct SliderRangeProps {
+    #[prop_or_default]
+    pub color: Color,
+    #[prop_or_default]
+    pub class: AttrValue,
+    /// Appended to the default fill style.
+    #[prop_or_default]
+    pub style: AttrValue,
+}
+
+/// SliderRange Component
+///
+/// The fill of a [`SliderTrack`], from the start of the track to a single thumb or between
+/// the outer thumbs.
+#[function_component(SliderRange)]
+pub fn slider_range(props: &SliderRangeProps) -> Html {
+    let slider = use_slider_handle("SliderRange");
+    let range = slider.range_props();
+    let (start, end) = slider.model.fill_percent();
+    let style = format!(
+        "{} {}",
+        custom_range_style(&slider.config.orientation, &props.color, start, end),
+        props.style
+    );
+    html! {
+        <div class={classes!(range.class, props.class.clone())} style={style} />
+    }
+}
+
+/// Props for the `SliderThumb` component.
+#[derive(Properties, PartialEq)]
+pub struct SliderThumbProps {
+    /// Index of the thumb, in track order.
+    #[prop_or(0)]
+    pub index: usize,
+    #[prop_or_default]
+    pub class: AttrValue,
+    /// Appended to the thumb placement.
+    #[prop_or_default]
+    pub style: AttrValue,
+    /// Content rendered inside the thumb.
+    #[prop_or_default]
+    pub children: Html,
+}
+
+/// SliderThumb Component
+///
+/// A `role="slider"` thumb of a [`SliderTrack`], moved by the keyboard, the wheel and by
+/// dragging; its look comes from the `color` and `cursor_style` of the [`SliderRoot`].
+#[function_component(SliderThumb)]
+pub fn slider_thumb(props: &SliderThumbProps) -> Html {
+    let slider = use_slider_handle("SliderThumb");
+    let thumb = slider.thumb_props(props.index);
+    html! {
+        <div
+            id={thumb.id}
+            class={classes!(thumb.class, props.class.clone())}
+            style={format!("{} {}", thumb.style, props.style)}
+            role={thumb.role}
+            tabindex={thumb.tabindex}
+            onkeydown={thumb.onkeydown}
+            onkeyup={thumb.onkeyup}
+            onwheel={thumb.onwheel}
+            onfocus={thumb.onfocus}
+            onblur={thumb.onblur}
+            aria-valuemin={thumb.aria_valuemin}
+            aria-valuemax={thumb.aria_valuemax}
+            aria-valuenow={thumb.aria_valuenow}
+            aria-valuetext={thumb.aria_valuetext}
+            aria-orientation={thumb.aria_orientation}
+            aria-disabled={thumb.aria_disabled}
+            aria-label={thumb.aria_label}
+            aria-labelledby={thumb.aria_labelledby}
+            aria-describedby={thumb.aria_describedby}
+        >
+            { props.children.clone() }
+        </div>
+    }
+}
+
+/// Props for the `SliderMarks` component.
+#[derive(Properties, PartialEq)]
+pub struct SliderMarksProps {
+    pub marks: Vec<Mark>,
+}
+
+/// SliderMarks Component
+///
+/// Labelled marks lined up with the thumb positions of a [`SliderRoot`]; set
+/// [`SliderModel::with_snap_points`] on the configuration to make the thumbs snap to them.
+#[function_component(SliderMarks)]
+pub fn slider_marks_part(props: &SliderMarksProps) -> Html {
+    let slider = use_slider_handle("SliderMarks");
+    html! {
+        <Marks
+            model={slider.model.clone()}
+            marks={props.marks.clone()}
+            orientation={slider.config.orientation.clone()}
+        />
+    }
+}
+
+/// Props for the `SliderValue` component.
+#[derive(Properties, PartialEq)]
+pub struct SliderValueProps {
+    #[prop_or(AttrValue::Static("slider-output"))]
+    pub class: AttrValue,
+    #[prop_or(AttrValue::Static("font-size: 12px; margin-top: 8px; text-align: center;"))]
+    pub style: AttrValue,
+}
+
+/// SliderValue Component
+///
+/// An `<output>` announcing the values of a [`SliderRoot`] as they change.
+#[function_component(SliderValue)]
+pub fn slider_value(props: &SliderValueProps) -> Html {
+    let slider = use_slider_handle("SliderValue");
+    let output = slider.output_props();
+    html! {
+        <Output
+            id={output.id}
+            for_ids={output.for_ids}
+            value_display={output.text}
+            output_class={props.class.clone()}
+            output_style={props.style.clone()}
+        />
+    }
+}
+
+/// Props for the `Slider` component.
+///
+/// These props configure the behavior, appearance, and accessibility of a slider input component
+/// supporting both single and double (range) sliders.
+///
+/// # Features
+/// - Single or double slider modes.
+/// - Vertical or horizontal orientation.
+/// - Customizable appearance with CSS styles and classes.
+/// - Tooltip, value display, and step indicators.
+/// - Full accessibility support (ARIA attributes, keyboard steps).
+#[derive(Properties, PartialEq, Clone)]
+pub struct Props {
+    /// Id of the slider container; the ids of the label, inputs, datalist, output and
+    /// description derive from it. Generated once per slider when unset.
+    #[prop_or_default]
+    pub id: Option<AttrValue>,
+
+    /// Label text displayed above the slider.
+    #[prop_or_default]
+    pub label: AttrValue,
+
+    /// Text below the label that every thumb references through `aria-describedby`.
+    #[prop_or_default]
+    pub description: Option<AttrValue>,
+
+    /// Minimum value for the slider.
+    #[prop_or(0.0)]
+    pub min: f64,
+
+    /// Maximum value for the slider.
+    #[prop_or(10.0)]
+    pub max: f64,
+
+    /// Increment step size.
+    #[prop_or(1.0)]
+    pub step: f64,
+
+    /// Controlled value for single slider mode.
+    ///
+    /// When set, the slider always renders this value and only reports user changes through
+    /// `on_change`; the parent is expected to pass the new value back.
+    #[prop_or_default]
+    pub value: Option<f64>,
+
+    /// Initial value for an uncontrolled single slider. Defaults to `min`.
+    #[prop_or_default]
+    pub default_value: Option<f64>,
+
+    /// Controlled range values (start, end) for double slider mode.
+    ///
+    /// When set, the slider always renders this range and only reports user changes through
+    /// `on_change_range`; the parent is expected to pass the new range back.
+    #[prop_or_default]
+    pub range: Option<(f64, f64)>,
+
+    /// Initial range for an uncontrolled double slider. Defaults to `(min, max)`.
+    #[prop_or_default]
+    pub default_range: Option<(f64, f64)>,
+
+    /// Whether to enable double slider mode (range selector).
+    #[prop_or(false)]
+    pub double: bool,
+
+    /// Lets the segment between the outer thumbs drag the whole range, keeping its width.
+    ///
+    /// The segment is focusable and moves the range with the arrow, page, Home and End keys.
+    #[prop_or(false)]
+    pub draggable_range: bool,
+
+    /// Controlled values for a slider with any number of thumbs.
+    ///
+    /// Takes precedence over `value`, `range` and `double`; one thumb is rendered per value.
+    #[prop_or_default]
+    pub values: Option<Vec<f64>>,
+
+    /// Initial values for an uncontrolled multi-thumb slider.
+    #[prop_or_default]
+    pub default_values: Option<Vec<f64>>,
+
+    /// Smallest allowed gap between neighbouring thumbs.
+    #[prop_or(0.0)]
+    pub min_distance: f64,
+
+    /// Largest allowed gap between neighbouring thumbs.
+    #[prop_or_default]
+    pub max_distance: Option<f64>,
+
+    /// How a thumb reacts when dragged into a neighbouring thumb.
+    #[prop_or_default]
+    pub range_behavior: RangeBehavior,
+
+    /// How values are distributed along the track.
+    #[prop_or_default]
+    pub scale: Scale,
+
+    /// Labelled marks drawn at their position along the track.
+    #[prop_or_default]
+    pub marks: Vec<Mark>,
+
+    /// Restricts values to the `marks` instead of the step grid.
+    #[prop_or(false)]
+    pub snap_to_marks: bool,
+
+    /// Coloured regions drawn on the track behind the fill, e.g. thresholds of a gauge.
+    #[prop_or_default]
+    pub zones: Vec<Zone>,
+
+    /// Colours the fill and the thumbs with the zone holding the last thumb.
+    #[prop_or(false)]
+    pub color_by_zone: bool,
+
+    /// Item counts drawn as bars behind the track, spread evenly from `min` to `max`; bars
+    /// outside the selection are dimmed.
+    #[prop_or_default]
+    pub histogram: Vec<f64>,
+
+    /// Value the fill of a single-thumb slider grows from, e.g. `0.0` for a balance slider
+    /// from `-50.0` to `50.0`. Defaults to `min`.
+    #[prop_or_default]
+    pub fill_origin: Option<f64>,
+
+    /// Distance from `fill_origin` within which a dragged thumb snaps onto it, like a centre
+    /// detent.
+    #[prop_or_default]
+    pub origin_detent: Option<f64>,
+
+    /// Text for each of [`SliderModel::step_values`], from `min` upwards.
+    ///
+    /// Replaces the numbers in the output, the tooltip, the step labels and `aria-valuetext`,
+    /// e.g. `["Off", "Low", "High"]` for a `0.0..=2.0` slider.
+    #[prop_or_default]
+    pub labels: Vec<String>,
+
+    /// How values are shown in the output, the tooltip, the step labels and `aria-valuetext`.
+    #[prop_or_default]
+    pub value_format: ValueFormat,
+
+    /// Slider orientation: horizontal or vertical.
+    #[prop_or_default]
+    pub orientation: Orientation,
+
+    /// Size variant for styling the slider track and thumb.
+    #[prop_or_default]
+    pub size: Size,
+
+    /// Color variant for styling the slider.
+    #[prop_or_default]
+    pub color: Color,
+
+    /// Colour of the track outside the fill; defaults to the theme's track colour.
+    #[prop_or_default]
+    pub outside_color: Option<Color>,
+
+    /// Runs the fill from the far end of the track, for sliders laid out right to left.
+    #[prop_or(false)]
+    pub rtl_fill: bool,
+
+    /// Thumb shape: `Round`, `Square` or `Custom` CSS declarations.
+    #[prop_or_default]
+    pub cursor_style: Cursor,
+
+    /// Whether to show the current value as an output.
+    #[prop_or(false)]
+    pub show_value: bool,
+
+    /// Whether to show step ticks along the slider track.
+    #[prop_or(false)]
+    pub show_steps: bool,
+
+    /// Whether to show tooltip above the thumb on hover.
+    #[prop_or(false)]
+    pub show_tooltip: bool,
+
+    /// Whether to disable interaction with the slider.
+    #[prop_or(false)]
+    pub disabled: bool,
+
+    /// Callback triggered when the slider value changes.
+    #[prop_or_default]
+    pub on_change: Callback<f64>,
+
+    /// Callback triggered when the slider range changes (double mode).
+    #[prop_or_default]
+    pub on_change_range: Callback<(f64, f64)>,
+
+    /// Callback triggered with every thumb value when any thumb moves.
+    #[prop_or_default]
+    pub on_change_values: Callback<Vec<f64>>,
+
+    /// Callback triggered with the final value once a thumb is released, a key is released or
+    /// the slider loses focus.
+    #[prop_or_default]
+    pub on_change_committed: Callback<f64>,
+
+    /// Callback triggered with the thumb, the old and new value and the source of every change,
+    /// and once more with `committed` set when the interaction ends.
+    #[prop_or_default]
+    pub on_change_event: Callback<SliderChange>,
+
+    /// Callback triggered with the thumb index when a pointer starts dragging a thumb.
+    #[prop_or_default]
+    pub on_drag_start: Callback<usize>,
+
+    /// Callback triggered with the thumb index when the pointer lets go of the thumb.
+    #[prop_or_default]
+    pub on_drag_end: Callback<usize>,
+
+    /// Sends at most one live change per this many milliseconds while the slider moves.
+    #[prop_or_default]
+    pub throttle_ms: Option<u32>,
+
+    /// Sends a live change only after the slider rested for this many milliseconds; takes
+    /// precedence over `throttle_ms`.
+    #[prop_or_default]
+    pub debounce_ms: Option<u32>,
+
+    /// Callback triggered when the slider gains focus.
+    #[prop_or_default]
+    pub on_focus: Callback<()>,
+
+    /// Callback triggered when the slider loses focus.
+    #[prop_or_default]
+    pub on_blur: Callback<()>,
+
+    /// ARIA label for accessibility.
+    #[prop_or_default]
+    pub aria_label: Option<AttrValue>,
+
+    /// Id of an element describing the slider; defaults to the `description`.
+    #[prop_or_default]
+    pub aria_describedby: Option<AttrValue>,
+
+    /// Per-thumb ARIA labels, falling back to `aria_label` for thumbs without one.
+    #[prop_or_default]
+    pub thumb_labels: Vec<AttrValue>,
+
+    /// CSS class for the container wrapping the slider.
+    #[prop_or(AttrValue::Static("slider-container"))]
+    pub container_class: AttrValue,
+
+    /// Inline style for the container wrapping the slider.
+    #[prop_or(AttrValue::Static(
+        "display: flex; flex-direction: column; align-items: center; margin: 20px; position: relative;"
+    ))]
+    pub container_style: AttrValue,
+
+    /// Colours, radii and sizes emitted as `--slider-*` CSS variables on the container.
+    ///
+    /// Falls back to the theme of the nearest [`ThemeProvider`].
+    #[prop_or_default]
+    pub theme: Option<Theme>,
+
+    /// How the track and thumbs are drawn: native range inputs, or a `div` track with
+    /// pointer-driven `role="slider"` thumbs that hold `custom_thumb_html`.
+    #[prop_or_default]
+    pub renderer: Renderer,
+
+    /// CSS class for the slider label.
+    #[prop_or(AttrValue::Static("slider-label"))]
+    pub label_class: AttrValue,
+
+    /// Inline style for the slider label.
+    #[prop_or(AttrValue::Static("font-size: 14px; margin-bottom: 8px;"))]
+    pub label_style: AttrValue,
+
+    /// CSS class for the slider input element.
+    #[prop_or(AttrValue::Static("slider-input"))]
+    pub input_class: AttrValue,
+
+    /// Inline style for the slider input element.
+    #[prop_or(AttrValue::Static(
+        "border-radius: var(--slider-track-radius, 8px); appearance: none; outline: none;"
+    ))]
+    pub input_style: AttrValue,
+
+    /// CSS class for the value/output display.
+    #[prop_or(AttrValue::Static("slider-output"))]
+    pub output_class: AttrValue,
+
+    /// Inline style for the value/output display.
+    #[prop_or(AttrValue::Static("font-size: 12px; margin-top: 8px;"))]
+    pub output_style: AttrValue,
+
+    /// Inline style for the tooltip element.
+    #[prop_or(AttrValue::Static(
+        "background-color: var(--slider-tooltip-bg, #333); color: var(--slider-tooltip-text, #fff); padding: 4px 8px; border-radius: 4px; font-size: 12px; display: none;"
+    ))]
+    pub tooltip_style: AttrValue,
+
+    /// Inline style for the steps indicator below the slider track.
+    #[prop_or(AttrValue::Static(
+        "width: 100%; display: flex; justify-content: space-between; margin-top: 8px; font-size: 10px;"
+    ))]
+    pub steps_style: AttrValue,
 
     /// Custom width for the slider track.
     #[prop_or_default]
@@ -433,9 +1674,9 @@ pub struct Props {
     #[prop_or_default]
     pub slider_height: Height,
 
-    /// Optional custom CSS for the slider thumb.
+    /// Extra CSS declarations for the native thumb, e.g. `"border: 2px solid #fff;"`.
     #[prop_or_default]
-    pub custom_thumb_css: Option<&'static str>,
+    pub custom_thumb_css: Option<AttrValue>,
 
     /// Optional custom HTML content for the slider thumb.
     #[prop_or_default]
@@ -445,6 +1686,14 @@ pub struct Props {
     #[prop_or(1.0)]
     pub keyboard_step: f64,
 
+    /// Whether the mouse wheel over a thumb moves it by `keyboard_step`.
+    #[prop_or(false)]
+    pub wheel: bool,
+
+    /// Increment for PageUp, PageDown and Shift+arrow keys; defaults to a tenth of the range.
+    #[prop_or_default]
+    pub page_step: Option<f64>,
+
     /// Optional icon element displayed before the slider.
     #[prop_or_default]
     pub icon_start: Option<Html>,
@@ -459,7 +1708,7 @@ pub struct Props {
 /// A Yew slider (range input) component, supporting both single and double handle sliders.
 ///
 /// # Features
-/// - Single value or double range mode (TODO).
+/// - Single value, double range or any number of thumbs.
 /// - Horizontal and vertical orientations.
 /// - Customizable track, thumb, tooltip, and step indicators.
 /// - Full ARIA support for accessibility.
@@ -467,28 +1716,96 @@ pub struct Props {
 ///
 /// # Examples
 ///
-/// ## Single Value Slider
+/// ## Single Value Slider
+/// ```rust
+/// use yew::prelude::*;
+/// use slider_rs::yew::{Slider};
+///
+/// #[function_component]
+/// fn App() -> Html {
+///     html! {
+///         <Slider
+///             label="Volume"
+///             min={0.0}
+///             max={100.0}
+///             step={1.0}
+///             default_value={50.0}
+///             show_value={true}
+///             on_change={Callback::from(|v| log::info!("Value: {}", v))}
+///         />
+///     }
+/// }
+/// ```
+///
+/// ## Controlled Slider
+/// ```rust
+/// use yew::prelude::*;
+/// use slider_rs::yew::{Slider};
+///
+/// #[function_component]
+/// fn App() -> Html {
+///     let value = use_state(|| 50.0);
+///     let on_change = {
+///         let value = value.clone();
+///         Callback::from(move |v| value.set(v))
+///     };
+///
+///     html! {
+///         <Slider
+///             label="Volume"
+///             min={0.0}
+///             max={100.0}
+///             value={Some(*value)}
+///             on_change={on_change}
+///         />
+///     }
+/// }
+/// ```
+///
+/// ## Double Range Slider
+/// ```rust
+/// use yew::prelude::*;
+/// use slider_rs::yew::{Slider};
+///
+/// #[function_component]
+/// fn App() -> Html {
+///     html! {
+///         <Slider
+///             label="Range"
+///             min={0.0}
+///             max={100.0}
+///             step={1.0}
+///             default_range={Some((20.0, 80.0))}
+///             double={true}
+///             show_value={true}
+///             on_change_range={Callback::from(|(start, end)| log::info!("Range: {} - {}", start, end))}
+///         />
+///     }
+/// }
+/// ```
+///
+/// ## Logarithmic Slider
 /// ```rust
 /// use yew::prelude::*;
 /// use slider_rs::yew::{Slider};
+/// use slider_rs::Scale;
 ///
 /// #[function_component]
 /// fn App() -> Html {
 ///     html! {
 ///         <Slider
-///             label="Volume"
-///             min={0.0}
-///             max={100.0}
-///             step={1.0}
-///             value={Some(50.0)}
-///             show_value={true}
-///             on_change={Callback::from(|v| log::info!("Value: {}", v))}
+///             label="Cutoff (Hz)"
+///             min={20.0}
+///             max={20_000.0}
+///             default_value={Some(1_000.0)}
+///             scale={Scale::Log}
+///             show_steps={true}
 ///         />
 ///     }
 /// }
 /// ```
 ///
-/// ## Double Range Slider
+/// ## Multi-Thumb Slider
 /// ```rust
 /// use yew::prelude::*;
 /// use slider_rs::yew::{Slider};
@@ -497,14 +1814,13 @@ pub struct Props {
 /// fn App() -> Html {
 ///     html! {
 ///         <Slider
-///             label="Range"
+///             label="Alert thresholds"
 ///             min={0.0}
 ///             max={100.0}
-///             step={1.0}
-///             range={Some((20.0, 80.0))}
-///             double={true}
-///             show_value={true}
-///             on_change_range={Callback::from(|(start, end)| log::info!("Range: {} - {}", start, end))}
+///             default_values={Some(vec![25.0, 50.0, 75.0])}
+///             min_distance={5.0}
+///             thumb_labels={vec!["Warning".into(), "Error".into(), "Critical".into()]}
+///             on_change_values={Callback::from(|values: Vec<f64>| log::info!("Thresholds: {:?}", values))}
 ///         />
 ///     }
 /// }
@@ -513,6 +1829,9 @@ pub struct Props {
 /// # Notes
 /// - Subcomponents include: `Label`, `Input`, `Ticks`, `Output`, `Steps`.
 /// - Double slider mode renders two overlapping input elements.
+/// - `values`/`default_values` render one stacked input per value on a shared track.
+/// - `value`/`range` make the slider controlled; `default_value`/`default_range` leave the
+///   state to the slider itself.
 /// - Styling is fully customizable via `*_style` and `*_class` props.
 ///
 /// # Accessibility
@@ -523,288 +1842,521 @@ pub struct Props {
 /// - [MDN <input type="range"> Element](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/input/range)
 #[function_component(Slider)]
 pub fn slider(props: &Props) -> Html {
-    let input_ref1 = use_node_ref();
-    let input_ref2 = use_node_ref();
-    let (initial_val1, initial_val2) = props.range.unwrap_or((props.min, props.max));
-    let val1 = use_state(|| initial_val1);
-    let val2 = use_state(|| initial_val2);
-
-    let list_id = format!("slider-list-{}", Uuid::new_v4());
+    let multi = props.values.is_some() || props.default_values.is_some();
+    let double = props.double && !multi;
+    let initial = if let Some(default_values) = &props.default_values {
+        default_values.clone()
+    } else if props.double {
+        let (start, end) = props.default_range.unwrap_or((props.min, props.max));
+        vec![start, end]
+    } else {
+        vec![props.default_value.unwrap_or(props.min)]
+    };
+    let controlled = if multi {
+        props.values.clone()
+    } else if props.double {
+        props.range.map(|(start, end)| vec![start, end])
+    } else {
+        props.value.map(|value| vec![value])
+    };
+    let context_theme = use_context::<Theme>();
+    let model = SliderModel::new(props.min, props.max, props.step)
+        .with_keyboard_step(props.keyboard_step)
+        .with_page_step(props.page_step)
+        .with_min_distance(props.min_distance)
+        .with_max_distance(props.max_distance)
+        .with_range_behavior(props.range_behavior)
+        .with_scale(props.scale.clone())
+        .with_snap_points(if props.snap_to_marks {
+            props.marks.iter().map(|mark| mark.value).collect()
+        } else {
+            Vec::new()
+        })
+        .with_fill_origin(props.fill_origin)
+        .with_origin_detent(props.origin_detent)
+        .with_values(initial);
 
-    let update_range = {
-        let val1 = val1.clone();
-        let val2 = val2.clone();
+    let on_values = {
         let on_change_range = props.on_change_range.clone();
-        let on_change = props.on_change.clone();
-        Callback::from(move |_| {
-            on_change_range.emit((*val1, *val2));
-            on_change.emit(*val1);
+        let on_change_values = props.on_change_values.clone();
+        Callback::from(move |values: Vec<f64>| {
+            if double {
+                on_change_range.emit((values[0], values[values.len() - 1]));
+            }
+            on_change_values.emit(values);
         })
     };
-
-    let on_input1 = {
-        let val1 = val1.clone();
-        let update_range = update_range.clone();
+    let on_change = {
         let on_change = props.on_change.clone();
-        Callback::from(move |e: InputEvent| {
-            if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
-                if let Ok(v) = input.value().parse::<f64>() {
-                    val1.set(v);
-                    update_range.emit(());
-                    on_change.emit(v);
+        let on_change_event = props.on_change_event.clone();
+        Callback::from(move |change: SliderChange| {
+            on_change.emit(change.value);
+            on_change_event.emit(change);
+        })
+    };
+    let on_commit = {
+        let on_change_committed = props.on_change_committed.clone();
+        let on_change_event = props.on_change_event.clone();
+        Callback::from(move |change: SliderChange| {
+            on_change_committed.emit(change.value);
+            on_change_event.emit(change);
+        })
+    };
+    let slider = use_slider(SliderConfig {
+        model,
+        values: controlled,
+        id: props.id.clone(),
+        orientation: props.orientation.clone(),
+        disabled: props.disabled,
+        wheel: props.wheel,
+        rate_limit: RateLimit {
+            throttle_ms: props.throttle_ms,
+            debounce_ms: props.debounce_ms,
+        },
+        value_format: props.value_format.clone(),
+        labels: props.labels.clone(),
+        thumb_labels: props.thumb_labels.clone(),
+        aria_label: props.aria_label.clone(),
+        labelled: !props.label.is_empty(),
+        aria_describedby: props.aria_describedby.clone(),
+        described: props.description.is_some(),
+        on_change,
+        on_values,
+        on_commit,
+        on_drag_start: props.on_drag_start.clone(),
+        on_drag_end: props.on_drag_end.clone(),
+        on_focus: props.on_focus.reform(|_| ()),
+        on_blur: props.on_blur.reform(|_| ()),
+    });
+    let model = &slider.model;
+    let ids = &slider.ids;
+    let thumbs = model.values().len();
+    let color = props
+        .color_by_zone
+        .then(|| Zone::at(&props.zones, model.values()[thumbs - 1]))
+        .flatten()
+        .map_or_else(|| props.color.clone(), |zone| zone.color.clone());
+    let zoned = (!props.zones.is_empty()).then(|| {
+        zoned_background(
+            &color,
+            props.outside_color.as_ref(),
+            &props.zones,
+            model,
+            &props.orientation,
+            props.rtl_fill,
+        )
+    });
+
+    // One node ref per thumb; the list grows and shrinks with the number of values.
+    let input_refs = use_mut_ref(Vec::<NodeRef>::new);
+    input_refs
+        .borrow_mut()
+        .resize_with(thumbs, NodeRef::default);
+    let input_refs = input_refs.borrow().clone();
+    let list_id = ids.list();
+
+    // Keeps keyboard focus on the moved thumb when `RangeBehavior::Swap` reorders the thumbs.
+    let follow_thumb = {
+        let input_refs = input_refs.clone();
+        move |thumb: usize, moved: Option<usize>| {
+            if let Some(moved) = moved.filter(|&moved| moved != thumb) {
+                if let Some(input) = input_refs[moved].cast::<HtmlInputElement>() {
+                    let _ = input.focus();
                 }
             }
+        }
+    };
+
+    let on_input = |thumb: usize| {
+        let slider = slider.clone();
+        let follow_thumb = follow_thumb.clone();
+        Callback::from(move |v: f64| {
+            follow_thumb(thumb, slider.set_value(thumb, v, ChangeSource::Pointer));
         })
     };
 
-    let on_input2 = {
-        let val2 = val2.clone();
-        let update_range = update_range.clone();
-        let on_change = props.on_change.clone();
-        Callback::from(move |e: InputEvent| {
-            if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
-                if let Ok(v) = input.value().parse::<f64>() {
-                    val2.set(v);
-                    update_range.emit(());
-                    on_change.emit(v);
-                }
-            }
+    let on_key = |thumb: usize| {
+        let slider = slider.clone();
+        let follow_thumb = follow_thumb.clone();
+        Callback::from(move |action: KeyAction| {
+            follow_thumb(thumb, slider.apply_key(thumb, action));
         })
     };
 
-    let on_focus_cb = {
-        let cb = props.on_focus.clone();
-        Callback::from(move |_| cb.emit(()))
+    let on_wheel = |thumb: usize| {
+        let slider = slider.clone();
+        let follow_thumb = follow_thumb.clone();
+        (props.wheel && !props.disabled).then(|| {
+            Callback::from(move |delta_y: f64| {
+                follow_thumb(thumb, slider.apply_wheel(thumb, delta_y));
+            })
+        })
+    };
+
+    let commit = {
+        let slider = slider.clone();
+        Callback::from(move |_| slider.commit())
+    };
+    let drag_start = {
+        let slider = slider.clone();
+        Callback::from(move |thumb: usize| slider.drag_start(thumb))
+    };
+    let drag_end = {
+        let slider = slider.clone();
+        Callback::from(move |_| slider.drag_end())
+    };
+    let on_focus_cb = |thumb: usize| {
+        let slider = slider.clone();
+        Callback::from(move |_| slider.focus(thumb))
+    };
+    let on_blur_cb = |thumb: usize| {
+        let slider = slider.clone();
+        Callback::from(move |_| slider.blur(thumb))
+    };
+
+    let input = |thumb: usize, background: Option<String>| {
+        html! {
+            <Input
+                key={thumb}
+                input_ref={input_refs[thumb].clone()}
+                model={model.clone()}
+                thumb={thumb}
+                rtl_fill={props.rtl_fill}
+                background={background}
+                orientation={props.orientation.clone()}
+                disabled={props.disabled}
+                size={props.size.clone()}
+                color={color.clone()}
+                outside_color={props.outside_color.clone()}
+                input_class={props.input_class.clone()}
+                on_input={on_input(thumb)}
+                on_key={on_key(thumb)}
+                on_wheel={on_wheel(thumb)}
+                on_commit={commit.clone()}
+                on_drag_start={drag_start.reform(move |_| thumb)}
+                on_drag_end={drag_end.clone()}
+                on_focus={on_focus_cb(thumb)}
+                on_blur={on_blur_cb(thumb)}
+                id={ids.input(thumb)}
+                datalist_id={Some(list_id.clone())}
+                aria_label={slider.aria_label(thumb)}
+                aria_describedby={slider.aria_describedby()}
+                aria_valuetext={model.thumb_value(thumb).map(|value| slider.value_text(value))}
+                width={props.slider_width.clone()}
+                height={props.slider_height.clone()}
+                input_style={props.input_style.clone()}
+                custom_thumb_html={props.custom_thumb_html.clone()}
+            />
+        }
     };
 
-    let on_blur_cb = {
-        let cb = props.on_blur.clone();
-        Callback::from(move |_| cb.emit(()))
+    let is_vertical = props.orientation.is_vertical();
+    let group_style = if is_vertical {
+        "display: flex; flex-direction: row; align-items: flex-start;"
+    } else if props.double || multi {
+        "position: relative; width: 100%; display: flex; align-items: center;"
+    } else {
+        "display: flex; align-items: center; width: 100%;"
+    };
+    let step_values = model.step_values();
+    let tick_values = if props.marks.is_empty() {
+        model.tick_values()
+    } else {
+        props
+            .marks
+            .iter()
+            .map(|mark| model.to_input(mark.value))
+            .collect()
+    };
+    // Native thumbs stop half a thumb short of the track ends; custom thumbs are centred.
+    let native_thumb_size = AttrValue::from(if props.renderer == Renderer::Native {
+        thumb_size(props.theme.as_ref().or(context_theme.as_ref())).to_string()
+    } else {
+        "0px".to_string()
+    });
+    let marks = html! {
+        <>
+            { if props.zones.iter().any(|zone| zone.label.is_some()) {
+                html! {
+                    <ZoneLabels
+                        model={model.clone()}
+                        zones={props.zones.clone()}
+                        orientation={props.orientation.clone()}
+                    />
+                }
+            } else {
+                html! {}
+            } }
+            { if props.marks.is_empty() {
+                html! {}
+            } else {
+                html! {
+                    <Marks
+                        model={model.clone()}
+                        marks={props.marks.clone()}
+                        orientation={props.orientation.clone()}
+                        thumb_size={native_thumb_size.clone()}
+                    />
+                }
+            } }
+        </>
+    };
+    let histogram = html! {
+        <Histogram
+            model={model.clone()}
+            counts={props.histogram.clone()}
+            orientation={props.orientation.clone()}
+            color={color.clone()}
+            rtl_fill={props.rtl_fill}
+            thumb_size={native_thumb_size.clone()}
+        />
+    };
+    let step_labels = step_values
+        .iter()
+        .map(|&v| slider.value_text(v))
+        .collect::<Vec<_>>();
+
+    let range_drag = if props.draggable_range && thumbs > 1 {
+        let segment = slider.range_drag_props();
+        html! {
+            <>
+                <style>{ RANGE_DRAG_CSS }</style>
+                <div
+                    class={segment.class}
+                    style={segment.style}
+                    role={segment.role}
+                    tabindex={segment.tabindex}
+                    onpointerdown={segment.onpointerdown}
+                    onpointermove={segment.onpointermove}
+                    onpointerup={segment.onpointerup.clone()}
+                    onpointercancel={segment.onpointerup}
+                    onkeydown={segment.onkeydown}
+                    onkeyup={segment.onkeyup}
+                    onblur={segment.onblur}
+                    aria-valuemin={segment.aria_valuemin}
+                    aria-valuemax={segment.aria_valuemax}
+                    aria-valuenow={segment.aria_valuenow}
+                    aria-valuetext={segment.aria_valuetext}
+                    aria-orientation={segment.aria_orientation}
+                    aria-disabled={segment.aria_disabled}
+                    aria-label={segment.aria_label}
+                    aria-labelledby={segment.aria_labelledby}
+                />
+            </>
+        }
+    } else {
+        html! {}
     };
 
-    let (input_style1, input_style2): (&'static str, &'static str) = if props.double {
-        let flipped_style = Box::leak(Box::new(format!(
-            "{}; transform: rotate(0deg); direction: rtl; z-index: 3; position: relative; flex: 1;",
-            props.input_style
-        )));
-        let normal_style = Box::leak(Box::new(format!(
-            "{}; z-index: 2; position: relative; flex: 1;",
-            props.input_style
-        )));
-        (flipped_style, normal_style)
+    // Range and multi-thumb sliders stack one input per thumb on a shared track: the bottom
+    // input paints the fill between the outer thumbs and the inputs above it stay transparent.
+    let inputs = if props.renderer == Renderer::Custom {
+        let track = slider.track_props();
+        let (start, end) = model.fill_percent();
+        let mut track_style = custom_track_style(
+            &props.orientation,
+            &props.slider_width,
+            &props.slider_height,
+            &props.size,
+            props.disabled,
+        );
+        if let Some(outside) = &props.outside_color {
+            track_style.push_str(&format!(" background: {};", outside.to_color_code()));
+        }
+        if !props.zones.is_empty() {
+            track_style.push(' ');
+            track_style.push_str(&zones_track_background(
+                props.outside_color.as_ref(),
+                &props.zones,
+                model,
+                &props.orientation,
+            ));
+        }
+        html! {
+            <div
+                class={track.class}
+                style={track_style}
+                onpointerdown={track.onpointerdown}
+                onpointermove={track.onpointermove}
+                onpointerup={track.onpointerup.clone()}
+                onpointercancel={track.onpointerup}
+            >
+                <div
+                    class={RANGE_CLASS}
+                    style={custom_range_style(&props.orientation, &color, start, end)}
+                />
+                { range_drag }
+                { for (0..thumbs).map(|thumb| {
+                    let thumb_props = slider.thumb_props(thumb);
+                    html! {
+                        <div
+                            key={thumb}
+                            id={thumb_props.id}
+                            class={thumb_props.class}
+                            style={thumb_props.style}
+                            role={thumb_props.role}
+                            tabindex={thumb_props.tabindex}
+                            onkeydown={thumb_props.onkeydown}
+                            onkeyup={thumb_props.onkeyup}
+                            onwheel={thumb_props.onwheel}
+                            onfocus={thumb_props.onfocus}
+                            onblur={thumb_props.onblur}
+                            aria-valuemin={thumb_props.aria_valuemin}
+                            aria-valuemax={thumb_props.aria_valuemax}
+                            aria-valuenow={thumb_props.aria_valuenow}
+                            aria-valuetext={thumb_props.aria_valuetext}
+                            aria-orientation={thumb_props.aria_orientation}
+                            aria-disabled={thumb_props.aria_disabled}
+                            aria-label={thumb_props.aria_label}
+                            aria-labelledby={thumb_props.aria_labelledby}
+                            aria-describedby={thumb_props.aria_describedby}
+                        >
+                            { props.custom_thumb_html.clone().unwrap_or_default() }
+                        </div>
+                    }
+                }) }
+            </div>
+        }
+    } else if thumbs > 1 {
+        let (start, end) = model.fill_percent();
+        let fill = zoned.clone().unwrap_or_else(|| {
+            range_background(
+                &color,
+                props.outside_color.as_ref(),
+                &props.orientation,
+                props.rtl_fill,
+                true,
+                start,
+                end,
+            )
+        });
+        let stack_style = if is_vertical {
+            "display: grid; height: 100%;"
+        } else {
+            "display: grid; width: 100%;"
+        };
+        html! {
+            <div class={STACKED_THUMBS_CLASS} style={stack_style}>
+                <style>{ STACKED_THUMBS_CSS }</style>
+                { range_drag }
+                { for (0..thumbs).map(|thumb| {
+                    let background = if thumb == 0 {
+                        fill.clone()
+                    } else {
+                        "background: transparent;".to_string()
+                    };
+                    input(thumb, Some(background))
+                }) }
+            </div>
+        }
     } else {
-        (props.input_style, props.input_style)
+        input(0, zoned)
     };
 
+    let value_display = slider.output_text();
+
+    let theme = props.theme.as_ref().or(context_theme.as_ref());
+    let (container_class, container_style) =
+        themed_container(theme, &props.container_class, &props.container_style);
+
     html! {
         <div
-            class={props.container_class}
-            style={props.container_style}
+            id={ids.root().to_string()}
+            class={container_class}
+            style={container_style}
             role="group"
-            aria-orientation={if props.orientation.is_vertical() { "vertical" } else { "horizontal" }}
+            aria-labelledby={(!props.label.is_empty()).then(|| ids.label())}
+            aria-orientation={props.orientation.to_aria()}
             aria-disabled={props.disabled.to_string()}
         >
+            <style>
+                { thumb_css(
+                    ids.root(),
+                    &props.cursor_style,
+                    &color,
+                    props.disabled,
+                    props.custom_thumb_css.as_deref(),
+                ) }
+            </style>
+            { if theme.is_some() {
+                html! { <style>{ THEME_CSS }</style> }
+            } else {
+                html! {}
+            } }
             <Label
-                label={props.label}
-                label_class={props.label_class}
-                label_style={props.label_style}
+                id={ids.label()}
+                for_id={(props.renderer == Renderer::Native).then(|| ids.input(0))}
+                label={props.label.clone()}
+                label_class={props.label_class.clone()}
+                label_style={props.label_style.clone()}
             />
-            { if props.orientation.is_vertical() {
+            { match &props.description {
+                Some(description) => html! {
+                    <p
+                        id={ids.description()}
+                        class="slider-description"
+                        style="font-size: 12px; margin: 0 0 8px;"
+                    >
+                        { description }
+                    </p>
+                },
+                None => html! {},
+            } }
+            <div style={group_style}>
+                { props.icon_start.clone().unwrap_or_default() }
+                { if props.histogram.is_empty() {
+                    inputs
+                } else {
                     html! {
-                        <div style="display: flex; flex-direction: row; align-items: flex-start;">
-                            { props.icon_start.clone().unwrap_or_default() }
-                            <Input
-                                input_ref={input_ref1}
-                                min={props.min}
-                                max={props.max}
-                                step={props.step}
-                                value={*val1}
-                                orientation={props.orientation.clone()}
-                                disabled={props.disabled}
-                                size={props.size.clone()}
-                                color={props.color.clone()}
-                                cursor_style={props.cursor_style.clone()}
-                                input_class={props.input_class}
-                                on_input={on_input1}
-                                on_focus={on_focus_cb.clone()}
-                                on_blur={on_blur_cb.clone()}
-                                datalist_id={Some(list_id.clone())}
-                                aria_label={props.aria_label}
-                                aria_describedby={props.aria_describedby}
-                                width={props.slider_width.clone()}
-                                height={props.slider_height.clone()}
-                                input_style={input_style1}
-                                custom_thumb_css={props.custom_thumb_css}
-                                custom_thumb_html={props.custom_thumb_html.clone()}
-                                keyboard_step={props.keyboard_step}
-                            />
-                            {
-                                if props.double {
-                                    html! {
-                                        <Input
-                                            input_ref={input_ref2}
-                                            min={props.min}
-                                            max={props.max}
-                                            step={props.step}
-                                            value={*val2}
-                                            orientation={props.orientation.clone()}
-                                            disabled={props.disabled}
-                                            size={props.size.clone()}
-                                            color={props.color.clone()}
-                                            cursor_style={props.cursor_style.clone()}
-                                            input_class={props.input_class}
-                                            on_input={on_input2}
-                                            on_focus={on_focus_cb}
-                                            on_blur={on_blur_cb}
-                                            datalist_id={Some(list_id.clone())}
-                                            aria_label={props.aria_label}
-                                            aria_describedby={props.aria_describedby}
-                                            width={props.slider_width.clone()}
-                                            height={props.slider_height.clone()}
-                                            input_style={input_style2}
-                                            custom_thumb_css={props.custom_thumb_css}
-                                            custom_thumb_html={props.custom_thumb_html.clone()}
-                                            keyboard_step={props.keyboard_step}
-                                        />
-                                    }
-                                } else {
-                                    html! {}
-                                }
-                            }
-                            { props.icon_end.clone().unwrap_or_default() }
-                            {if props.show_steps {
-                                html! {
-                                    <>
-                                        <Ticks id={list_id.clone()} min={props.min} max={props.max} step={props.step} />
-                                        <Steps
-                                            min={props.min}
-                                            max={props.max}
-                                            step={props.step}
-                                            steps_style={props.steps_style}
-                                            orientation={props.orientation.clone()}
-                                        />
-                                    </>
-                                }
-                            }
-                            else {
-                                html!{}
-                            }
-                        }
+                        <div
+                            class="slider-histogram-track"
+                            style={histogram_track_style(&props.orientation)}
+                        >
+                            { histogram }
+                            { inputs }
                         </div>
                     }
-                } else if props.double {
+                } }
+                { props.icon_end.clone().unwrap_or_default() }
+                { if is_vertical { marks.clone() } else { html! {} } }
+                { if props.show_steps && is_vertical {
                     html! {
-                        <div style="position: relative; width: 100%; display: flex; align-items: center;">
-                            { props.icon_start.clone().unwrap_or_default() }
-                            <Input
-                                input_ref={input_ref1}
-                                min={props.min}
-                                max={props.max}
-                                step={props.step}
-                                value={*val1}
-                                rtl_fill={true}
+                        <>
+                            <Ticks id={list_id.clone()} values={tick_values.clone()} />
+                            <Steps
+                                labels={step_labels.clone()}
+                                steps_style={props.steps_style.clone()}
                                 orientation={props.orientation.clone()}
-                                disabled={props.disabled}
-                                size={props.size.clone()}
-                                color={props.color.clone()}
-                                cursor_style={props.cursor_style.clone()}
-                                input_class={props.input_class}
-                                on_input={on_input1}
-                                on_focus={on_focus_cb.clone()}
-                                on_blur={on_blur_cb.clone()}
-                                datalist_id={Some(list_id.clone())}
-                                aria_label={props.aria_label}
-                                aria_describedby={props.aria_describedby}
-                                width={props.slider_width.clone()}
-                                height={props.slider_height.clone()}
-                                input_style={input_style1}
-                                custom_thumb_css={props.custom_thumb_css}
-                                custom_thumb_html={props.custom_thumb_html.clone()}
-                                keyboard_step={props.keyboard_step}
                             />
-                            <Input
-                                input_ref={input_ref2}
-                                min={props.min}
-                                max={props.max}
-                                step={props.step}
-                                value={*val2}
-                                orientation={props.orientation.clone()}
-                                disabled={props.disabled}
-                                size={props.size.clone()}
-                                color={props.color.clone()}
-                                cursor_style={props.cursor_style.clone()}
-                                input_class={props.input_class}
-                                on_input={on_input2}
-                                on_focus={on_focus_cb}
-                                on_blur={on_blur_cb}
-                                datalist_id={Some(list_id.clone())}
-                                aria_label={props.aria_label}
-                                aria_describedby={props.aria_describedby}
-                                width={props.slider_width.clone()}
-                                height={props.slider_height.clone()}
-                                input_style={input_style2}
-                                custom_thumb_css={props.custom_thumb_css}
-                                custom_thumb_html={props.custom_thumb_html.clone()}
-                                keyboard_step={props.keyboard_step}
-                            />
-                            { props.icon_end.clone().unwrap_or_default() }
-                        </div>
+                        </>
                     }
                 } else {
-                    html! {
-                        <div style="display: flex; align-items: center; width: 100%;">
-                            { props.icon_start.clone().unwrap_or_default() }
-                            <Input
-                                input_ref={input_ref1}
-                                min={props.min}
-                                max={props.max}
-                                step={props.step}
-                                value={*val1}
-                                orientation={props.orientation.clone()}
-                                disabled={props.disabled}
-                                size={props.size.clone()}
-                                color={props.color.clone()}
-                                cursor_style={props.cursor_style.clone()}
-                                input_class={props.input_class}
-                                on_input={on_input1}
-                                on_focus={on_focus_cb.clone()}
-                                on_blur={on_blur_cb.clone()}
-                                datalist_id={Some(list_id.clone())}
-                                aria_label={props.aria_label}
-                                aria_describedby={props.aria_describedby}
-                                width={props.slider_width.clone()}
-                                height={props.slider_height.clone()}
-                                input_style={input_style1}
-                                custom_thumb_css={props.custom_thumb_css}
-                                custom_thumb_html={props.custom_thumb_html.clone()}
-                                keyboard_step={props.keyboard_step}
-                            />
-                            { props.icon_end.clone().unwrap_or_default() }
-                        </div>
-                    }
+                    html! {}
                 } }
-            <Ticks id={list_id.clone()} min={props.min} max={props.max} step={props.step} />
+            </div>
+            { if is_vertical { html! {} } else { marks } }
+            <Ticks id={list_id.clone()} values={tick_values.clone()} />
             { if props.show_value {
                 html! {
                     <Output
-                        value_display={format!("{:.1}", *val1)}
-                        output_class={props.output_class}
-                        output_style={props.output_style}
-                        tooltip_style={props.tooltip_style}
+                        id={ids.output()}
+                        for_ids={ids.inputs(thumbs)}
+                        value_display={value_display}
+                        output_class={props.output_class.clone()}
+                        output_style={props.output_style.clone()}
+                        tooltip_style={props.tooltip_style.clone()}
                         show_tooltip={props.show_tooltip}
-                        tooltip_left={format!("{:.2}%", ((*val1 - props.min) / (props.max - props.min)) * 100.0)}
+                        tooltip_left={format!("{:.2}%", model.percent(model.value()))}
                     />
                 }
             } else {
                 html! {}
             } }
-            { if props.show_steps && !props.orientation.is_vertical() {
+            { if props.show_steps && !is_vertical {
                 html! {
                     <Steps
-                        min={props.min}
-                        max={props.max}
-                        step={props.step}
-                        steps_style={props.steps_style}
+                        labels={step_labels}
+                        steps_style={props.steps_style.clone()}
                         orientation={props.orientation.clone()}
                     />
                 }
@@ -814,3 +2366,300 @@ pub fn slider(props: &Props) -> Html {
         </div>
     }
 }
+
+/// Props for the `ThemeProvider` component.
+#[derive(Properties, PartialEq)]
+pub struct ThemeProviderProps {
+    /// Theme used by every slider inside, unless a slider sets its own `theme`.
+    pub theme: Theme,
+
+    #[prop_or_default]
+    pub children: Html,
+}
+
+/// ThemeProvider Component
+///
+/// Shares a [`Theme`] with every [`Slider`] below it; passing a new theme restyles them all.
+///
+/// # Examples
+/// ```rust
+/// use yew::prelude::*;
+/// use slider_rs::yew::{Slider, ThemeProvider};
+/// use slider_rs::common::Theme;
+///
+/// #[function_component]
+/// fn App() -> Html {
+///     let dark = use_state(|| false);
+///     let theme = if *dark { Theme::dark() } else { Theme::light() };
+///     let toggle = {
+///         let dark = dark.clone();
+///         Callback::from(move |_| dark.set(!*dark))
+///     };
+///
+///     html! {
+///         <ThemeProvider theme={theme}>
+///             <button onclick={toggle}>{ "Toggle theme" }</button>
+///             <Slider label="Volume" />
+///             <Slider label="Balance" min={-1.0} max={1.0} step={0.1} />
+///         </ThemeProvider>
+///     }
+/// }
+/// ```
+#[function_component(ThemeProvider)]
+pub fn theme_provider(props: &ThemeProviderProps) -> Html {
+    html! {
+        <ContextProvider<Theme> context={props.theme.clone()}>
+            { props.children.clone() }
+        </ContextProvider<Theme>>
+    }
+}
+
+/// Numbers the sliders below a [`SliderIdProvider`] in render order.
+#[derive(Clone, Default)]
+struct SliderIdCounter(Rc<Cell<usize>>);
+
+impl PartialEq for SliderIdCounter {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl SliderIdCounter {
+    fn next_ids(&self) -> SliderIds {
+        let index = self.0.get();
+        self.0.set(index + 1);
+        SliderIds::numbered(index)
+    }
+}
+
+/// Warns once that a slider got ids from the process-wide counter, which the server and the
+/// hydrating client do not share.
+#[cfg(any(feature = "ssr", feature = "hydrate"))]
+fn warn_unnumbered_slider() {
+    static WARNED: std::sync::Once = std::sync::Once::new();
+    WARNED.call_once(|| {
+        let message = "slider-rs: a slider without `id` outside a `SliderIdProvider` gets ids \
+                       that can differ between the server and the hydrating client";
+        #[cfg(target_arch = "wasm32")]
+        web_sys::console::warn_1(&message.into());
+        #[cfg(not(target_arch = "wasm32"))]
+        eprintln!("{message}");
+    });
+}
+
+/// Props for the `SliderIdProvider` component.
+#[derive(Properties, PartialEq)]
+pub struct SliderIdProviderProps {
+    #[prop_or_default]
+    pub children: Html,
+}
+
+/// SliderIdProvider Component
+///
+/// Numbers the sliders inside that have no `id` from `slider-0`, in render order. Wrap the
+/// root of a server rendered app in it: the server and the hydrating client then count from
+/// the same start and agree on every `id`, `for` and `aria-*` attribute. With `ssr` or
+/// `hydrate` enabled, a slider without `id` outside one logs a warning.
+///
+/// # Examples
+/// ```rust
+/// use yew::prelude::*;
+/// use slider_rs::yew::{Slider, SliderIdProvider};
+///
+/// #[function_component]
+/// fn App() -> Html {
+///     html! {
+///         <SliderIdProvider>
+///             <Slider label="Volume" />
+///             <Slider label="Balance" min={-1.0} max={1.0} step={0.1} />
+///         </SliderIdProvider>
+///     }
+/// }
+/// ```
+#[function_component(SliderIdProvider)]
+pub fn slider_id_provider(props: &SliderIdProviderProps) -> Html {
+    let counter = use_state(SliderIdCounter::default);
+    html! {
+        <ContextProvider<SliderIdCounter> context={(*counter).clone()}>
+            { props.children.clone() }
+        </ContextProvider<SliderIdCounter>>
+    }
+}
+
+/// Props for the `OptionSlider` component.
+#[derive(Properties, PartialEq, Clone)]
+pub struct OptionSliderProps<T: Clone + PartialEq + Display + 'static> {
+    /// Id of the slider container, see [`Props::id`].
+    #[prop_or_default]
+    pub id: Option<AttrValue>,
+
+    /// Label text displayed above the slider.
+    #[prop_or_default]
+    pub label: AttrValue,
+
+    /// The options to pick from, in track order.
+    pub options: Vec<T>,
+
+    /// Controlled index of the selected option.
+    #[prop_or_default]
+    pub index: Option<usize>,
+
+    /// Initial index for an uncontrolled slider. Defaults to the first option.
+    #[prop_or_default]
+    pub default_index: Option<usize>,
+
+    /// Text for an option, used instead of its `Display` output.
+    #[prop_or_default]
+    pub format: Option<Callback<T, String>>,
+
+    /// Callback triggered with the selected option.
+    #[prop_or_default]
+    pub on_change: Callback<T>,
+
+    /// Callback triggered with the index of the selected option.
+    #[prop_or_default]
+    pub on_change_index: Callback<usize>,
+
+    /// Slider orientation: horizontal or vertical.
+    #[prop_or_default]
+    pub orientation: Orientation,
+
+    /// Size variant for styling the slider track and thumb.
+    #[prop_or_default]
+    pub size: Size,
+
+    /// Color variant for styling the slider.
+    #[prop_or_default]
+    pub color: Color,
+
+    /// Thumb shape: `Round`, `Square` or `Custom` CSS declarations.
+    #[prop_or_default]
+    pub cursor_style: Cursor,
+
+    /// Whether to show the selected option as an output.
+    #[prop_or(false)]
+    pub show_value: bool,
+
+    /// Whether to show the option labels along the slider track.
+    #[prop_or(true)]
+    pub show_steps: bool,
+
+    /// Whether to show tooltip above the thumb on hover.
+    #[prop_or(false)]
+    pub show_tooltip: bool,
+
+    /// Whether to disable interaction with the slider.
+    #[prop_or(false)]
+    pub disabled: bool,
+
+    /// ARIA label for accessibility.
+    #[prop_or_default]
+    pub aria_label: Option<AttrValue>,
+
+    /// ARIA describedby attribute for accessibility.
+    #[prop_or_default]
+    pub aria_describedby: Option<AttrValue>,
+
+    /// Custom width for the slider track.
+    #[prop_or_default]
+    pub slider_width: Width,
+
+    /// Custom height for the slider track.
+    #[prop_or_default]
+    pub slider_height: Height,
+
+    /// CSS class for the container wrapping the slider.
+    #[prop_or(AttrValue::Static("slider-container"))]
+    pub container_class: AttrValue,
+
+    /// Inline style for the container wrapping the slider.
+    #[prop_or(AttrValue::Static(
+        "display: flex; flex-direction: column; align-items: center; margin: 20px; position: relative;"
+    ))]
+    pub container_style: AttrValue,
+
+    /// Colours, radii and sizes emitted as `--slider-*` CSS variables on the container.
+    ///
+    /// Falls back to the theme of the nearest [`ThemeProvider`].
+    #[prop_or_default]
+    pub theme: Option<Theme>,
+}
+
+/// OptionSlider Component
+///
+/// A [`Slider`] over a list of options: the thumb snaps to each option and the step labels,
+/// the output, the tooltip and `aria-valuetext` show the option instead of its index.
+///
+/// # Examples
+/// ```rust
+/// use yew::prelude::*;
+/// use slider_rs::yew::OptionSlider;
+///
+/// #[function_component]
+/// fn App() -> Html {
+///     html! {
+///         <OptionSlider<&'static str>
+///             label="Size"
+///             options={vec!["XS", "S", "M", "L", "XL"]}
+///             default_index={Some(2)}
+///             on_change={Callback::from(|size| log::info!("Size: {}", size))}
+///         />
+///     }
+/// }
+/// ```
+#[function_component(OptionSlider)]
+pub fn option_slider<T>(props: &OptionSliderProps<T>) -> Html
+where
+    T: Clone + PartialEq + Display + 'static,
+{
+    let labels = props
+        .options
+        .iter()
+        .map(|option| match &props.format {
+            Some(format) => format.emit(option.clone()),
+            None => option.to_string(),
+        })
+        .collect::<Vec<_>>();
+
+    let on_change = {
+        let options = props.options.clone();
+        let on_change = props.on_change.clone();
+        let on_change_index = props.on_change_index.clone();
+        Callback::from(move |value: f64| {
+            let index = value.round() as usize;
+            if let Some(option) = options.get(index) {
+                on_change_index.emit(index);
+                on_change.emit(option.clone());
+            }
+        })
+    };
+
+    html! {
+        <Slider
+            label={props.label.clone()}
+            min={0.0}
+            max={props.options.len().saturating_sub(1) as f64}
+            step={1.0}
+            value={props.index.map(|index| index as f64)}
+            default_value={props.default_index.map(|index| index as f64)}
+            labels={labels}
+            on_change={on_change}
+            orientation={props.orientation.clone()}
+            size={props.size.clone()}
+            color={props.color.clone()}
+            cursor_style={props.cursor_style.clone()}
+            show_value={props.show_value}
+            show_steps={props.show_steps}
+            show_tooltip={props.show_tooltip}
+            disabled={props.disabled}
+            id={props.id.clone()}
+            aria_label={props.aria_label.clone()}
+            aria_describedby={props.aria_describedby.clone()}
+            slider_width={props.slider_width.clone()}
+            slider_height={props.slider_height.clone()}
+            container_class={props.container_class.clone()}
+            container_style={props.container_style.clone()}
+            theme={props.theme.clone()}
+        />
+    }
+}
@@ -0,0 +1,420 @@
+//! Server rendered markup of each adapter.
+//!
+//! Run with `cargo test --features ssr,yew,dio,lep`; review changed snapshots with
+//! `cargo insta review`.
+#![cfg(feature = "ssr")]
+
+#[cfg(feature = "yew")]
+mod yew_ssr {
+    use slider_rs::common::{Mark, SliderModel};
+    use slider_rs::yew::{
+        Slider, SliderConfig, SliderIdProvider, SliderMarks, SliderRange, SliderRoot, SliderThumb,
+        SliderTrack, SliderValue, use_slider,
+    };
+    use yew::prelude::*;
+
+    #[function_component(Volume)]
+    fn volume() -> Html {
+        html! {
+            <Slider
+                id="volume"
+                label="Volume"
+                description="Output level of the speakers"
+                min={0.0}
+                max={10.0}
+                default_value={Some(4.0)}
+                show_value=true
+            />
+        }
+    }
+
+    #[function_component(Budget)]
+    fn budget() -> Html {
+        html! {
+            <Slider id="budget" label="Budget" double=true default_range={Some((2.0, 8.0))} />
+        }
+    }
+
+    #[function_component(Unnamed)]
+    fn unnamed() -> Html {
+        html! {
+            <SliderIdProvider>
+                <Slider label="Budget" double=true default_range={Some((2.0, 8.0))} />
+                <Slider label="Volume" />
+            </SliderIdProvider>
+        }
+    }
+
+    #[function_component(Window)]
+    fn window() -> Html {
+        html! {
+            <Slider
+                id="window"
+                label="Time window"
+                double=true
+                draggable_range=true
+                default_range={Some((9.0, 17.0))}
+                max={24.0}
+            />
+        }
+    }
+
+    #[function_component(Prices)]
+    fn prices() -> Html {
+        html! {
+            <Slider
+                id="prices"
+                label="Price"
+                double=true
+                default_range={Some((25.0, 75.0))}
+                max={100.0}
+                histogram={vec![3.0, 8.0, 5.0, 1.0]}
+            />
+        }
+    }
+
+    #[function_component(Headless)]
+    fn headless() -> Html {
+        let slider = use_slider(SliderConfig {
+            id: Some("level".into()),
+            ..SliderConfig::new(SliderModel::new(0.0, 10.0, 1.0).with_value(3.0))
+        });
+        let track = slider.track_props();
+        let range = slider.range_props();
+        let thumb = slider.thumb_props(0);
+        html! {
+            <div class={track.class} style={track.style}>
+                <div class={range.class} style={range.style} />
+                <div
+                    id={thumb.id}
+                    class={thumb.class}
+                    role={thumb.role}
+                    aria-valuenow={thumb.aria_valuenow}
+                    aria-valuetext={thumb.aria_valuetext}
+                />
+            </div>
+        }
+    }
+
+    #[function_component(Parts)]
+    fn parts() -> Html {
+        let config = SliderConfig {
+            id: Some("span".into()),
+            ..SliderConfig::new(SliderModel::new(0.0, 10.0, 1.0).with_values(vec![2.0, 8.0]))
+        };
+        html! {
+            <SliderRoot config={config}>
+                <SliderTrack>
+                    <SliderRange />
+                    <SliderThumb index={0} />
+                    <SliderThumb index={1} class="upper" />
+                </SliderTrack>
+                <SliderMarks marks={vec![Mark::new(5.0).with_label("mid")]} />
+                <SliderValue />
+            </SliderRoot>
+        }
+    }
+
+    fn render<C: BaseComponent<Properties = ()>>() -> String {
+        futures::executor::block_on(
+            yew::LocalServerRenderer::<C>::new()
+                .hydratable(false)
+                .render(),
+        )
+    }
+
+    #[test]
+    fn single_slider() {
+        insta::assert_snapshot!(render::<Volume>());
+    }
+
+    #[test]
+    fn double_slider() {
+        insta::assert_snapshot!(render::<Budget>());
+    }
+
+    #[test]
+    fn generated_ids_follow_the_provider() {
+        let html = render::<Unnamed>();
+        assert_eq!(html, render::<Unnamed>());
+        assert!(html.contains(r#"id="slider-0-input-0""#));
+        assert!(html.contains(r#"id="slider-1-input-0""#));
+    }
+
+    #[test]
+    fn draggable_range_slider() {
+        insta::assert_snapshot!(render::<Window>());
+    }
+
+    #[test]
+    fn histogram_slider() {
+        insta::assert_snapshot!(render::<Prices>());
+    }
+
+    #[test]
+    fn headless_slider() {
+        insta::assert_snapshot!(render::<Headless>());
+    }
+
+    #[test]
+    fn compound_slider() {
+        insta::assert_snapshot!(render::<Parts>());
+    }
+}
+
+#[cfg(feature = "dio")]
+mod dioxus_ssr {
+    use dioxus::prelude::*;
+    use slider_rs::common::{Mark, SliderModel};
+    use slider_rs::dioxus::{
+        Slider, SliderConfig, SliderMarks, SliderRange, SliderRoot, SliderThumb, SliderTrack,
+        SliderValue, use_slider,
+    };
+
+    fn volume() -> Element {
+        rsx! {
+            Slider {
+                id: "volume",
+                label: "Volume",
+                description: "Output level of the speakers",
+                min: 0.0,
+                max: 10.0,
+                default_value: 4.0,
+                show_value: true,
+            }
+        }
+    }
+
+    fn budget() -> Element {
+        rsx! {
+            Slider { label: "Budget", double: true, default_range: (2.0, 8.0) }
+        }
+    }
+
+    fn window() -> Element {
+        rsx! {
+            Slider {
+                id: "window",
+                label: "Time window",
+                double: true,
+                draggable_range: true,
+                default_range: (9.0, 17.0),
+                max: 24.0,
+            }
+        }
+    }
+
+    fn prices() -> Element {
+        rsx! {
+            Slider {
+                id: "prices",
+                label: "Price",
+                double: true,
+                default_range: (25.0, 75.0),
+                max: 100.0,
+                histogram: vec![3.0, 8.0, 5.0, 1.0],
+            }
+        }
+    }
+
+    fn headless() -> Element {
+        let slider = use_slider(SliderConfig {
+            id: Some("level".to_string()),
+            ..SliderConfig::new(SliderModel::new(0.0, 10.0, 1.0).with_value(3.0))
+        });
+        let track = slider.track_props();
+        let range = slider.range_props();
+        let thumb = slider.thumb_props(0);
+        rsx! {
+            div { class: track.class, style: track.style,
+                div { class: range.class, style: range.style }
+                div {
+                    id: thumb.id,
+                    class: thumb.class,
+                    role: thumb.role,
+                    aria_valuenow: thumb.aria_valuenow,
+                    aria_valuetext: thumb.aria_valuetext,
+                }
+            }
+        }
+    }
+
+    fn parts() -> Element {
+        let config = SliderConfig {
+            id: Some("span".to_string()),
+            ..SliderConfig::new(SliderModel::new(0.0, 10.0, 1.0).with_values(vec![2.0, 8.0]))
+        };
+        rsx! {
+            SliderRoot { config,
+                SliderTrack {
+                    SliderRange {}
+                    SliderThumb { index: 0 }
+                    SliderThumb { index: 1, class: "upper" }
+                }
+                SliderMarks { marks: vec![Mark::new(5.0).with_label("mid")] }
+                SliderValue {}
+            }
+        }
+    }
+
+    fn render(app: fn() -> Element) -> String {
+        let mut dom = VirtualDom::new(app);
+        dom.rebuild_in_place();
+        dioxus::ssr::render(&dom)
+    }
+
+    #[test]
+    fn single_slider() {
+        insta::assert_snapshot!(render(volume));
+    }
+
+    #[test]
+    fn double_slider() {
+        insta::assert_snapshot!(render(budget));
+    }
+
+    #[test]
+    fn draggable_range_slider() {
+        insta::assert_snapshot!(render(window));
+    }
+
+    #[test]
+    fn histogram_slider() {
+        insta::assert_snapshot!(render(prices));
+    }
+
+    #[test]
+    fn headless_slider() {
+        insta::assert_snapshot!(render(headless));
+    }
+
+    #[test]
+    fn compound_slider() {
+        insta::assert_snapshot!(render(parts));
+    }
+
+    #[test]
+    fn generated_ids_follow_the_tree() {
+        assert_eq!(render(budget), render(budget));
+    }
+}
+
+#[cfg(feature = "lep")]
+mod leptos_ssr {
+    use hydration_context::SsrSharedContext;
+    use leptos::prelude::*;
+    use slider_rs::common::{Mark, SliderModel};
+    use slider_rs::leptos::{
+        Slider, SliderConfig, SliderMarks, SliderRange, SliderRoot, SliderThumb, SliderTrack,
+        SliderValue, use_slider,
+    };
+    use std::sync::Arc;
+
+    fn render<V: IntoView + 'static>(view: impl FnOnce() -> V) -> String {
+        // Effects need an executor once `hydrate` is enabled alongside `ssr`.
+        let _ = leptos::task::Executor::init_futures_executor();
+        let owner = Owner::new_root(Some(Arc::new(SsrSharedContext::new())));
+        owner.with(|| view().to_html())
+    }
+
+    fn volume() -> impl IntoView {
+        view! {
+            <Slider
+                id="volume"
+                label="Volume"
+                description="Output level of the speakers"
+                min=0.0
+                max=10.0
+                default_value=4.0
+                show_value=true
+            />
+        }
+    }
+
+    fn budget() -> impl IntoView {
+        view! { <Slider label="Budget" double=true default_range=(2.0, 8.0) /> }
+    }
+
+    fn prices() -> impl IntoView {
+        view! {
+            <Slider
+                id="prices"
+                label="Price"
+                double=true
+                default_range=(25.0, 75.0)
+                max=100.0
+                histogram=vec![3.0, 8.0, 5.0, 1.0]
+            />
+        }
+    }
+
+    fn headless() -> impl IntoView {
+        let slider = use_slider(SliderConfig {
+            id: Some("level".to_string()),
+            ..SliderConfig::new(SliderModel::new(0.0, 10.0, 1.0).with_value(3.0))
+        });
+        let track = slider.track_props();
+        let range = slider.range_props();
+        let thumb = slider.thumb_props(0);
+        view! {
+            <div class=track.class style=track.style>
+                <div class=range.class style=range.style></div>
+                <div
+                    id=thumb.id
+                    class=thumb.class
+                    role=thumb.role
+                    aria-valuenow=thumb.aria_valuenow
+                    aria-valuetext=thumb.aria_valuetext
+                ></div>
+            </div>
+        }
+    }
+
+    fn parts() -> impl IntoView {
+        let config = SliderConfig {
+            id: Some("span".to_string()),
+            ..SliderConfig::new(SliderModel::new(0.0, 10.0, 1.0).with_values(vec![2.0, 8.0]))
+        };
+        view! {
+            <SliderRoot config=config>
+                <SliderTrack>
+                    <SliderRange />
+                    <SliderThumb index=0 />
+                    <SliderThumb index=1 class="upper" />
+                </SliderTrack>
+                <SliderMarks marks=vec![Mark::new(5.0).with_label("mid")] />
+                <SliderValue />
+            </SliderRoot>
+        }
+    }
+
+    #[test]
+    fn single_slider() {
+        insta::assert_snapshot!(render(volume));
+    }
+
+    #[test]
+    fn double_slider() {
+        insta::assert_snapshot!(render(budget));
+    }
+
+    #[test]
+    fn histogram_slider() {
+        insta::assert_snapshot!(render(prices));
+    }
+
+    #[test]
+    fn headless_slider() {
+        insta::assert_snapshot!(render(headless));
+    }
+
+    #[test]
+    fn compound_slider() {
+        insta::assert_snapshot!(render(parts));
+    }
+
+    #[test]
+    fn generated_ids_follow_the_request() {
+        assert_eq!(render(budget), render(budget));
+    }
+}